@@ -0,0 +1,64 @@
+use clia::{help_items::{HelpItem, HelpItemKind}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec, synopsis::SynopsisKind};
+
+/// this is just an example of writing your own help renderer, it prints the options and parameters of a program as csv, one row each
+fn main() {
+    /*
+    first step is to define the program, like you would to parse it
+    */
+    let spec = CliSpec::new(
+        &[
+            ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+            ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search, ei. \"rs, toml\"").unwrap(), "EXTENSIONS"),
+            ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap().with_meta("group", "output"), "FORMAT")
+                .with_allowed_values(&["DEFAULT", "BULLET", "NUMERIC"])
+                .default_with(|| String::from("DEFAULT")),
+        ],
+        &[
+            ClParameter::new("PATH", "Path to search in"),
+            ClParameter::new("QUERY", "String to search for").optional(true),
+        ],
+    );
+
+    /*
+    second step is to render a row for each help item, in the order they'd be shown in the help message
+    */
+    println!("order,kind,short,long,placeholder,description,default,allowed values,required,group");
+    for item in spec.help_items() {
+        println!("{}", row(&item));
+    }
+}
+
+/// returns the csv row of `item`
+fn row(item: &HelpItem) -> String {
+    let kind: &str = match item.get_kind() {
+        HelpItemKind::Option(SynopsisKind::Flag) => "flag",
+        HelpItemKind::Option(SynopsisKind::Toggle) => "toggle",
+        HelpItemKind::Option(SynopsisKind::Count) => "count",
+        HelpItemKind::Option(SynopsisKind::Preset) => "preset",
+        HelpItemKind::Option(SynopsisKind::List) => "list",
+        HelpItemKind::Option(SynopsisKind::Data) => "data",
+        HelpItemKind::Parameter => "parameter",
+    };
+
+    [
+        item.get_display_order().to_string(),
+        kind.to_string(),
+        item.get_short_flag().to_string(),
+        item.get_long_flag().to_string(),
+        item.get_placeholder().unwrap_or_default().to_string(),
+        item.get_description().to_string(),
+        item.get_default().map(|default| default.produce()).unwrap_or_default(),
+        item.get_allowed_values().join(" "),
+        item.get_required().to_string(),
+        item.get_meta("group").unwrap_or_default().to_string(),
+    ].iter().map(|field| quote(field)).collect::<Vec<String>>().join(",")
+}
+
+/// returns `field` quoted if it has to be, ei. `rs, toml` becomes `"rs, toml"`
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
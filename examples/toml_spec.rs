@@ -0,0 +1,24 @@
+use std::env;
+
+use clia::{spec, Parser};
+
+/// this is just an example of defining a program in a toml file, the same program as src/main.rs
+fn main() {
+    /*
+    first step is to load the definition, it's checked as it's loaded so a mistake in it fails here (or in a test that loads it)
+    */
+    let spec = spec::load_toml(include_str!("cli.toml")).unwrap();
+
+    /*
+    second step is to collect CLI Arguments and call the parser, which prints help or errors itself
+    */
+    let args: Vec<String> = env::args().collect();
+    let Some(parser) = Parser::parse_or_report(&spec, &args) else {
+        return;
+    };
+
+    /*
+    third step is to access the "found" fields from the parser
+    */
+    println!("{}", parser.summary());
+}
@@ -0,0 +1,82 @@
+//! # Artifact
+//! 'artifact' is a module containing utilities for
+//! generating the files that describe a program from its options and parameters, ei. its help message or shell completions
+//!
+//! programs can offer them to their users with a `--generate <ARTIFACT>` option, see `ParserSettings::with_generators()`
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use crate::completion;
+use crate::option_args;
+use crate::parameter_args;
+use crate::Parser;
+
+/// the long flag of the option added by `ParserSettings::with_generators()`
+pub const GENERATE_FLAG: &str = "--generate";
+
+/// the things that can be generated from the definition of a program
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Artifact {
+    /// the help message, see `Parser::help()`
+    Help,
+    /// a fish completion script, see `completion::generate_fish()`
+    FishCompletions,
+    /// a fish completion script that asks the program for candidates at runtime, see `completion::generate_fish_dynamic()`
+    DynamicFishCompletions,
+}
+impl Artifact {
+    /// get the name of this artifact, as given to `--generate`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::artifact::Artifact;
+    /// //...
+    ///     assert_eq!(Artifact::Help.get_name(), "help");
+    ///     assert_eq!(Artifact::FishCompletions.get_name(), "fish-completions");
+    ///     assert_eq!(Artifact::DynamicFishCompletions.get_name(), "fish-dynamic-completions");
+    /// ```
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Artifact::Help => "help",
+            Artifact::FishCompletions => "fish-completions",
+            Artifact::DynamicFishCompletions => "fish-dynamic-completions",
+        }
+    }
+
+    /// returns the artifact named `name`, see `Artifact::get_name()`
+    ///
+    /// # None
+    /// - no artifact is named `name`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::artifact::Artifact;
+    /// //...
+    ///     assert_eq!(Artifact::from_name("fish-completions"), Some(Artifact::FishCompletions));
+    ///     assert_eq!(Artifact::from_name("man"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Artifact> {
+        [Artifact::Help, Artifact::FishCompletions, Artifact::DynamicFishCompletions].into_iter().find(|artifact| artifact.get_name().eq(name))
+    }
+
+    /// renders this artifact for the program `program_name`, with `valid_options` and `expected_parameters`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{artifact::Artifact, completion, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///
+    ///     assert_eq!(Artifact::Help.render("foo", &valid_options, &expected_parameters), Parser::help("foo", "", "", &valid_options, &expected_parameters));
+    ///     assert_eq!(Artifact::FishCompletions.render("foo", &valid_options, &expected_parameters), completion::generate_fish("foo", &valid_options, &expected_parameters));
+    /// ```
+    pub fn render(&self, program_name: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter]) -> String {
+        match self {
+            Artifact::Help => Parser::help(program_name, "", "", valid_options, expected_parameters),
+            Artifact::FishCompletions => completion::generate_fish(program_name, valid_options, expected_parameters),
+            Artifact::DynamicFishCompletions => completion::generate_fish_dynamic(program_name),
+        }
+    }
+}
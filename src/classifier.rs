@@ -0,0 +1,682 @@
+//! # Classifier
+//! 'classifier' is a module containing utilities for
+//! telling what the parser would make of a single arg, without parsing a whole command line, ei. to analyze many command lines quickly
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use crate::option_args;
+use crate::option_parser::{self, Token};
+use crate::parameter_args;
+use crate::parameter_parser;
+use crate::parser_settings::ParserSettings;
+
+/// what an arg is, see `Classifier::classify()`
+///
+/// options are identified by their index in the options the Classifier was made from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenClass {
+    /// a flag of the option at this index
+    KnownFlag(usize),
+    /// a flag that isn't a flag of any option
+    UnknownFlag,
+    /// the list/data of the option at this index
+    ValueFor(usize),
+    /// the `--` that ends the options, see `option_parser::find_terminator()`
+    Terminator,
+    /// anything else, ei. a parameter
+    Positional,
+}
+
+/// classifies args one at a time, by the same rules as the parser, see `Classifier::classify()`
+///
+/// every form of every flag is found up front, so classifying an arg doesn't allocate,
+/// and args are split into flags the same way the parser splits them, see `option_parser::parse_for_options()`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Classifier {
+    tokenizer: option_parser::Tokenizer,
+    corrections: HashMap<String, usize>,
+    trim_flag_whitespace: bool,
+}
+impl Classifier {
+    /// creates a new Classifier for `valid_options`, parsed with `settings`
+    ///
+    /// # Notes:
+    /// - flags with trailing whitespace are recognized if `ParserSettings::trim_flag_whitespace()` is set
+    /// - flags with the wrong number of dashes are recognized if `ParserSettings::correct_dash_mistakes()` is set
+    /// - if more than one option has a flag, it's a flag of the first of them
+    /// - flags are only the forms the parser accepts, so there are no slash flags (`/r` is a positional) and case matters (`-R` isn't `-r`)
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::{Classifier, TokenClass}, option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///
+    ///     let classifier = Classifier::new(&valid_options, &ParserSettings::new());
+    ///     assert_eq!(classifier.classify("-recursive", None), TokenClass::UnknownFlag);
+    ///     assert_eq!(classifier.classify("-r ", None), TokenClass::UnknownFlag);
+    ///
+    ///     let classifier = Classifier::new(&valid_options, &ParserSettings::new().correct_dash_mistakes(true).trim_flag_whitespace(true));
+    ///     assert_eq!(classifier.classify("-recursive", None), TokenClass::KnownFlag(0));
+    ///     assert_eq!(classifier.classify("-r ", None), TokenClass::KnownFlag(0));
+    /// ```
+    pub fn new(valid_options: &[option_args::ClOption], settings: &ParserSettings) -> Classifier {
+        //DATA
+        let tokenizer: option_parser::Tokenizer = option_parser::Tokenizer::new(valid_options);
+        let valid_flags: HashSet<&str> = option_parser::get_valid_flags(valid_options);
+
+        //the flags with the wrong number of dashes, and the options they're flags of, if they're accepted
+        let corrections: HashMap<String, usize> = match settings.get_correct_dash_mistakes() {
+            true => valid_flags.iter()
+            .map(|flag| if flag.starts_with("--") {flag[1..].to_string()} else {format!("-{}", flag)})
+            .filter_map(|mistake| option_parser::find_dash_mistake(&mistake, &valid_flags).and_then(|(corrected, _)| tokenizer.get_option(&corrected)).map(|index| (mistake, index)))
+            .collect(),
+            false => HashMap::new(),
+        };
+
+        Classifier {
+            tokenizer,
+            corrections,
+            trim_flag_whitespace: settings.get_trim_flag_whitespace(),
+        }
+    }
+
+    /// returns what the parser would make of `token`, when it comes right after `prev_token` (none if it's the first arg)
+    ///
+    /// # Notes:
+    /// - an arg right after a flag of an option that takes a list/data is its list/data, unless it's a flag too
+    /// - nothing is checked across args, ei. an option whose flag is repeated is only given the list/data after its first flag by the parser
+    /// - a cluster of short flags, ei. `-rf`, is a flag of the option of its last letter, the one that can take a list/data,
+    ///   or an unknown flag if an earlier letter's option takes one too, but the arg after it is still the list/data of the first of them, like the parser takes it
+    /// - a long flag with one dash is never a cluster, ei. `-ri` when `--ri` is a flag, it's an unknown flag, or a flag of `--ri` if `ParserSettings::correct_dash_mistakes()` is set
+    /// - a long flag given its list/data after an `=`, ei. `--format=json`, is a flag, and the arg after it isn't its list/data
+    /// - `--` is a terminator, and never the list/data of a flag, but only the first one ends the options and every arg after it is a positional,
+    ///   which only `Classifier::quick_scan()` and `Classifier::annotate()` can tell, since they see every arg
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::{Classifier, TokenClass}, option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///     ];
+    ///     let classifier = Classifier::new(&valid_options, &ParserSettings::new());
+    ///
+    ///     let args = ["-r", "--output", "out.txt", "-z", "path", "-"];
+    ///     let classes: Vec<TokenClass> = args.iter().enumerate().map(|(index, arg)| classifier.classify(arg, index.checked_sub(1).map(|prev| args[prev]))).collect();
+    ///     assert_eq!(classes, vec![
+    ///         TokenClass::KnownFlag(0),
+    ///         TokenClass::KnownFlag(1),
+    ///         TokenClass::ValueFor(1),
+    ///         TokenClass::UnknownFlag,
+    ///         TokenClass::Positional,
+    ///         TokenClass::Positional, //a lone dash isn't a flag
+    ///     ]);
+    ///     //a flag is never the data of a flag
+    ///     assert_eq!(classifier.classify("-r", Some("-o")), TokenClass::KnownFlag(0));
+    ///     
+    ///     //clusters are a flag of their last letter
+    ///     assert_eq!(classifier.classify("-ro", None), TokenClass::KnownFlag(1));
+    ///     assert_eq!(classifier.classify("out.txt", Some("-ro")), TokenClass::ValueFor(1));
+    ///     assert_eq!(classifier.classify("-or", None), TokenClass::UnknownFlag);
+    ///     assert_eq!(classifier.classify("out.txt", Some("-or")), TokenClass::ValueFor(1));
+    ///     
+    ///     //and values can be given after an '=', even ones that look like flags
+    ///     assert_eq!(classifier.classify("--output=out.txt", None), TokenClass::KnownFlag(1));
+    ///     assert_eq!(classifier.classify("path", Some("--output=out.txt")), TokenClass::Positional);
+    ///     assert_eq!(classifier.classify("--output=-5", None), TokenClass::KnownFlag(1));
+    ///     assert_eq!(classifier.classify("path", Some("--output=-5")), TokenClass::Positional);
+    ///     
+    ///     //a terminator is a terminator wherever it is
+    ///     assert_eq!(classifier.classify("--", Some("-o")), TokenClass::Terminator);
+    /// ```
+    ///
+    /// a long flag with one dash is a dash mistake, not a cluster, and values after an '=' are never flags, like the parser takes them
+    /// ```
+    /// use clia::{classifier::{Classifier, TokenClass}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-i", "--ignore-case", "Ignore case").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("", "--ri", "Search through subdirectories, ignoring case").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("", "--offset", "Lines to skip").unwrap(), "OFFSET"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    ///
+    ///     let classifier = Classifier::new(&valid_options, &ParserSettings::new());
+    ///     assert_eq!(classifier.classify("-ri", None), TokenClass::UnknownFlag);
+    ///     assert!(Parser::new(&to_args(&["foo.exe", "-ri", "path"]), &valid_options, &expected_parameters).is_err());
+    ///     assert_eq!(classifier.classify("-ir", None), TokenClass::KnownFlag(0));
+    ///     assert_eq!(classifier.classify("--offset=-5", None), TokenClass::KnownFlag(3));
+    ///     assert_eq!(classifier.classify("path", Some("--offset=-5")), TokenClass::Positional);
+    ///     let parser = Parser::new(&to_args(&["foo.exe", "--offset=-5", "path"]), &valid_options, &expected_parameters).unwrap();
+    ///     assert_eq!(parser.get_option_arguments_found()[3].get_data_opt(), Some("-5"));
+    ///
+    ///     let settings = ParserSettings::new().correct_dash_mistakes(true);
+    ///     let classifier = Classifier::new(&valid_options, &settings);
+    ///     assert_eq!(classifier.classify("-ri", None), TokenClass::KnownFlag(2));
+    ///     let parser = Parser::new_with_settings(&to_args(&["foo.exe", "-ri", "path"]), &valid_options, &expected_parameters, &settings).unwrap();
+    ///     assert_eq!(parser.get_option_arguments_found().iter().map(|option| option.get_present()).collect::<Vec<bool>>(), vec![false, false, true, false]);
+    /// ```
+    /// it agrees with the parser
+    /// ```
+    /// use clia::{classifier::{Classifier, TokenClass}, option_args::{ClOption, ClOptionInfo}, option_parser, parser_settings::ParserSettings};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///     ];
+    ///     let settings = ParserSettings::new().correct_dash_mistakes(true).trim_flag_whitespace(true);
+    ///     let classifier = Classifier::new(&valid_options, &settings);
+    ///     let pool = ["-r", "--recursive", "-recursive", "--r", "-r ", "-z", "--zen", "a.txt", "path", "-"];
+    ///
+    ///     //generate command lines, with at most one flag of --output since the parser only reads the data after the first one
+    ///     let mut seed: u64 = 42;
+    ///     let mut next = |bound: u64| {seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407); ((seed >> 33) % bound) as usize};
+    ///     for _ in 0..500 {
+    ///         let mut args: Vec<String> = vec![String::from("foo.exe")];
+    ///         args.extend((0..next(6)).map(|_| pool[next(pool.len() as u64)].to_string()));
+    ///         if next(2) == 0 {
+    ///             args.insert(1 + next(args.len() as u64), ["-o", "--output", "-output"][next(3)].to_string());
+    ///         }
+    ///
+    ///         let classes: Vec<TokenClass> = (1..args.len()).map(|index| classifier.classify(&args[index], Some(&args[index - 1]))).collect();
+    ///         let expected_ok: bool = !classes.contains(&TokenClass::UnknownFlag)
+    ///             && classes.iter().enumerate().all(|(index, class)| *class != TokenClass::KnownFlag(1) || classes.get(index + 1) == Some(&TokenClass::ValueFor(1)));
+    ///
+    ///         match option_parser::parse_for_options_with_settings(&args, &valid_options, &settings) {
+    ///             Ok(found) => {
+    ///                 assert!(expected_ok, "{:?}", args);
+    ///                 for (index, option) in found.iter().enumerate() {
+    ///                     assert_eq!(option.get_present(), classes.contains(&TokenClass::KnownFlag(index)), "{:?}", args);
+    ///                 }
+    ///                 let value: Option<&str> = classes.iter().position(|class| *class == TokenClass::ValueFor(1)).map(|index| args[index + 1].as_str());
+    ///                 assert_eq!(found[1].get_data_opt(), value, "{:?}", args);
+    ///             },
+    ///             Err(_) => assert!(!expected_ok, "{:?}", args),
+    ///         }
+    ///     }
+    /// ```
+    pub fn classify(&self, token: &str, prev_token: Option<&str>) -> TokenClass {
+        //the list/data of the flag before it, unless it was given one after an '='
+        if let Some(index) = prev_token.and_then(|prev| self.find_value_taker(prev)) {
+            if !option_parser::is_flag(token) {
+                return TokenClass::ValueFor(index);
+            }
+        }
+
+        if !option_parser::is_flag(token) {
+            return TokenClass::Positional;
+        }
+        if token.eq("--") {
+            return TokenClass::Terminator;
+        }
+        self.find_flag(token).map_or(TokenClass::UnknownFlag, TokenClass::KnownFlag)
+    }
+
+    /// returns `token` trimmed, if flags are trimmed (see `option_parser::trim_flag()`)
+    fn tidy<'a>(&self, token: &'a str) -> &'a str {
+        if self.trim_flag_whitespace {option_parser::trim_flag(token)} else {token}
+    }
+
+    /// returns the index of the option `flag` is a flag of, or would be with its dashes corrected, if it's a flag of one
+    fn find_option(&self, flag: &str) -> Option<usize> {
+        self.tokenizer.get_option(flag).or_else(|| self.corrections.get(flag).copied())
+    }
+
+    /// returns the index of the option `token` is a flag of, if it's a flag of one, the option of the last letter of a cluster,
+    /// unless an earlier letter's option takes a list/data, which the parser reports
+    fn find_flag(&self, token: &str) -> Option<usize> {
+        let token: &str = self.tidy(token);
+        match self.tokenizer.split(token) {
+            Token::Flag(flag) => self.find_option(flag),
+            Token::Equals(flag, _) => self.tokenizer.get_option(flag),
+            Token::Cluster(letters) if self.tokenizer.find_misplaced(token).is_none() => letters.chars().last().and_then(|letter| self.tokenizer.get_letter_option(letter)),
+            Token::Cluster(_) | Token::Positional => None,
+        }
+    }
+
+    /// returns the index of the option whose list/data is the arg after `token`, if it's a flag of one that takes a list/data, or a cluster with one,
+    /// the first of them if there's more than one, which the parser reports, but still gives the arg after the cluster
+    fn find_value_taker(&self, token: &str) -> Option<usize> {
+        let token: &str = self.tidy(token);
+        match self.tokenizer.split(token) {
+            Token::Flag(flag) => self.find_option(flag).filter(|index| self.tokenizer.takes_value(*index)),
+            _ => self.tokenizer.find_value_taker(token),
+        }
+    }
+
+    /// calls `found` with the index of every option `token` is a flag of, more than one if it's a cluster of short flags
+    fn for_each_flag(&self, token: &str, mut found: impl FnMut(usize)) {
+        let token: &str = self.tidy(token);
+        match self.tokenizer.split(token) {
+            Token::Flag(flag) => if let Some(index) = self.find_option(flag) {
+                found(index);
+            },
+            _ => self.tokenizer.for_each_flag(token, |index, _| found(index)),
+        }
+    }
+
+    /// returns which options have a flag in `args` (ignoring the first, the program name), and where the first `--` is if there is one,
+    /// nothing after it is scanned, since it ends the options (see `option_parser::find_terminator()`)
+    ///
+    /// this only recognizes flags, by the same rules as the parser, so it's much cheaper than parsing,
+    /// ei. to check if `--help` was given before loading anything slow, nothing is allocated for each arg
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::Classifier, option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-h", "--help", "Show help").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///     ];
+    ///     let classifier = Classifier::new(&valid_options, &ParserSettings::new());
+    ///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    ///
+    ///     let scan = classifier.quick_scan(&to_args(&["foo.exe", "-rh", "--output=out.txt", "path"]));
+    ///     assert_eq!(scan.get_present(), &vec![true, true, true]);
+    ///     assert_eq!(scan.get_terminator(), None);
+    ///
+    ///     //nothing after a terminator is a flag
+    ///     let scan = classifier.quick_scan(&to_args(&["foo.exe", "-r", "--", "--help"]));
+    ///     assert_eq!(scan.get_present(), &vec![false, true, false]);
+    ///     assert_eq!(scan.get_terminator(), Some(2));
+    /// ```
+    ///
+    /// it agrees with the parser on which options are present, and where the options end
+    /// ```
+    /// use clia::{classifier::Classifier, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-i", "--ignore-case", "Ignore case").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+    ///     ];
+    ///     let classifier = Classifier::new(&valid_options, &ParserSettings::new());
+    ///     let pool = ["-r", "--recursive", "-ri", "-ir", "-rio", "-rf", "--output=a.txt", "--filter=rs,py", "-o", "a.txt", "path", "-z", "-rz", "--output=", "--"];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATHS", "Paths to search in").variadic(true).optional(true)];
+    ///
+    ///     let mut seed: u64 = 7;
+    ///     let mut next = |bound: u64| {seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407); ((seed >> 33) % bound) as usize};
+    ///     let mut parsed: usize = 0;
+    ///     for _ in 0..2000 {
+    ///         let mut args: Vec<String> = vec![String::from("foo.exe")];
+    ///         args.extend((0..next(5)).map(|_| pool[next(pool.len() as u64)].to_string()));
+    ///
+    ///         if let Ok(parser) = Parser::new(&args, &valid_options, &expected_parameters) {
+    ///             parsed += 1;
+    ///             let scan = classifier.quick_scan(&args);
+    ///             assert_eq!(&parser.get_option_arguments_found().iter().map(|option| option.get_present()).collect::<Vec<bool>>(), scan.get_present(), "{:?}", args);
+    ///             //everything after the terminator is given to the parameters as it was typed
+    ///             if let Some(terminator) = scan.get_terminator() {
+    ///                 assert!(parser.get_parameter_arguments_found()[0].get_values().ends_with(&args[terminator + 1..]), "{:?}", args);
+    ///             }
+    ///         }
+    ///     }
+    ///     assert!(parsed > 100);
+    /// ```
+    ///
+    /// it splits clusters and values after an '=' like the parser, with and without dash mistakes corrected, so a long flag with one dash is never a cluster
+    /// ```
+    /// use clia::{classifier::Classifier, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-i", "--ignore-case", "Ignore case").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("", "--ri", "Search through subdirectories, ignoring case").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("", "--offset", "Lines to skip").unwrap(), "OFFSET"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATHS", "Paths to search in").variadic(true).optional(true)];
+    ///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    ///     let pool = ["-ri", "-ir", "-rio", "-ior", "--ri", "-r", "--output=-i", "--offset=-5", "--offset=--ri", "-o", "a.txt", "path", "-z", "--"];
+    ///
+    ///     let mut seed: u64 = 13;
+    ///     let mut next = |bound: u64| {seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407); ((seed >> 33) % bound) as usize};
+    ///     for settings in [ParserSettings::new(), ParserSettings::new().correct_dash_mistakes(true)] {
+    ///         let classifier = Classifier::new(&valid_options, &settings);
+    ///         let mut parsed: usize = 0;
+    ///         for _ in 0..2000 {
+    ///             let mut args: Vec<String> = vec![String::from("foo.exe")];
+    ///             args.extend((0..next(5)).map(|_| pool[next(pool.len() as u64)].to_string()));
+    ///
+    ///             if let Ok(parser) = Parser::new_with_settings(&args, &valid_options, &expected_parameters, &settings) {
+    ///                 parsed += 1;
+    ///                 assert_eq!(&parser.get_option_arguments_found().iter().map(|option| option.get_present()).collect::<Vec<bool>>(), classifier.quick_scan(&args).get_present(), "{:?}", args);
+    ///             }
+    ///         }
+    ///         assert!(parsed > 100);
+    ///     }
+    ///
+    ///     //`-ri` is a mistyped `--ri`, not `-r` and `-i`
+    ///     let args = to_args(&["foo.exe", "-ri"]);
+    ///     assert_eq!(Classifier::new(&valid_options, &ParserSettings::new()).quick_scan(&args).get_present(), &vec![false; 5]);
+    ///     assert_eq!(Classifier::new(&valid_options, &ParserSettings::new().correct_dash_mistakes(true)).quick_scan(&args).get_present(), &vec![false, false, true, false, false]);
+    /// ```
+    pub fn quick_scan(&self, args: &[String]) -> QuickScan {
+        //DATA
+        let mut present: Vec<bool> = vec![false; self.tokenizer.get_option_count()];
+        let terminator: Option<usize> = option_parser::find_terminator(args);
+
+        for arg in option_parser::before_terminator(args).iter().skip(1) {
+            if option_parser::is_flag(arg) {
+                self.for_each_flag(arg, |option| present[option] = true);
+            }
+        }
+
+        QuickScan { present, terminator }
+    }
+
+    /// returns what every arg in `args` is (ignoring the first, the program name), down to its parts, and which of `expected_parameters` each positional is given,
+    /// ei. to syntax highlight a command line as it's typed
+    ///
+    /// # Notes:
+    /// - args are classified like `Classifier::classify()`, and positionals are given to parameters the same way the parser gives them,
+    ///   so only if there are enough of them for every required parameter, and never the ones left over
+    /// - the first `--` is a terminator, and every arg after it is a positional, like the parser, see `option_parser::find_terminator()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::{Classifier, TokenClass}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("QUERY", "String to search for"), ClParameter::new("PATH", "Path to search in")];
+    ///     let args: Vec<String> = ["foo.exe", "-r", "--output", "out.txt", "fn", "src"].iter().map(|arg| arg.to_string()).collect();
+    ///
+    ///     let tokens = Classifier::new(&valid_options, &ParserSettings::new()).annotate(&args, &expected_parameters);
+    ///     assert_eq!(tokens.iter().map(|token| token.get_index()).collect::<Vec<usize>>(), vec![1, 2, 3, 4, 5]);
+    ///     assert_eq!(
+    ///         tokens.iter().map(|token| token.get_class()).collect::<Vec<TokenClass>>(),
+    ///         vec![TokenClass::KnownFlag(0), TokenClass::KnownFlag(1), TokenClass::ValueFor(1), TokenClass::Positional, TokenClass::Positional]
+    ///     );
+    ///     assert_eq!(tokens[2].get_option(), Some(1));
+    ///     assert_eq!(tokens[3].get_parameter(), Some("QUERY"));
+    ///     assert_eq!(tokens[4].get_parameter(), Some("PATH"));
+    /// ```
+    ///
+    /// it agrees with the parser on which arg each parameter is given
+    /// ```
+    /// use clia::{classifier::Classifier, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("QUERY", "String to search for"), ClParameter::new("PATH", "Path to search in").optional(true)];
+    ///     let classifier = Classifier::new(&valid_options, &ParserSettings::new());
+    ///     let pool = ["-r", "-ro", "--output=a.txt", "a.txt", "b.txt", "fn", "src", "--"];
+    ///
+    ///     let mut seed: u64 = 11;
+    ///     let mut next = |bound: u64| {seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407); ((seed >> 33) % bound) as usize};
+    ///     let mut parsed: usize = 0;
+    ///     for _ in 0..1000 {
+    ///         let mut args: Vec<String> = vec![String::from("foo.exe")];
+    ///         args.extend((0..next(6)).map(|_| pool[next(pool.len() as u64)].to_string()));
+    ///
+    ///         if let Ok(parser) = Parser::new(&args, &valid_options, &expected_parameters) {
+    ///             parsed += 1;
+    ///             let tokens = classifier.annotate(&args, &expected_parameters);
+    ///             for parameter in parser.get_parameter_arguments_found() {
+    ///                 let given: Vec<&str> = tokens.iter().filter(|token| token.get_parameter() == Some(parameter.get_name())).map(|token| args[token.get_index()].as_str()).collect();
+    ///                 assert_eq!(given.first().copied().unwrap_or_default(), parameter.get_data(), "{:?}", args);
+    ///             }
+    ///         }
+    ///     }
+    ///     assert!(parsed > 100);
+    /// ```
+    pub fn annotate(&self, args: &[String], expected_parameters: &[parameter_args::ClParameter]) -> Vec<AnnotatedToken> {
+        //DATA
+        let terminator: usize = option_parser::find_terminator(args).unwrap_or(args.len());
+        let positionals: Vec<usize> = parameter_parser::find_positionals(args, self, 1);
+        let parameters: Vec<Option<usize>> = parameter_parser::find_parameter_indices(expected_parameters, &positionals);
+
+        (1..args.len()).map(|index| {
+            //nothing after the terminator is a flag
+            let class: TokenClass = if index > terminator {TokenClass::Positional} else {self.classify(&args[index], Some(&args[index - 1]))};
+            AnnotatedToken {
+                index,
+                class,
+                parameter: parameters.get(index).copied().flatten().map(|parameter| expected_parameters[parameter].get_name().to_string()),
+                spans: self.find_spans(&args[index], class),
+            }
+        }).collect()
+    }
+
+    /// returns the parts of `token`, which is a `class`: the flag and the list/data of a long flag given it after an '=',
+    /// the flag of every letter of a cluster of short flags, or otherwise all of it
+    fn find_spans(&self, token: &str, class: TokenClass) -> Vec<TokenSpan> {
+        let whole: Vec<TokenSpan> = vec![TokenSpan { range: 0..token.len(), class }];
+        if !matches!(class, TokenClass::KnownFlag(_)) {
+            return whole;
+        }
+
+        match self.tokenizer.split(self.tidy(token)) {
+            //a long flag and its list/data, split at the '='
+            Token::Equals(flag, value) => {
+                let index: usize = self.tokenizer.get_option(flag).unwrap_or_default();
+                let mut spans: Vec<TokenSpan> = vec![TokenSpan { range: 0..flag.len(), class: TokenClass::KnownFlag(index) }];
+                if !value.is_empty() {
+                    spans.push(TokenSpan { range: flag.len() + 1..flag.len() + 1 + value.len(), class: TokenClass::ValueFor(index) });
+                }
+                spans
+            },
+            //a cluster, the dash goes with the first letter
+            Token::Cluster(letters) => letters.char_indices()
+            .filter_map(|(position, letter)| self.tokenizer.get_letter_option(letter).map(|index| TokenSpan {
+                range: if position == 0 {0..2} else {position + 1..position + 1 + letter.len_utf8()},
+                class: TokenClass::KnownFlag(index),
+            }))
+            .collect(),
+            Token::Flag(_) | Token::Positional => whole,
+        }
+    }
+}
+
+/// which options have a flag in some args, and where the first `--` in them is, see `Classifier::quick_scan()`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QuickScan {
+    present: Vec<bool>,
+    terminator: Option<usize>,
+}
+impl QuickScan {
+    //getter methods
+    /// get a reference to whether each option has a flag in the args, in the order of the options the Classifier was made from
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::Classifier, option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let scan = Classifier::new(&valid_options, &ParserSettings::new()).quick_scan(&[String::from("foo.exe"), String::from("path")]);
+    ///     assert_eq!(scan.get_present(), &vec![false]);
+    /// ```
+    pub fn get_present(&self) -> &Vec<bool> {&self.present}
+
+    /// get the index of the first `--` in the args
+    ///
+    /// # None
+    /// - there is no `--` in the args
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::Classifier, parser_settings::ParserSettings};
+    /// //...
+    ///     let scan = Classifier::new(&[], &ParserSettings::new()).quick_scan(&[String::from("foo.exe"), String::from("--"), String::from("--")]);
+    ///     assert_eq!(scan.get_terminator(), Some(1));
+    /// ```
+    pub fn get_terminator(&self) -> Option<usize> {self.terminator}
+}
+
+/// a part of an arg, and what it is, see `AnnotatedToken::get_spans()`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenSpan {
+    range: Range<usize>,
+    class: TokenClass,
+}
+impl TokenSpan {
+    //getter methods
+    /// get where the part is in its arg, in bytes
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::Classifier, option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE")];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--output=out.txt")];
+    ///     let tokens = Classifier::new(&valid_options, &ParserSettings::new()).annotate(&args, &[]);
+    ///     assert_eq!(tokens[0].get_spans()[1].get_range(), 9..16);
+    /// ```
+    pub fn get_range(&self) -> Range<usize> {self.range.clone()}
+
+    /// get what the part is
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::{Classifier, TokenClass}, option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE")];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--output=out.txt")];
+    ///     let tokens = Classifier::new(&valid_options, &ParserSettings::new()).annotate(&args, &[]);
+    ///     assert_eq!(tokens[0].get_spans()[1].get_class(), TokenClass::ValueFor(0));
+    /// ```
+    pub fn get_class(&self) -> TokenClass {self.class}
+}
+
+/// an arg, what it is, and what its parts are, see `Classifier::annotate()`
+///
+/// # Examples
+/// ```
+/// use clia::{classifier::TokenClass, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec};
+/// //...
+///     let spec = CliSpec::new(&[
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag(&ClOptionInfo::new("-i", "--ignore-case", "Ignore case").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+///     ], &[ClParameter::new("PATH", "Path to search in")]);
+///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+///     let spans = |token: &clia::classifier::AnnotatedToken| token.get_spans().iter().map(|span| (span.get_range(), span.get_class())).collect::<Vec<_>>();
+///
+///     //a flag and its data in separate args
+///     let tokens = spec.annotate(&to_args(&["foo.exe", "--output", "out.txt", "src"]));
+///     assert_eq!(spans(&tokens[0]), vec![(0..8, TokenClass::KnownFlag(2))]);
+///     assert_eq!(spans(&tokens[1]), vec![(0..7, TokenClass::ValueFor(2))]);
+///     assert_eq!((tokens[2].get_class(), tokens[2].get_parameter()), (TokenClass::Positional, Some("PATH")));
+///
+///     //given after an '='
+///     let tokens = spec.annotate(&to_args(&["foo.exe", "--output=out.txt", "src"]));
+///     assert_eq!(tokens[0].get_class(), TokenClass::KnownFlag(2));
+///     assert_eq!(spans(&tokens[0]), vec![(0..8, TokenClass::KnownFlag(2)), (9..16, TokenClass::ValueFor(2))]);
+///     assert_eq!(tokens[1].get_parameter(), Some("PATH"));
+///
+///     //a cluster, whose last flag takes the next arg
+///     let tokens = spec.annotate(&to_args(&["foo.exe", "-rio", "out.txt", "src"]));
+///     assert_eq!(tokens[0].get_class(), TokenClass::KnownFlag(2));
+///     assert_eq!(spans(&tokens[0]), vec![(0..2, TokenClass::KnownFlag(0)), (2..3, TokenClass::KnownFlag(1)), (3..4, TokenClass::KnownFlag(2))]);
+///     assert_eq!(tokens[1].get_class(), TokenClass::ValueFor(2));
+///
+///     //unknown flags, which the parser reports
+///     let tokens = spec.annotate(&to_args(&["foo.exe", "-z", "src"]));
+///     assert_eq!(spans(&tokens[0]), vec![(0..2, TokenClass::UnknownFlag)]);
+///     assert_eq!(tokens[0].get_option(), None);
+///
+///     //a terminator, nothing after it is a flag
+///     let tokens = spec.annotate(&to_args(&["foo.exe", "-r", "--", "-i"]));
+///     assert_eq!(spans(&tokens[1]), vec![(0..2, TokenClass::Terminator)]);
+///     assert_eq!((tokens[2].get_class(), tokens[2].get_parameter()), (TokenClass::Positional, Some("PATH")));
+///
+///     //positionals left over aren't given to a parameter
+///     let tokens = spec.annotate(&to_args(&["foo.exe", "src", "tests"]));
+///     assert_eq!((tokens[0].get_parameter(), tokens[1].get_parameter()), (None, Some("PATH")));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnnotatedToken {
+    index: usize,
+    class: TokenClass,
+    parameter: Option<String>,
+    spans: Vec<TokenSpan>,
+}
+impl AnnotatedToken {
+    //getter methods
+    /// get the index of the arg in the args
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     let tokens = CliSpec::new(&[], &[]).annotate(&[String::from("foo.exe"), String::from("src")]);
+    ///     assert_eq!(tokens[0].get_index(), 1);
+    /// ```
+    pub fn get_index(&self) -> usize {self.index}
+
+    /// get what the arg is, for a cluster of short flags or a long flag given its list/data after an '=', the option of its last flag, see `Classifier::classify()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::TokenClass, spec::CliSpec};
+    /// //...
+    ///     let tokens = CliSpec::new(&[], &[]).annotate(&[String::from("foo.exe"), String::from("src")]);
+    ///     assert_eq!(tokens[0].get_class(), TokenClass::Positional);
+    /// ```
+    pub fn get_class(&self) -> TokenClass {self.class}
+
+    /// get the index of the option the arg is a flag, or the list/data, of
+    ///
+    /// # None
+    /// - the arg isn't a flag of an option, or the list/data of one
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())], &[]);
+    ///     let tokens = spec.annotate(&[String::from("foo.exe"), String::from("-r"), String::from("src")]);
+    ///     assert_eq!(tokens[0].get_option(), Some(0));
+    ///     assert_eq!(tokens[1].get_option(), None);
+    /// ```
+    pub fn get_option(&self) -> Option<usize> {
+        match self.class {
+            TokenClass::KnownFlag(index) | TokenClass::ValueFor(index) => Some(index),
+            TokenClass::UnknownFlag | TokenClass::Terminator | TokenClass::Positional => None,
+        }
+    }
+
+    /// get a reference to the name of the parameter the arg is given
+    ///
+    /// # None
+    /// - the arg isn't a positional, or isn't given to a parameter
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[], &[ClParameter::new("FILES", "Files to search").variadic(true)]);
+    ///     let tokens = spec.annotate(&[String::from("foo.exe"), String::from("a.txt"), String::from("b.txt")]);
+    ///     assert_eq!((tokens[0].get_parameter(), tokens[1].get_parameter()), (Some("FILES"), Some("FILES")));
+    /// ```
+    pub fn get_parameter(&self) -> Option<&str> {self.parameter.as_deref()}
+
+    /// get a reference to the parts of the arg, in order, ei. the flag and the data of `--format=json`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::TokenClass, spec::CliSpec};
+    /// //...
+    ///     let tokens = CliSpec::new(&[], &[]).annotate(&[String::from("foo.exe"), String::from("src")]);
+    ///     assert_eq!(tokens[0].get_spans().len(), 1);
+    ///     assert_eq!(tokens[0].get_spans()[0].get_range(), 0..3);
+    ///     assert_eq!(tokens[0].get_spans()[0].get_class(), TokenClass::Positional);
+    /// ```
+    pub fn get_spans(&self) -> &Vec<TokenSpan> {&self.spans}
+}
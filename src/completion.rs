@@ -0,0 +1,571 @@
+//! # Completion
+//! 'completion' is a module containing utilities for
+//! generating shell completion scripts from the options and parameters of a program, ei. for fish
+//!
+//! the scripts are generated as strings, so it's up to the program to print them or write them where the shell looks for completions
+//!
+//! the scripts are generated from a `completion::CompletionSpec` (see `completion::to_spec()`), which can also be written as json for other tools to use
+//!
+//! completions that depend on runtime state (ei. names read from a config file) can't be in a static script,
+//! so `completion::generate_fish_dynamic()` generates one that asks the program itself, through a hidden `__complete` subcommand, see `completion::respond()`
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use std::fmt;
+use std::io::Write;
+
+use crate::option_args;
+use crate::option_parser;
+use crate::parse_error::escape_json;
+use crate::parameter_args;
+use crate::parser_settings::ParserSettings;
+
+/// the hidden subcommand that shells call to get completion candidates at runtime, see `completion::respond()`
+pub const COMPLETE_SUBCOMMAND: &str = "__complete";
+
+/// what printed candidate tells the shell to complete file paths instead
+pub const FILE_PATH_MARKER: &str = "__files__";
+
+/// something the word being completed could be
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Candidate {
+    /// the flag of an option, ei. `--format`
+    Flag(String),
+    /// one of the subcommands set with `ParserSettings::subcommands()`
+    Subcommand(String),
+    /// one of the allowed values of the option whose flag came before the word
+    Value(String),
+    /// a path on the file system, which the shell knows how to complete itself
+    FilePath,
+}
+impl fmt::Display for Candidate {
+    /// the candidate as printed by `completion::respond()`, a file path is printed as `completion::FILE_PATH_MARKER`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::completion::Candidate;
+    /// //...
+    ///     assert_eq!(Candidate::Flag(String::from("--format")).to_string(), "--format");
+    ///     assert_eq!(Candidate::FilePath.to_string(), "__files__");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Candidate::Flag(text) | Candidate::Subcommand(text) | Candidate::Value(text) => write!(f, "{}", text),
+            Candidate::FilePath => write!(f, "{}", FILE_PATH_MARKER),
+        }
+    }
+}
+
+/// how the value an option takes is completed, see `CompletionOption::get_hint()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueHint {
+    /// the option doesn't take a value
+    None,
+    /// the option takes any value, which is completed as a file path
+    FilePath,
+    /// the option takes one of its allowed values, see `CompletionOption::get_values()`
+    Values,
+}
+impl ValueHint {
+    /// get the snake_case name of this hint, as used in json
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::completion::ValueHint;
+    /// //...
+    ///     assert_eq!(ValueHint::FilePath.get_name(), "file_path");
+    /// ```
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ValueHint::None => "none",
+            ValueHint::FilePath => "file_path",
+            ValueHint::Values => "values",
+        }
+    }
+}
+
+/// an option of a completion spec, its flags, description, and how its value is completed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompletionOption {
+    short_flag: String,
+    long_flag: String,
+    description: String,
+    value_name: Option<String>,
+    hint: ValueHint,
+    values: Vec<String>,
+}
+impl CompletionOption {
+    /// creates a new CompletionOption describing `option`
+    fn new(option: &option_args::ClOption) -> CompletionOption {
+        let value_name: Option<&str> = match option {
+            option_args::ClOption::FlagList { list_name, .. } => Some(list_name),
+            option_args::ClOption::FlagData { data_name, .. } => Some(data_name),
+            _ => None,
+        };
+        let (hint, values): (ValueHint, Vec<String>) = match option.get_allowed_values() {
+            Some(allowed_values) if !allowed_values.is_empty() => (ValueHint::Values, allowed_values.clone()),
+            Some(_) => (ValueHint::FilePath, Vec::new()),
+            None => (ValueHint::None, Vec::new()),
+        };
+        CompletionOption {
+            short_flag: option.get_short_flag().to_string(),
+            long_flag: option.get_long_flag().to_string(),
+            description: option.get_description().to_string(),
+            value_name: value_name.map(|value_name| value_name.to_string()),
+            hint,
+            values,
+        }
+    }
+
+    /// returns this option as a json object, with its flags, description, value name, hint, and values
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"short_flag\":\"{}\",\"long_flag\":\"{}\",\"description\":\"{}\",\"value_name\":{},\"hint\":\"{}\",\"values\":[{}]}}",
+            escape_json(&self.short_flag), escape_json(&self.long_flag), escape_json(&self.description),
+            self.value_name.as_ref().map_or(String::from("null"), |value_name| format!("\"{}\"", escape_json(value_name))),
+            self.hint.get_name(),
+            self.values.iter().map(|value| format!("\"{}\"", escape_json(value))).collect::<Vec<String>>().join(","),
+        )
+    }
+
+    //getter methods
+    /// get a reference to the short flag of the option, empty if it doesn't have one
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{completion, option_args::{ClOption, ClOptionInfo}};
+    /// //...
+    ///     let spec = completion::to_spec(&[ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())], &[]);
+    ///     assert_eq!(spec.get_options()[0].get_short_flag(), "-r");
+    /// ```
+    pub fn get_short_flag(&self) -> &str {&self.short_flag}
+
+    /// get a reference to the long flag of the option, empty if it doesn't have one
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{completion, option_args::{ClOption, ClOptionInfo}};
+    /// //...
+    ///     let spec = completion::to_spec(&[ClOption::new_flag(&ClOptionInfo::new("-r", "", "Search through subdirectories").unwrap())], &[]);
+    ///     assert_eq!(spec.get_options()[0].get_long_flag(), "");
+    /// ```
+    pub fn get_long_flag(&self) -> &str {&self.long_flag}
+
+    /// get a reference to the description of the option
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{completion, option_args::{ClOption, ClOptionInfo}};
+    /// //...
+    ///     let spec = completion::to_spec(&[ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())], &[]);
+    ///     assert_eq!(spec.get_options()[0].get_description(), "Search through subdirectories");
+    /// ```
+    pub fn get_description(&self) -> &str {&self.description}
+
+    /// get a reference to the name of the list/data the option takes, ei. `FORMAT`
+    ///
+    /// # None
+    /// - the option doesn't take a list/data
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{completion, option_args::{ClOption, ClOptionInfo}};
+    /// //...
+    ///     let spec = completion::to_spec(&[ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT")], &[]);
+    ///     assert_eq!(spec.get_options()[0].get_value_name(), Some("FORMAT"));
+    /// ```
+    pub fn get_value_name(&self) -> Option<&str> {self.value_name.as_deref()}
+
+    /// get how the value the option takes is completed
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{completion::{self, ValueHint}, option_args::{ClOption, ClOptionInfo}};
+    /// //...
+    ///     let spec = completion::to_spec(&[ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE")], &[]);
+    ///     assert_eq!(spec.get_options()[0].get_hint(), ValueHint::FilePath);
+    /// ```
+    pub fn get_hint(&self) -> ValueHint {self.hint}
+
+    /// get a reference to the values the option allows, empty unless its hint is `ValueHint::Values`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{completion, option_args::{ClOption, ClOptionInfo}};
+    /// //...
+    ///     let spec = completion::to_spec(&[ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"])], &[]);
+    ///     assert_eq!(spec.get_options()[0].get_values(), &vec![String::from("DEFAULT"), String::from("BULLET")]);
+    /// ```
+    pub fn get_values(&self) -> &Vec<String> {&self.values}
+}
+
+/// a parameter of a completion spec, its name and description
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompletionParam {
+    name: String,
+    description: String,
+}
+impl CompletionParam {
+    /// creates a new CompletionParam describing `parameter`
+    fn new(parameter: &parameter_args::ClParameter) -> CompletionParam {
+        CompletionParam {
+            name: parameter.get_name().to_string(),
+            description: parameter.get_description().to_string(),
+        }
+    }
+
+    //getter methods
+    /// get a reference to the name of the parameter
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{completion, parameter_args::ClParameter};
+    /// //...
+    ///     let spec = completion::to_spec(&[], &[ClParameter::new("PATH", "Path to search in")]);
+    ///     assert_eq!(spec.get_parameters()[0].get_name(), "PATH");
+    /// ```
+    pub fn get_name(&self) -> &str {&self.name}
+
+    /// get a reference to the description of the parameter
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{completion, parameter_args::ClParameter};
+    /// //...
+    ///     let spec = completion::to_spec(&[], &[ClParameter::new("PATH", "Path to search in")]);
+    ///     assert_eq!(spec.get_parameters()[0].get_description(), "Path to search in");
+    /// ```
+    pub fn get_description(&self) -> &str {&self.description}
+}
+
+/// stores everything a shell needs to complete the args of a program, which the completion scripts are generated from, see `completion::to_spec()`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompletionSpec {
+    options: Vec<CompletionOption>,
+    parameters: Vec<CompletionParam>,
+}
+impl CompletionSpec {
+    /// returns this spec as a json object, with its options and parameters, so tools other than the shells can use it
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{completion, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the \"output\"").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"]),
+    ///     ];
+    ///     let spec = completion::to_spec(&valid_options, &[ClParameter::new("PATH", "Path to search in")]);
+    ///
+    ///     assert_eq!(spec.to_json(), concat!(
+    ///         r#"{"options":["#,
+    ///         r#"{"short_flag":"-r","long_flag":"--recursive","description":"Search through subdirectories","value_name":null,"hint":"none","values":[]},"#,
+    ///         r#"{"short_flag":"-F","long_flag":"--format","description":"Format the \"output\"","value_name":"FORMAT","hint":"values","values":["DEFAULT","BULLET"]}"#,
+    ///         r#"],"parameters":[{"name":"PATH","description":"Path to search in"}]}"#,
+    ///     ));
+    ///     assert_eq!(completion::to_spec(&[], &[]).to_json(), r#"{"options":[],"parameters":[]}"#);
+    /// ```
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"options\":[{}],\"parameters\":[{}]}}",
+            self.options.iter().map(|option| option.to_json()).collect::<Vec<String>>().join(","),
+            self.parameters.iter().map(|parameter| format!("{{\"name\":\"{}\",\"description\":\"{}\"}}", escape_json(&parameter.name), escape_json(&parameter.description))).collect::<Vec<String>>().join(","),
+        )
+    }
+
+    //getter methods
+    /// get a reference to the options, in the order they're defined
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::completion;
+    /// //...
+    ///     assert!(completion::to_spec(&[], &[]).get_options().is_empty());
+    /// ```
+    pub fn get_options(&self) -> &Vec<CompletionOption> {&self.options}
+
+    /// get a reference to the parameters, in the order they're expected
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::completion;
+    /// //...
+    ///     assert!(completion::to_spec(&[], &[]).get_parameters().is_empty());
+    /// ```
+    pub fn get_parameters(&self) -> &Vec<CompletionParam> {&self.parameters}
+}
+
+/// returns the completion spec of a program with `valid_options` and `expected_parameters`, which the completion scripts are generated from
+///
+/// # Notes:
+/// - options that take a list or data are completed with their allowed values (`ValueHint::Values`) if they have any, otherwise as file paths (`ValueHint::FilePath`)
+/// - options that don't take a value have `ValueHint::None`
+///
+/// # Examples
+/// ```
+/// use clia::{completion::{self, ValueHint}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"]),
+///         ClOption::new_choice(&ClOptionInfo::new("-c", "--color", "When to color the output").unwrap(), "WHEN", &["auto", "always", "never"], 0),
+///     ];
+///     let spec = completion::to_spec(&valid_options, &[ClParameter::new("PATH", "Path to search in")]);
+///
+///     assert_eq!(
+///         spec.get_options().iter().map(|option| (option.get_long_flag(), option.get_value_name(), option.get_hint(), option.get_values().clone())).collect::<Vec<_>>(),
+///         vec![
+///             ("--recursive", None, ValueHint::None, vec![]),
+///             ("--filter", Some("EXTENSIONS"), ValueHint::FilePath, vec![]),
+///             ("--format", Some("FORMAT"), ValueHint::Values, vec![String::from("DEFAULT"), String::from("BULLET")]),
+///             ("--color", Some("WHEN"), ValueHint::Values, vec![String::from("auto"), String::from("always"), String::from("never")]),
+///         ]
+///     );
+///     assert_eq!(spec.get_parameters()[0].get_name(), "PATH");
+/// ```
+pub fn to_spec(valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter]) -> CompletionSpec {
+    CompletionSpec {
+        options: valid_options.iter().map(CompletionOption::new).collect(),
+        parameters: expected_parameters.iter().map(CompletionParam::new).collect(),
+    }
+}
+
+/// generates a fish completion script for `program_name`, with one `complete` line per option in `valid_options`
+///
+/// # Notes:
+/// - options are completed by their short flag (`-s`), long flag (`-l`), and description (`-d`), flags are given without their dashes
+/// - options that take a list or data require an argument (`-r`), and if they have allowed values those are completed instead of files (`-x -a`),
+///   each escaped, so one with a space in it is still one candidate
+/// - if `expected_parameters` is empty, files aren't completed for the program at all (`-f`)
+///
+/// # Examples
+/// ```
+/// use clia::{completion, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"]),
+///         ClOption::new_flag_data(&ClOptionInfo::new("", "--output", "File to write to").unwrap(), "FILE"),
+///     ];
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+///     let script: String = completion::generate_fish("foo", &valid_options, &expected_parameters);
+///
+///     assert!(script.contains("complete -c foo -s r -l recursive -d 'Search through subdirectories'\n"));
+///     assert!(script.contains("complete -c foo -s F -l format -d 'Format the output' -x -a 'DEFAULT BULLET'\n"));
+///     assert!(script.contains("complete -c foo -l output -d 'File to write to' -r\n"));
+///     assert!(!script.contains("complete -c foo -f\n")); //PATH is completed with files
+/// ```
+///
+/// descriptions are escaped for fish
+/// ```
+/// use clia::{completion, option_args::{ClOption, ClOptionInfo}};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-q", "--quiet", "Don't print C:\\ paths").unwrap())];
+///     let script: String = completion::generate_fish("foo", &valid_options, &[]);
+///
+///     assert!(script.contains("complete -c foo -s q -l quiet -d 'Don\\'t print C:\\\\ paths'\n"));
+///     assert!(script.starts_with("complete -c foo -f\n")); //no parameters, so no files
+/// ```
+///
+/// and so are allowed values, each on its own, so fish doesn't split them
+/// ```
+/// use clia::{completion, option_args::{ClOption, ClOptionInfo}};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["two words", "it's", "plain"]),
+///     ];
+///     let script: String = completion::generate_fish("foo", &valid_options, &[]);
+///
+///     //fish reads `two\ words it\'s plain` from the quotes, which it splits into `two words`, `it's` and `plain`
+///     assert!(script.contains("complete -c foo -s F -l format -d 'Format the output' -x -a 'two\\\\ words it\\\\\\'s plain'\n"));
+/// ```
+pub fn generate_fish(program_name: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter]) -> String {
+    generate_fish_from_spec(program_name, &to_spec(valid_options, expected_parameters))
+}
+
+/// generates a fish completion script for `program_name` from `spec`, see `completion::generate_fish()`
+///
+/// # Examples
+/// ```
+/// use clia::{completion, option_args::{ClOption, ClOptionInfo}};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+///     let spec = completion::to_spec(&valid_options, &[]);
+///
+///     assert_eq!(completion::generate_fish_from_spec("foo", &spec), completion::generate_fish("foo", &valid_options, &[]));
+/// ```
+pub fn generate_fish_from_spec(program_name: &str, spec: &CompletionSpec) -> String {
+    //DATA
+    let mut script: String = String::new();
+
+    if spec.parameters.is_empty() {
+        script += format!("complete -c {} -f\n", program_name).as_str();
+    }
+
+    for option in spec.options.iter() {
+        let mut line: String = format!("complete -c {}", program_name);
+        if let Some(short) = option.short_flag.strip_prefix('-').filter(|short| !short.is_empty()) {
+            line += format!(" -s {}", short).as_str();
+        }
+        if let Some(long) = option.long_flag.strip_prefix("--").filter(|long| !long.is_empty()) {
+            line += format!(" -l {}", long).as_str();
+        }
+        line += format!(" -d {}", fish_quote(&option.description)).as_str();
+
+        match option.hint {
+            ValueHint::Values => line += format!(" -x -a {}", fish_quote(&option.values.iter().map(|value| fish_escape(value)).collect::<Vec<String>>().join(" "))).as_str(),
+            ValueHint::FilePath => line += " -r",
+            ValueHint::None => {},
+        }
+
+        script += line.as_str();
+        script.push('\n');
+    }
+
+    script
+}
+
+/// wraps `value` in single quotes, escaping the backslashes and single quotes inside of it, as fish expects
+fn fish_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// escapes every character of `value` fish would split or expand it at, so it's one candidate of the `-a` of `complete`, which fish splits like a command line
+fn fish_escape(value: &str) -> String {
+    let mut escaped: String = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            ' ' | '\\' | '\'' | '"' | '$' | '*' | '?' | '~' | '#' | '(' | ')' | '{' | '}' | '[' | ']' | '<' | '>' | '&' | '|' | ';' | '%' => {
+                escaped.push('\\');
+                escaped.push(c);
+            },
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// returns the candidates for `words[cursor_index]`, where `words` is the partial command line (starting with the program name) and the word at `cursor_index` is being typed
+///
+/// # Notes:
+/// - after the flag of an option that takes a list or data, its allowed values starting with the word are the candidates, or a file path if it has none
+/// - a word starting with a `-` is completed with the flags starting with it, including those of the subcommand given
+/// - the first word after the program name is completed with the subcommands starting with it
+/// - anything else is completed as a file path, as are words nothing else matches
+/// - a `cursor_index` past the end of `words` completes an empty word
+///
+/// # Examples
+/// ```
+/// use clia::{completion::{self, Candidate}, option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"]),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-p", "--profile", "Profile to use").unwrap(), "NAME"),
+///     ];
+///     let settings = ParserSettings::new().subcommands(&["build", "bench", "test"]);
+///     let complete = |words: &[&str], cursor_index: usize| {
+///         let words: Vec<String> = words.iter().map(|word| word.to_string()).collect();
+///         completion::complete(&valid_options, &settings, &words, cursor_index)
+///     };
+///
+///     //subcommands
+///     assert_eq!(complete(&["foo", "b"], 1), vec![Candidate::Subcommand(String::from("build")), Candidate::Subcommand(String::from("bench"))]);
+///     //flags
+///     assert_eq!(complete(&["foo", "build", "--re"], 2), vec![Candidate::Flag(String::from("--recursive"))]);
+///     assert_eq!(complete(&["foo", "-"], 1).len(), 6);
+///     //allowed values, and a file path for options that allow anything
+///     assert_eq!(complete(&["foo", "--format", "b"], 2), vec![Candidate::Value(String::from("BULLET"))]);
+///     assert_eq!(complete(&["foo", "-F"], 2), vec![Candidate::Value(String::from("DEFAULT")), Candidate::Value(String::from("BULLET"))]);
+///     assert_eq!(complete(&["foo", "--profile", ""], 2), vec![Candidate::FilePath]);
+///     //parameters
+///     assert_eq!(complete(&["foo", "build", "src/"], 2), vec![Candidate::FilePath]);
+/// ```
+pub fn complete(valid_options: &[option_args::ClOption], settings: &ParserSettings, words: &[String], cursor_index: usize) -> Vec<Candidate> {
+    //DATA
+    let word: &str = words.get(cursor_index).map_or("", |word| word.as_str());
+    let subcommand_options: &[option_args::ClOption] = words.get(1)
+        .filter(|_| cursor_index > 1)
+        .and_then(|subcommand| settings.get_subcommand_options(subcommand))
+        .map_or(&[], |options| options.as_slice());
+    let options: Vec<&option_args::ClOption> = valid_options.iter().chain(subcommand_options.iter()).collect();
+    let starts_with = |candidate: &str| candidate.to_ascii_lowercase().starts_with(&word.to_ascii_lowercase());
+
+    //the data of the option before the word
+    if let Some(previous) = cursor_index.checked_sub(1).and_then(|index| words.get(index)).filter(|previous| option_parser::is_flag(previous)) {
+        if let Some(allowed_values) = options.iter().find(|option| option.has_flag(previous)).and_then(|option| option.get_allowed_values()) {
+            let values: Vec<Candidate> = allowed_values.iter().filter(|value| starts_with(value)).map(|value| Candidate::Value(value.clone())).collect();
+            return if values.is_empty() && allowed_values.is_empty() {vec![Candidate::FilePath]} else {values};
+        }
+    }
+
+    let candidates: Vec<Candidate> = if word.starts_with('-') {
+        options.iter()
+        .flat_map(|option| [option.get_long_flag(), option.get_short_flag()])
+        .filter(|flag| !flag.is_empty() && starts_with(flag))
+        .map(|flag| Candidate::Flag(flag.to_string()))
+        .collect()
+    } else if cursor_index == 1 {
+        settings.get_subcommands().iter().filter(|subcommand| starts_with(subcommand)).map(|subcommand| Candidate::Subcommand(subcommand.clone())).collect()
+    } else {
+        Vec::new()
+    };
+
+    if candidates.is_empty() {vec![Candidate::FilePath]} else {candidates}
+}
+
+/// if `args` is a completion request (`program __complete WORDS...`, where the last word is the one being typed),
+/// writes the candidates for it to `out` one per line (see `completion::complete()`) and returns true, otherwise does nothing and returns false
+///
+/// call this before parsing, so the hidden subcommand is never parsed like the usual args, `Parser::parse_or_report_to()` already does
+///
+/// # Examples
+/// ```
+/// use clia::{completion, option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+///     let to_args = |args: &[&str]| -> Vec<String> {args.iter().map(|arg| arg.to_string()).collect()};
+///
+///     let mut out: Vec<u8> = Vec::new();
+///     assert!(completion::respond(&to_args(&["foo", "__complete", "foo", "-"]), &valid_options, &ParserSettings::new(), &mut out));
+///     assert_eq!(String::from_utf8(out).unwrap(), "--recursive\n-r\n");
+///
+///     let mut out: Vec<u8> = Vec::new();
+///     assert!(!completion::respond(&to_args(&["foo", "-r"]), &valid_options, &ParserSettings::new(), &mut out));
+///     assert!(out.is_empty());
+/// ```
+pub fn respond(args: &[String], valid_options: &[option_args::ClOption], settings: &ParserSettings, out: &mut dyn Write) -> bool {
+    if args.get(1).map(|arg| arg.as_str()) != Some(COMPLETE_SUBCOMMAND) {
+        return false;
+    }
+
+    let words: &[String] = &args[2..];
+    for candidate in complete(valid_options, settings, words, words.len().saturating_sub(1)) {
+        let _ = writeln!(out, "{}", candidate);
+    }
+    true
+}
+
+/// generates a fish completion script for `program_name` that gets its candidates from the program at runtime, see `completion::respond()`
+///
+/// # Examples
+/// ```
+/// use clia::completion;
+/// //...
+///     let script: String = completion::generate_fish_dynamic("foo");
+///     assert!(script.contains("foo __complete (commandline -opc) (commandline -ct)"));
+///     assert!(script.contains("complete -c foo -f -a '(__foo_complete)'\n"));
+/// ```
+pub fn generate_fish_dynamic(program_name: &str) -> String {
+    //DATA
+    let function: String = format!("__{}_complete", program_name.replace(|c: char| !c.is_ascii_alphanumeric(), "_"));
+
+    format!(
+        "function {function}\n    for candidate in ({program} {subcommand} (commandline -opc) (commandline -ct))\n        if test \"$candidate\" = {marker}\n            __fish_complete_path (commandline -ct)\n        else\n            echo $candidate\n        end\n    end\nend\ncomplete -c {program} -f -a '({function})'\n",
+        function = function, program = program_name, subcommand = COMPLETE_SUBCOMMAND, marker = FILE_PATH_MARKER,
+    )
+}
@@ -0,0 +1,229 @@
+//! # Constraints
+//! 'constraints' is a module containing utilities for
+//! checking the relationships between the options and parameters given, ei. that `--json` and `--pretty` aren't used together
+//!
+//! constraints are declared once, in a `Constraints`, and checked all at once after parsing, so every one that's broken is reported, see `Constraints::evaluate()`
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use crate::option_parser;
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::Parser;
+
+/// a relationship between options and parameters, each identified by an id like in `Parser::get_flag()` and `Parser::get_one()`,
+/// ei. `json` for the option with the long flag `--json`, or `PATH` for the parameter named `PATH`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Constraint {
+    /// the first and second can't both be given
+    Conflicts(String, String),
+    /// if the first is given, the second must be too
+    Requires(String, String),
+    /// exactly one of them must be given
+    OneOf(Vec<String>),
+    /// the first must be given, unless one of the others is
+    RequiredUnless(String, Vec<String>),
+}
+impl Constraint {
+    /// returns the errors for how `parser` breaks this constraint, empty if it doesn't
+    pub(crate) fn check(&self, parser: &Parser) -> Vec<ParseError> {
+        //DATA
+        let mut errors: Vec<ParseError> = Vec::new();
+        let mut given = |id: &str| -> bool {
+            match find(parser, id) {
+                Some(given) => given,
+                None => {
+                    errors.push(ParseError::new(ParseErrorKind::Other, None, &format!("BUG: a constraint names '{}', which isn't an option or parameter", id)));
+                    false
+                },
+            }
+        };
+
+        let message: Option<String> = match self {
+            Constraint::Conflicts(first, second) => {
+                (given(first) & given(second)).then(|| format!("User Error: {} can't be used with {}", display(parser, first), display(parser, second)))
+            },
+            Constraint::Requires(first, second) => {
+                (given(first) & !given(second)).then(|| format!("User Error: {} requires {}", display(parser, first), display(parser, second)))
+            },
+            Constraint::OneOf(ids) => {
+                let given_ids: Vec<&String> = ids.iter().filter(|id| given(id)).collect();
+                (given_ids.len() != 1).then(|| format!(
+                    "User Error: exactly one of {} must be given, but {} {}",
+                    ids.iter().map(|id| display(parser, id)).collect::<Vec<String>>().join(", "),
+                    given_ids.len(),
+                    if given_ids.len() == 1 {"was"} else {"were"},
+                ))
+            },
+            Constraint::RequiredUnless(id, others) => {
+                let any_other: bool = others.iter().fold(false, |any, other| given(other) | any);
+                (!given(id) & !any_other).then(|| format!(
+                    "User Error: {} is required unless {} is given",
+                    display(parser, id),
+                    others.iter().map(|other| display(parser, other)).collect::<Vec<String>>().join(" or "),
+                ))
+            },
+        };
+
+        if errors.is_empty() {
+            if let Some(message) = message {
+                errors.push(ParseError::new(ParseErrorKind::ConstraintViolated, None, &message));
+            }
+        }
+        errors
+    }
+}
+
+/// stores the constraints between the options and parameters of a program, see `Constraints::evaluate()`
+///
+/// # Examples
+/// ```
+/// use clia::{constraints::{Constraint, Constraints}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-j", "--json", "Print json").unwrap()),
+///         ClOption::new_flag(&ClOptionInfo::new("-p", "--pretty", "Print indented").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+///         ClOption::new_flag(&ClOptionInfo::new("-a", "--append", "Append to the output").unwrap()),
+///         ClOption::new_flag(&ClOptionInfo::new("", "--stdin", "Read from stdin").unwrap()),
+///     ];
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to read").optional(true)];
+///     let constraints = Constraints::new()
+///         .with(Constraint::Conflicts(String::from("json"), String::from("pretty")))
+///         .with(Constraint::Requires(String::from("append"), String::from("output")))
+///         .with(Constraint::RequiredUnless(String::from("PATH"), vec![String::from("stdin")]));
+///     let parse = |args: &[&str]| {
+///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+///         Parser::new(&args, &valid_options, &expected_parameters).unwrap()
+///     };
+///
+///     assert!(constraints.evaluate(&parse(&["foo", "--json", "-o", "out.json", "--append", "src"])).is_ok());
+///     assert!(constraints.evaluate(&parse(&["foo", "--stdin"])).is_ok());
+///
+///     //every broken constraint is reported at once
+///     let errors = constraints.evaluate(&parse(&["foo", "-j", "-p", "-a"])).unwrap_err();
+///     assert_eq!(
+///         errors.iter().map(|error| error.to_string()).collect::<Vec<String>>(),
+///         vec![
+///             "User Error: flag(--json) can't be used with flag(--pretty)",
+///             "User Error: flag(--append) requires flag(--output)",
+///             "User Error: parameter(PATH) is required unless flag(--stdin) is given",
+///         ]
+///     );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Constraints {
+    constraints: Vec<Constraint>,
+}
+impl Constraints {
+    /// creates a new Constraints, without any constraints
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::constraints::Constraints;
+    /// //...
+    ///     assert!(Constraints::new().get_constraints().is_empty());
+    /// ```
+    pub fn new() -> Constraints {
+        Constraints::default()
+    }
+
+    /// adds `constraint`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::constraints::{Constraint, Constraints};
+    /// //...
+    ///     let constraints = Constraints::new().with(Constraint::OneOf(vec![String::from("json"), String::from("yaml")]));
+    ///     assert_eq!(constraints.get_constraints(), &vec![Constraint::OneOf(vec![String::from("json"), String::from("yaml")])]);
+    /// ```
+    pub fn with(mut self, constraint: Constraint) -> Constraints {
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// checks every constraint against the options and parameters `parser` found
+    ///
+    /// # Notes:
+    /// - an option is given if it's present, and a parameter if it has data
+    /// - errors are in the order the constraints were added, and have the kind `ParseErrorKind::ConstraintViolated`
+    ///
+    /// # Errors
+    /// - every constraint `parser` breaks
+    /// - a constraint names something that isn't an option or parameter, as a `BUG:` error
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{constraints::{Constraint, Constraints}, option_args::{ClOption, ClOptionInfo}, parse_error::{ParseErrorKind, ValidationPhase}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-j", "--json", "Print json").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-y", "--yaml", "Print yaml").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-t", "--toml", "Print toml").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-q", "--quiet", "Print nothing").unwrap()),
+    ///     ];
+    ///     let parse = |args: &[&str]| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         Parser::new(&args, &valid_options, &[]).unwrap()
+    ///     };
+    ///     let constraints = Constraints::new()
+    ///         .with(Constraint::OneOf(vec![String::from("json"), String::from("yaml"), String::from("toml")]))
+    ///         .with(Constraint::Conflicts(String::from("quiet"), String::from("json")))
+    ///         .with(Constraint::Conflicts(String::from("quiet"), String::from("yaml")));
+    ///
+    ///     assert!(constraints.evaluate(&parse(&["foo", "-t"])).is_ok());
+    ///
+    ///     let errors = constraints.evaluate(&parse(&["foo", "-q"])).unwrap_err();
+    ///     assert_eq!(errors.len(), 1);
+    ///     assert_eq!(errors[0].to_string(), "User Error: exactly one of flag(--json), flag(--yaml), flag(--toml) must be given, but 0 were");
+    ///
+    ///     let errors = constraints.evaluate(&parse(&["foo", "-q", "-j", "-y"])).unwrap_err();
+    ///     assert_eq!(
+    ///         errors.iter().map(|error| error.to_string()).collect::<Vec<String>>(),
+    ///         vec![
+    ///             "User Error: exactly one of flag(--json), flag(--yaml), flag(--toml) must be given, but 2 were",
+    ///             "User Error: flag(--quiet) can't be used with flag(--json)",
+    ///             "User Error: flag(--quiet) can't be used with flag(--yaml)",
+    ///         ]
+    ///     );
+    ///     assert!(errors.iter().all(|error| error.get_kind() == ParseErrorKind::ConstraintViolated && error.get_phase() == ValidationPhase::Relations));
+    ///
+    ///     //a typo in a constraint
+    ///     let errors = Constraints::new().with(Constraint::Requires(String::from("quiet"), String::from("jsn"))).evaluate(&parse(&["foo"])).unwrap_err();
+    ///     assert_eq!(errors[0].to_string(), "BUG: a constraint names 'jsn', which isn't an option or parameter");
+    /// ```
+    pub fn evaluate(&self, parser: &Parser) -> Result<(), Vec<ParseError>> {
+        let errors: Vec<ParseError> = self.constraints.iter().flat_map(|constraint| constraint.check(parser)).collect();
+        if errors.is_empty() {Ok(())} else {Err(errors)}
+    }
+
+    //getter methods
+    /// get a reference to the constraints, in the order they were added
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::constraints::{Constraint, Constraints};
+    /// //...
+    ///     let constraints = Constraints::new().with(Constraint::Requires(String::from("append"), String::from("output")));
+    ///     assert_eq!(constraints.get_constraints().len(), 1);
+    /// ```
+    pub fn get_constraints(&self) -> &Vec<Constraint> {&self.constraints}
+}
+
+/// returns whether the option or parameter identified by `id` was given, none if nothing is identified by `id`
+fn find(parser: &Parser, id: &str) -> Option<bool> {
+    match parser.find_option_by_id(id) {
+        Some(option) => Some(option.get_present()),
+        None => parser.get_parameter_arguments_found().iter()
+            .find(|parameter| parameter.get_name().eq(id))
+            .map(|parameter| !parameter.get_data().is_empty() || !parameter.get_values().is_empty()),
+    }
+}
+
+/// returns how the option or parameter identified by `id` is shown in messages, ei. `flag(--json)` or `parameter(PATH)`
+fn display(parser: &Parser, id: &str) -> String {
+    match parser.find_option_by_id(id) {
+        Some(option) => format!("flag({})", option_parser::get_display_flag(option)),
+        None => format!("parameter({})", id),
+    }
+}
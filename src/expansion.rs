@@ -0,0 +1,225 @@
+//! # Expansion
+//! 'expansion' is a module containing utilities for
+//! expanding the indirections in args, ei. response files (`@path/to/file`) and the presets of ClOption::FlagPresets, which can include each other
+//!
+//! every indirection goes through an `Expander`, so how deep they can nest, and cycles (ei. `a.args -> b.args -> a.args`), are caught the same way for all of them
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use std::fs;
+
+use crate::parse_error::{ParseError, ParseErrorKind};
+
+/// how many indirections can be nested inside each other by default, see `ParserSettings::max_expansion_depth()`
+pub const DEFAULT_MAX_DEPTH: usize = 8;
+
+/// something in the args that includes more args
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Indirection {
+    /// a response file, by its path as it was typed (without the `@`)
+    ResponseFile(String),
+    /// the preset of a ClOption::FlagPreset, by its flag
+    Preset(String),
+}
+impl Indirection {
+    /// get the name of this indirection, as shown in the chain of an error
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::expansion::Indirection;
+    /// //...
+    ///     assert_eq!(Indirection::ResponseFile(String::from("a.args")).get_name(), "a.args");
+    ///     assert_eq!(Indirection::Preset(String::from("--fast")).get_name(), "--fast");
+    /// ```
+    pub fn get_name(&self) -> &str {
+        match self {
+            Indirection::ResponseFile(path) => path,
+            Indirection::Preset(flag) => flag,
+        }
+    }
+
+    /// returns what identifies this indirection when looking for cycles, the canonicalized path of a response file (if it exists), or the flag of a preset
+    fn get_key(&self) -> String {
+        match self {
+            Indirection::ResponseFile(path) => format!("file:{}", fs::canonicalize(path).map_or(path.clone(), |canonical| canonical.to_string_lossy().to_string())),
+            Indirection::Preset(flag) => format!("preset:{}", flag),
+        }
+    }
+}
+
+/// tracks the chain of indirections currently being expanded, enforcing a depth limit and catching cycles
+///
+/// # Examples
+/// ```
+/// use clia::expansion::{Expander, Indirection};
+/// //...
+///     let mut expander = Expander::new(8);
+///     expander.enter(Indirection::Preset(String::from("--all"))).unwrap();
+///     expander.enter(Indirection::Preset(String::from("--fast"))).unwrap();
+///     assert_eq!(expander.get_chain(), vec!["--all", "--fast"]);
+///
+///     //including something that is already being expanded is a cycle
+///     assert_eq!(
+///         expander.enter(Indirection::Preset(String::from("--all"))).unwrap_err().to_string(),
+///         "BUG: presets include each other: --all -> --fast -> --all"
+///     );
+///
+///     expander.leave();
+///     assert_eq!(expander.get_chain(), vec!["--all"]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expander {
+    max_depth: usize,
+    chain: Vec<Indirection>,
+}
+impl Expander {
+    /// creates a new Expander, that allows at most `max_depth` indirections to be nested inside each other
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::expansion::{Expander, DEFAULT_MAX_DEPTH};
+    /// //...
+    ///     assert!(Expander::new(DEFAULT_MAX_DEPTH).get_chain().is_empty());
+    /// ```
+    pub fn new(max_depth: usize) -> Expander {
+        Expander {
+            max_depth,
+            chain: Vec::new(),
+        }
+    }
+
+    /// starts expanding `indirection`, call `Expander::leave()` when done with it
+    ///
+    /// # Errors
+    /// - `indirection` is already being expanded (it includes itself, directly or not)
+    /// - `max_depth` indirections are already being expanded
+    ///
+    /// errors are about the user if a response file is in the chain, and about the program if only presets are
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::expansion::{Expander, Indirection};
+    /// //...
+    ///     let mut expander = Expander::new(2);
+    ///     expander.enter(Indirection::ResponseFile(String::from("a.args"))).unwrap();
+    ///     expander.enter(Indirection::Preset(String::from("--fast"))).unwrap();
+    ///     assert_eq!(
+    ///         expander.enter(Indirection::ResponseFile(String::from("b.args"))).unwrap_err().to_string(),
+    ///         "User Error: response files and presets are nested too deeply (the limit is 2): a.args -> --fast -> b.args"
+    ///     );
+    /// ```
+    pub fn enter(&mut self, indirection: Indirection) -> Result<(), ParseError> {
+        //DATA
+        let key: String = indirection.get_key();
+        let is_cycle: bool = self.chain.iter().any(|included| included.get_key().eq(&key));
+        let is_too_deep: bool = self.chain.len() >= self.max_depth;
+
+        if is_cycle || is_too_deep {
+            let mut chain: Vec<&str> = self.get_chain();
+            chain.push(indirection.get_name());
+            let has_file: bool = self.chain.iter().chain([&indirection]).any(|included| matches!(included, Indirection::ResponseFile(_)));
+            let (prefix, what) = if has_file {("User Error", "response files and presets")} else {("BUG", "presets")};
+
+            let message: String = if is_cycle {
+                format!("{}: {} include each other: {}", prefix, what, chain.join(" -> "))
+            } else {
+                format!("{}: {} are nested too deeply (the limit is {}): {}", prefix, what, self.max_depth, chain.join(" -> "))
+            };
+            return Err(ParseError::new(ParseErrorKind::InvalidExpansion, None, &message));
+        }
+
+        self.chain.push(indirection);
+        Ok(())
+    }
+
+    /// finishes expanding the indirection that was entered last
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::expansion::{Expander, Indirection};
+    /// //...
+    ///     let mut expander = Expander::new(8);
+    ///     expander.enter(Indirection::Preset(String::from("--all"))).unwrap();
+    ///     expander.leave();
+    ///     assert!(expander.get_chain().is_empty());
+    /// ```
+    pub fn leave(&mut self) {
+        self.chain.pop();
+    }
+
+    /// replaces every arg starting with `@` with the whitespace separated args in the file at the path after it,
+    /// args in those files starting with `@` are expanded too
+    ///
+    /// # Errors
+    /// - a response file can't be read
+    /// - same as `Expander::enter()`
+    ///
+    /// # Examples
+    /// ```
+    /// use std::fs;
+    /// use clia::expansion::{Expander, DEFAULT_MAX_DEPTH};
+    /// //...
+    ///     let dir = std::env::temp_dir().join("clia_doctest_expand_response_files");
+    ///     fs::create_dir_all(&dir).unwrap();
+    ///     let (a, b) = (dir.join("a.args"), dir.join("b.args"));
+    ///     let to_arg = |path: &std::path::Path| format!("@{}", path.display());
+    ///
+    ///     //a deep, but acyclic, chain
+    ///     fs::write(&a, format!("--recursive {}", to_arg(&b))).unwrap();
+    ///     fs::write(&b, "--format\nNUMERIC").unwrap();
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), to_arg(&a), String::from("path/to/search")];
+    ///     assert_eq!(
+    ///         Expander::new(DEFAULT_MAX_DEPTH).expand_response_files(&args).unwrap(),
+    ///         vec!["foo.exe", "--recursive", "--format", "NUMERIC", "path/to/search"]
+    ///     );
+    ///
+    ///     //exceeding the depth limit
+    ///     assert!(Expander::new(1).expand_response_files(&args).unwrap_err().to_string().contains("nested too deeply (the limit is 1)"));
+    ///
+    ///     //a two file cycle
+    ///     fs::write(&b, to_arg(&a)).unwrap();
+    ///     assert_eq!(
+    ///         Expander::new(DEFAULT_MAX_DEPTH).expand_response_files(&args).unwrap_err().to_string(),
+    ///         format!("User Error: response files and presets include each other: {0} -> {1} -> {0}", a.display(), b.display())
+    ///     );
+    ///
+    ///     //files that don't exist
+    ///     assert!(Expander::new(DEFAULT_MAX_DEPTH).expand_response_files(&[String::from("@path/that/doesnt/exist")]).is_err());
+    /// ```
+    pub fn expand_response_files(&mut self, args: &[String]) -> Result<Vec<String>, ParseError> {
+        //DATA
+        let mut expanded: Vec<String> = Vec::with_capacity(args.len());
+
+        for arg in args.iter() {
+            let path: &str = match arg.strip_prefix('@') {
+                Some(path) if !path.is_empty() => path,
+                _ => {
+                    expanded.push(arg.clone());
+                    continue;
+                },
+            };
+
+            self.enter(Indirection::ResponseFile(path.to_string()))?;
+            let contents: String = fs::read_to_string(path).map_err(|e| ParseError::new(ParseErrorKind::InvalidExpansion, None, &format!("User Error: couldn't read response file '{}': {}", path, e)))?;
+            let included: Vec<String> = contents.split_whitespace().map(|arg| arg.to_string()).collect();
+            expanded.extend(self.expand_response_files(&included)?);
+            self.leave();
+        }
+
+        Ok(expanded)
+    }
+
+    //getter methods
+    /// get the names of the indirections currently being expanded, outermost first
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::expansion::{Expander, DEFAULT_MAX_DEPTH};
+    /// //...
+    ///     assert!(Expander::new(DEFAULT_MAX_DEPTH).get_chain().is_empty());
+    /// ```
+    pub fn get_chain(&self) -> Vec<&str> {
+        self.chain.iter().map(|included| included.get_name()).collect()
+    }
+}
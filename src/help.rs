@@ -0,0 +1,361 @@
+//! # Help
+//! 'help' is a module containing utilities for
+//! formatting help messages, like wrapping long descriptions to fit a terminal
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+/// splits `text` into lines no wider than `width`, breaking on spaces
+///
+/// # Notes:
+/// - words longer than `width` are never split, they get a line to themselves instead
+/// - empty `text` results in a single empty line, so the line count of a wrapped description is never 0
+///
+/// # Examples
+/// ```
+/// use clia::help;
+/// //...
+///     assert_eq!(help::wrap("Search through subdirectories", 80), vec!["Search through subdirectories"]);
+///     assert_eq!(help::wrap("Search through subdirectories", 14), vec!["Search through", "subdirectories"]);
+///     assert_eq!(help::wrap("Search through subdirectories", 5), vec!["Search", "through", "subdirectories"]); //long words are left intact
+///     assert_eq!(help::wrap("", 5), vec![""]);
+/// ```
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    //DATA
+    let mut lines: Vec<String> = Vec::new();
+    let mut line: String = String::new();
+
+    for word in text.split_whitespace() {
+        //start a new line if this word won't fit on the current one
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(line);
+            line = String::new();
+        }
+
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line += word;
+    }
+    lines.push(line);
+
+    lines
+}
+
+/// indents every line of `lines` but the first by `indent` spaces and joins them into one string
+///
+/// # Examples
+/// ```
+/// use clia::help;
+/// //...
+///     let lines = help::wrap("Search through subdirectories", 14);
+///     assert_eq!(help::join_indented(&lines, 4), "Search through\n    subdirectories");
+/// ```
+pub fn join_indented(lines: &[String], indent: usize) -> String {
+    lines.join(format!("\n{}", " ".repeat(indent)).as_str())
+}
+
+/// removes the trailing whitespace of every line of `text`, leading whitespace (indentation) is kept
+///
+/// # Examples
+/// ```
+/// use clia::help;
+/// //...
+///     assert_eq!(help::trim_line_ends("    -r, --recursive    \n        \nUSAGE: foo.exe <PATH> \n"), "    -r, --recursive\n\nUSAGE: foo.exe <PATH>\n");
+/// ```
+pub fn trim_line_ends(text: &str) -> String {
+    text.split('\n').map(|line| line.trim_end()).collect::<Vec<&str>>().join("\n")
+}
+
+/// replaces the 4 space indent help lines start with (see `ClOption::gen_help_line()` and `ClParameter::gen_help_line()`) with `indent` spaces,
+/// on every line of `help_line`, so lines indented further keep their place relative to the first
+///
+/// # Examples
+/// ```
+/// use clia::{help, parameter_args::ClParameter};
+/// //...
+///     let help_line: String = ClParameter::new("PATH", "Path to search in").gen_help_line();
+///
+///     assert_eq!(help::reindent(&help_line, 4), help_line);
+///     assert_eq!(help::reindent(&help_line, 2), "  PATH:\n      Path to search in");
+///     assert_eq!(help::reindent(&help_line, 0), "PATH:\n    Path to search in");
+/// ```
+pub fn reindent(help_line: &str, indent: usize) -> String {
+    help_line.split('\n')
+        .map(|line| match line.strip_prefix("    ") {
+            Some(rest) => format!("{}{}", " ".repeat(indent), rest),
+            None => line.to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// how the flags of options are aligned within the flag column of a help message, the 38 characters before the descriptions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FlagAlign {
+    /// flags start at the indent and are padded on the right, ei. `    -r, --recursive       Search...`
+    #[default]
+    Left,
+    /// flags are padded on the left so they end two spaces before the descriptions, ei. `       -r, --recursive  Search...`
+    Right,
+}
+
+/// aligns the flags on the first line of the help line of an option (see `ClOption::gen_help_line()`) according to `align`
+///
+/// flags too long to fit in the flag column (so their description is on the next line) are left as they are
+///
+/// # Examples
+/// ```
+/// use clia::{help::{self, FlagAlign}, option_args::{ClOption, ClOptionInfo}};
+/// //...
+///     let help_line: String = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()).gen_help_line();
+///
+///     assert_eq!(help::align_flags(&help_line, FlagAlign::Left), help_line);
+///     assert_eq!(help::align_flags(&help_line, FlagAlign::Right), format!("{}-r, --recursive  Search through subdirectories", " ".repeat(21)));
+///
+///     //the descriptions start in the same column either way
+///     assert_eq!(help::align_flags(&help_line, FlagAlign::Right).find("Search"), help_line.find("Search"));
+/// ```
+pub fn align_flags(help_line: &str, align: FlagAlign) -> String {
+    //DATA
+    let (first_line, other_lines) = help_line.split_once('\n').map_or((help_line, None), |(first, others)| (first, Some(others)));
+    let (flags, description) = first_line.trim_start().split_once("  ").map_or((first_line.trim_start(), ""), |(flags, description)| (flags, description.trim_start()));
+
+    if align == FlagAlign::Left || flags.chars().count() > 36 {
+        return help_line.to_string();
+    }
+
+    let mut aligned: String = format!("{:>36}", flags);
+    if !description.is_empty() {
+        aligned += format!("  {}", description).as_str();
+    }
+    if let Some(other_lines) = other_lines {
+        aligned += format!("\n{}", other_lines).as_str();
+    }
+    aligned
+}
+
+/// the sections of a help message that can be titled and reordered with a `HelpTemplate`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HelpSection {
+    /// how to invoke the program, ei. `USAGE: foo.exe [OPTIONS]... <PATH>`
+    Usage,
+    /// the help lines of every option
+    Options,
+    /// the help lines of every parameter
+    Parameters,
+}
+
+/// the sections of a help message returned by `Parser::render_help_sections()`, ei. to show one of them for `foo.exe --help <section>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SectionTitle {
+    /// the title, author, and program description, always the first section
+    Header,
+    /// one of the sections that can be titled and reordered with a `HelpTemplate`
+    Section(HelpSection),
+}
+
+/// stores the titles and order of the sections of a help message, and how its flags are aligned and indented, used by `Parser::help_with_template()`
+///
+/// the title, author, and program description always come first, the defaults reproduce `Parser::help()`
+#[derive(Clone, Debug, PartialEq)]
+pub struct HelpTemplate {
+    usage_title: String,
+    options_title: String,
+    parameters_title: String,
+    section_order: Vec<HelpSection>,
+    flag_align: FlagAlign,
+    indent: usize,
+}
+impl Default for HelpTemplate {
+    fn default() -> HelpTemplate {
+        HelpTemplate {
+            usage_title: String::from("USAGE:"),
+            options_title: String::from("OPTIONS:"),
+            parameters_title: String::from("PARAMETER ARGUMENTS:"),
+            section_order: vec![HelpSection::Usage, HelpSection::Options, HelpSection::Parameters],
+            flag_align: FlagAlign::Left,
+            indent: 4,
+        }
+    }
+}
+impl HelpTemplate {
+    /// creates a new HelpTemplate with the default titles and order
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::{HelpSection, HelpTemplate};
+    /// //...
+    ///     let template = HelpTemplate::new();
+    ///     assert_eq!(template.get_usage_title(), "USAGE:");
+    ///     assert_eq!(template.get_section_order(), &vec![HelpSection::Usage, HelpSection::Options, HelpSection::Parameters]);
+    /// ```
+    pub fn new() -> HelpTemplate {
+        HelpTemplate::default()
+    }
+
+    //setter methods
+    /// sets the title of the usage section, which is followed by the usage on the same line
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::HelpTemplate;
+    /// //...
+    ///     assert_eq!(HelpTemplate::new().usage_title("USO:").get_usage_title(), "USO:");
+    /// ```
+    pub fn usage_title(mut self, title: &str) -> HelpTemplate {
+        self.usage_title = title.to_string();
+        self
+    }
+
+    /// sets the title of the options section
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::HelpTemplate;
+    /// //...
+    ///     assert_eq!(HelpTemplate::new().options_title("OPCIONES:").get_options_title(), "OPCIONES:");
+    /// ```
+    pub fn options_title(mut self, title: &str) -> HelpTemplate {
+        self.options_title = title.to_string();
+        self
+    }
+
+    /// sets the title of the parameters section
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::HelpTemplate;
+    /// //...
+    ///     assert_eq!(HelpTemplate::new().parameters_title("ARGUMENTOS:").get_parameters_title(), "ARGUMENTOS:");
+    /// ```
+    pub fn parameters_title(mut self, title: &str) -> HelpTemplate {
+        self.parameters_title = title.to_string();
+        self
+    }
+
+    /// sets the order the sections are rendered in, sections left out of `order` aren't rendered
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::{HelpSection, HelpTemplate};
+    /// //...
+    ///     let template = HelpTemplate::new().section_order(&[HelpSection::Parameters, HelpSection::Options]);
+    ///     assert_eq!(template.get_section_order(), &vec![HelpSection::Parameters, HelpSection::Options]);
+    /// ```
+    pub fn section_order(mut self, order: &[HelpSection]) -> HelpTemplate {
+        self.section_order = Vec::from(order);
+        self
+    }
+
+    /// sets how the flags of options are aligned in the flag column, see `help::align_flags()`, defaults to `FlagAlign::Left`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{help::{FlagAlign, HelpTemplate}, option_args::{ClOption, ClOptionInfo}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///     ];
+    ///     let help = |align: FlagAlign| Parser::help_with_template("foo.exe", "", "", &valid_options, &[], &HelpTemplate::new().flag_align(align));
+    ///
+    ///     //padded on the right
+    ///     assert!(help(FlagAlign::Left).contains("\n    -r, --recursive                   Search through subdirectories\n"));
+    ///     assert!(help(FlagAlign::Left).contains("\n    -F, --format <FORMAT>             Format the output\n"));
+    ///     //padded on the left
+    ///     assert!(help(FlagAlign::Right).contains("\n                     -r, --recursive  Search through subdirectories\n"));
+    ///     assert!(help(FlagAlign::Right).contains("\n               -F, --format <FORMAT>  Format the output\n"));
+    /// ```
+    pub fn flag_align(mut self, align: FlagAlign) -> HelpTemplate {
+        self.flag_align = align;
+        self
+    }
+
+    /// sets how many spaces the help lines of options and parameters are indented by, see `help::reindent()`, defaults to 4
+    ///
+    /// # Notes:
+    /// - descriptions are still wrapped as if the indent was 4, so a larger indent makes wrapped lines that much wider
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{help::HelpTemplate, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let help = |indent: usize| Parser::help_with_template("foo.exe", "", "", &valid_options, &expected_parameters, &HelpTemplate::new().indent(indent));
+    ///
+    ///     //the default is the same as Parser::help()
+    ///     assert_eq!(help(4), Parser::help("foo.exe", "", "", &valid_options, &expected_parameters));
+    ///
+    ///     assert!(help(8).contains("\n        -r, --recursive                   Search through subdirectories\n"));
+    ///     assert!(help(8).contains("\n        PATH:\n            Path to search in\n"));
+    ///     assert!(help(0).contains("\n-r, --recursive                   Search through subdirectories\n"));
+    ///     assert!(help(0).contains("\nPATH:\n    Path to search in\n"));
+    /// ```
+    pub fn indent(mut self, indent: usize) -> HelpTemplate {
+        self.indent = indent;
+        self
+    }
+
+    //getter methods
+    /// get the title of the usage section
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::HelpTemplate;
+    /// //...
+    ///     assert_eq!(HelpTemplate::new().get_usage_title(), "USAGE:");
+    /// ```
+    pub fn get_usage_title(&self) -> &str {&self.usage_title}
+
+    /// get the title of the options section
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::HelpTemplate;
+    /// //...
+    ///     assert_eq!(HelpTemplate::new().get_options_title(), "OPTIONS:");
+    /// ```
+    pub fn get_options_title(&self) -> &str {&self.options_title}
+
+    /// get the title of the parameters section
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::HelpTemplate;
+    /// //...
+    ///     assert_eq!(HelpTemplate::new().get_parameters_title(), "PARAMETER ARGUMENTS:");
+    /// ```
+    pub fn get_parameters_title(&self) -> &str {&self.parameters_title}
+
+    /// get the order the sections are rendered in
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::{HelpSection, HelpTemplate};
+    /// //...
+    ///     assert_eq!(HelpTemplate::new().get_section_order().len(), 3);
+    /// ```
+    pub fn get_section_order(&self) -> &Vec<HelpSection> {&self.section_order}
+
+    /// get how the flags of options are aligned
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::{FlagAlign, HelpTemplate};
+    /// //...
+    ///     assert_eq!(HelpTemplate::new().get_flag_align(), FlagAlign::Left);
+    /// ```
+    pub fn get_flag_align(&self) -> FlagAlign {self.flag_align}
+
+    /// get how many spaces the help lines of options and parameters are indented by
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::help::HelpTemplate;
+    /// //...
+    ///     assert_eq!(HelpTemplate::new().get_indent(), 4);
+    /// ```
+    pub fn get_indent(&self) -> usize {self.indent}
+}
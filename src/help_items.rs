@@ -0,0 +1,313 @@
+//! # Help Items
+//! 'help_items' is a module containing utilities for
+//! writing your own help renderer, ei. to show a program's options as a table, a man page, or csv,
+//! from what the help message shows about each option and parameter, as borrowed data
+//!
+//! the items of a program are made with `CliSpec::help_items()` or `Parser::help_items()`, and `Parser::help()` is rendered from them
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use crate::option_args::{ClOption, LazyDefault};
+use crate::parameter_args::ClParameter;
+use crate::synopsis::SynopsisKind;
+
+/// what a help item is, see `HelpItem::get_kind()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HelpItemKind {
+    /// an option, and what kind of option it is
+    Option(SynopsisKind),
+    /// a ClParameter
+    Parameter,
+}
+
+/// what an item is made from
+#[derive(Clone, Copy, Debug)]
+enum Source<'a> {
+    Option(&'a ClOption),
+    Parameter(&'a ClParameter),
+}
+
+/// an option or parameter of a help message, and everything the help message shows about it
+#[derive(Clone, Copy, Debug)]
+pub struct HelpItem<'a> {
+    source: Source<'a>,
+    display_order: usize,
+}
+impl<'a> HelpItem<'a> {
+    /// returns the help line of the item, wrapped to `width` if there is one, like it's shown by `Parser::help()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())], &[ClParameter::new("PATH", "Path to search in")]);
+    ///     let items: Vec<_> = spec.help_items().collect();
+    ///     assert_eq!(items[0].gen_help_line(None), "    -r, --recursive                   Search through subdirectories");
+    ///     assert_eq!(items[1].gen_help_line(None), "    PATH:\n        Path to search in");
+    ///     assert_eq!(items[1].gen_help_line(Some(18)), "    PATH:\n        Path to\n        search in");
+    /// ```
+    pub fn gen_help_line(&self, width: Option<usize>) -> String {
+        match (self.source, width) {
+            (Source::Option(option), Some(width)) => option.gen_help_line_wrapped(width),
+            (Source::Option(option), None) => option.gen_help_line(),
+            (Source::Parameter(parameter), Some(width)) => parameter.gen_help_line_wrapped(width),
+            (Source::Parameter(parameter), None) => parameter.gen_help_line(),
+        }
+    }
+
+    //getter methods
+    /// get what the item is
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{help_items::HelpItemKind, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec, synopsis::SynopsisKind};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag_toggle(&ClOptionInfo::new("-c", "--color", "Color the output").unwrap())], &[ClParameter::new("PATH", "Path to search in")]);
+    ///     assert_eq!(spec.help_items().map(|item| item.get_kind()).collect::<Vec<_>>(), vec![HelpItemKind::Option(SynopsisKind::Toggle), HelpItemKind::Parameter]);
+    /// ```
+    pub fn get_kind(&self) -> HelpItemKind {
+        match self.source {
+            Source::Option(option) => HelpItemKind::Option(match option {
+                ClOption::Flag { .. } => SynopsisKind::Flag,
+                ClOption::FlagToggle { .. } => SynopsisKind::Toggle,
+                ClOption::FlagCount { .. } => SynopsisKind::Count,
+                ClOption::FlagPreset { .. } => SynopsisKind::Preset,
+                ClOption::FlagList { .. } => SynopsisKind::List,
+                ClOption::FlagData { .. } => SynopsisKind::Data,
+            }),
+            Source::Parameter(_) => HelpItemKind::Parameter,
+        }
+    }
+
+    /// get a reference to the short flag of the item, empty if it doesn't have one, parameters never do
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())], &[]);
+    ///     assert_eq!(spec.help_items().next().unwrap().get_short_flag(), "-r");
+    /// ```
+    pub fn get_short_flag(&self) -> &'a str {
+        match self.source {
+            Source::Option(option) => option.get_short_flag(),
+            Source::Parameter(_) => "",
+        }
+    }
+
+    /// get a reference to the long flag of the item, empty if it doesn't have one, parameters never do
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag(&ClOptionInfo::new("-r", "", "Search through subdirectories").unwrap())], &[]);
+    ///     assert_eq!(spec.help_items().next().unwrap().get_long_flag(), "");
+    /// ```
+    pub fn get_long_flag(&self) -> &'a str {
+        match self.source {
+            Source::Option(option) => option.get_long_flag(),
+            Source::Parameter(_) => "",
+        }
+    }
+
+    /// get a reference to the placeholder of the item, ei. `FORMAT` for an option with data, or `PATH` for a parameter
+    ///
+    /// # None
+    /// - the item is an option that doesn't take a list/data
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///     ], &[ClParameter::new("PATH", "Path to search in")]);
+    ///     assert_eq!(spec.help_items().map(|item| item.get_placeholder()).collect::<Vec<_>>(), vec![Some("FORMAT"), None, Some("PATH")]);
+    /// ```
+    pub fn get_placeholder(&self) -> Option<&'a str> {
+        match self.source {
+            Source::Option(ClOption::FlagList { list_name, .. }) => Some(list_name),
+            Source::Option(ClOption::FlagData { data_name, .. }) => Some(data_name),
+            Source::Option(_) => None,
+            Source::Parameter(parameter) => Some(parameter.get_name()),
+        }
+    }
+
+    /// get a reference to the description of the item, without annotations like its allowed values
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[], &[ClParameter::new("PATH", "Path to search in")]);
+    ///     assert_eq!(spec.help_items().next().unwrap().get_description(), "Path to search in");
+    /// ```
+    pub fn get_description(&self) -> &'a str {
+        match self.source {
+            Source::Option(option) => option.get_description(),
+            Source::Parameter(parameter) => parameter.get_description(),
+        }
+    }
+
+    /// get a reference to the long description of the item, empty if it doesn't have one, see `ClOptionInfo::with_long_description()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap().with_long_description("Search through subdirectories, and theirs"))], &[]);
+    ///     assert_eq!(spec.help_items().next().unwrap().get_long_description(), "Search through subdirectories, and theirs");
+    /// ```
+    pub fn get_long_description(&self) -> &'a str {
+        match self.source {
+            Source::Option(option) => option.get_info().get_long_description(),
+            Source::Parameter(_) => "",
+        }
+    }
+
+    /// get a reference to what produces the data of the item if it isn't given, see `ClOption::default_with()`
+    ///
+    /// # None
+    /// - the item doesn't have a default
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag_data(&ClOptionInfo::new("-j", "--jobs", "Jobs to run at once").unwrap(), "N").default_with(|| String::from("8"))], &[]);
+    ///     assert_eq!(spec.help_items().next().unwrap().get_default().unwrap().produce(), "8");
+    /// ```
+    pub fn get_default(&self) -> Option<&'a LazyDefault> {
+        match self.source {
+            Source::Option(option) => option.get_default_with(),
+            Source::Parameter(_) => None,
+        }
+    }
+
+    /// get a reference to the values allowed for the item, empty if anything is allowed, see `ClOption::with_allowed_values()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"])], &[]);
+    ///     assert_eq!(spec.help_items().next().unwrap().get_allowed_values(), &[String::from("DEFAULT"), String::from("BULLET")]);
+    /// ```
+    pub fn get_allowed_values(&self) -> &'a [String] {
+        match self.source {
+            Source::Option(option) => option.get_allowed_values().map(|allowed_values| allowed_values.as_slice()).unwrap_or_default(),
+            Source::Parameter(_) => &[],
+        }
+    }
+
+    /// get whether the item must be given, options never must, and parameters must unless they're optional, see `ClParameter::optional()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[], &[ClParameter::new("PATH", "Path to search in"), ClParameter::new("LIMIT", "Most results to show").optional(true)]);
+    ///     assert_eq!(spec.help_items().map(|item| item.get_required()).collect::<Vec<bool>>(), vec![true, false]);
+    /// ```
+    pub fn get_required(&self) -> bool {
+        match self.source {
+            Source::Option(_) => false,
+            Source::Parameter(parameter) => !parameter.get_optional(),
+        }
+    }
+
+    /// get whether the item takes more than one value, options with a list and variadic parameters do, see `ClParameter::variadic()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS")], &[ClParameter::new("FILES", "Files to search").variadic(true)]);
+    ///     assert!(spec.help_items().all(|item| item.get_multiple()));
+    /// ```
+    pub fn get_multiple(&self) -> bool {
+        match self.source {
+            Source::Option(option) => matches!(option, ClOption::FlagList { .. }),
+            Source::Parameter(parameter) => parameter.get_variadic(),
+        }
+    }
+
+    /// get a reference to the value of the metadata of the item with the given `key`, parameters have none, see `ClOptionInfo::with_meta()`
+    ///
+    /// there are no keys with a meaning of their own, so this is where a renderer finds things like the group or env var of an option, if your program stores them
+    ///
+    /// # None
+    /// - the item has no metadata with the given `key`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag(&ClOptionInfo::new("", "--lint-all", "Enable every lint").unwrap().with_meta("group", "lints"))], &[]);
+    ///     assert_eq!(spec.help_items().next().unwrap().get_meta("group"), Some("lints"));
+    ///     assert_eq!(spec.help_items().next().unwrap().get_meta("env"), None);
+    /// ```
+    pub fn get_meta(&self, key: &str) -> Option<&'a str> {
+        match self.source {
+            Source::Option(option) => option.get_meta(key),
+            Source::Parameter(_) => None,
+        }
+    }
+
+    /// get where the item is shown in the help message, counting from 0, options come first, then parameters
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())], &[ClParameter::new("PATH", "Path to search in")]);
+    ///     assert_eq!(spec.help_items().map(|item| item.get_display_order()).collect::<Vec<usize>>(), vec![0, 1]);
+    /// ```
+    pub fn get_display_order(&self) -> usize {self.display_order}
+}
+
+/// returns the help items of `valid_options`, then of `expected_parameters`, in the order they're shown in help messages
+///
+/// # Examples
+/// ```
+/// use clia::{help_items::{self, HelpItemKind}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, synopsis::SynopsisKind};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap().with_long_description("Format the output, one result per line"), "FORMAT")
+///             .with_allowed_values(&["DEFAULT", "BULLET"])
+///             .default_with(|| String::from("DEFAULT")),
+///     ];
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in").optional(true)];
+///
+///     let items: Vec<_> = help_items::help_items(&valid_options, &expected_parameters).collect();
+///     assert_eq!(items.len(), 3);
+///
+///     let format = items[1];
+///     assert_eq!(format.get_kind(), HelpItemKind::Option(SynopsisKind::Data));
+///     assert_eq!((format.get_short_flag(), format.get_long_flag(), format.get_placeholder()), ("-F", "--format", Some("FORMAT")));
+///     assert_eq!(format.get_description(), "Format the output");
+///     assert_eq!(format.get_long_description(), "Format the output, one result per line");
+///     assert_eq!(format.get_default().map(|default| default.produce()), Some(String::from("DEFAULT")));
+///     assert_eq!(format.get_allowed_values(), &[String::from("DEFAULT"), String::from("BULLET")]);
+///     assert!(!format.get_required() && !format.get_multiple());
+///     assert_eq!(format.get_meta("group"), None);
+///     assert_eq!(format.get_display_order(), 1);
+///
+///     let path = items[2];
+///     assert_eq!(path.get_kind(), HelpItemKind::Parameter);
+///     assert_eq!((path.get_short_flag(), path.get_long_flag(), path.get_placeholder()), ("", "", Some("PATH")));
+///     assert_eq!((path.get_description(), path.get_long_description()), ("Path to search in", ""));
+///     assert!(path.get_default().is_none() && path.get_allowed_values().is_empty());
+///     assert!(!path.get_required() && !path.get_multiple());
+///     assert_eq!(path.get_display_order(), 2);
+/// ```
+pub fn help_items<'a>(valid_options: &'a [ClOption], expected_parameters: &'a [ClParameter]) -> impl Iterator<Item = HelpItem<'a>> {
+    valid_options.iter().map(Source::Option)
+    .chain(expected_parameters.iter().map(Source::Parameter))
+    .enumerate()
+    .map(|(display_order, source)| HelpItem { source, display_order })
+}
@@ -18,11 +18,37 @@
 //! - that all options / flags start with a `-`
 //! - that lists entered in the command line are comma separated
 //! - options and their associated bits of data, are typed before any parameter arguments
-//! - any and all "Parameters" are required, and must be included in the arguments for your program to work properly (optional arguments should be tied to flags anyway)
+//! - "Parameters" are required, and must be included in the arguments for your program to work properly, unless they're made optional with `ClParameter::optional()`
+//! 
+//! ### Ordering
+//! 
+//! Nothing this crate returns is in an arbitrary order:
+//! - found options (`Parser::get_option_arguments_found()`) are in the order they were defined
+//! - present options (`Parser::present_in_order()`) are in the order they were given
+//! - found parameters are in the order they were defined
+//! - errors are in the order of the phase they're found in, then of the args, see `parse_error::ParseErrors`
+//! - warnings are in the order of the check that found them, then of the args, see `Parser::get_warnings()`
+//! - unknown and duplicated flags are in the order they were given, see `option_parser::find_unknown_flags()` and `Parser::duplicated_flags()`
 
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_doc_code_examples)]
 
+/// utilities for generating descriptions of programs, ei. help messages and shell completions
+pub mod artifact;
+/// utilities for classifying args one at a time
+pub mod classifier;
+/// utilities for generating shell completions
+pub mod completion;
+/// utilities for checking the constraints between options and parameters
+pub mod constraints;
+/// utilities for expanding response files and presets
+pub mod expansion;
+/// utilities for formatting help messages
+pub mod help;
+/// utilities for writing your own help renderers
+pub mod help_items;
+/// utilities for rewording error messages
+pub mod messages;
 /// utilities for defining options
 pub mod option_args;
 /// utilities for parsing options
@@ -31,8 +57,66 @@ pub mod option_parser;
 pub mod parameter_args;
 /// utilities for parsing parameters
 pub mod parameter_parser;
+/// utilities for reporting errors found while parsing
+pub mod parse_error;
+/// utilities for measuring how long parsing takes
+pub mod parse_metrics;
+/// utilities for auditing what happened during a parse
+pub mod parse_report;
+/// utilities for customizing how arguments are parsed
+pub mod parser_settings;
+/// utilities for asking the user for missing arguments
+pub mod prompt;
+/// utilities for describing and comparing command line interfaces
+pub mod spec;
+/// utilities for suggesting what the user meant to type
+pub mod suggest;
+/// utilities for describing the usage line of help messages as data
+pub mod synopsis;
+/// utilities for binding the allowed values of options to enums
+pub mod value_enum;
+/// utilities for checking the data of options is well-formed
+pub mod value_parser;
+
+/// returns every change between two versions of a command line interface, see `spec::diff()`
+pub use spec::diff;
 
 use std::error::Error;
+use std::time::Instant;
+
+/// quotes `value` so that it survives a round-trip through a POSIX shell, useful when reconstructing a command line
+/// 
+/// values made up entirely of characters that are safe unquoted (alphanumerics and any of `_@%+=:,./-`) are returned as is,
+/// anything else is wrapped in single quotes, with any single quotes inside of it escaped as `'"'"'`
+/// 
+/// # Examples
+/// ```
+/// use clia::shell_quote;
+/// //...
+///     assert_eq!(shell_quote("path/to/search"), "path/to/search");
+///     assert_eq!(shell_quote("thing to search for"), "'thing to search for'");
+///     assert_eq!(shell_quote("it's"), "'it'\"'\"'s'");
+///     assert_eq!(shell_quote("say \"hi\""), "'say \"hi\"'");
+///     assert_eq!(shell_quote("$HOME"), "'$HOME'");
+///     assert_eq!(shell_quote(""), "''");
+/// ```
+pub fn shell_quote(value: &str) -> String {
+    //values that are safe unquoted
+    if !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric() || "_@%+=:,./-".contains(c)) {
+        return value.to_string();
+    }
+
+    //wrap everything else in single quotes, nothing is special inside them except for single quotes themselves
+    format!("'{}'", value.replace('\'', "'\"'\"'"))
+}
+
+/// what `Parser::new_or_generate()` did with the args
+pub enum ParseOutcome {
+    /// the args were parsed as usual
+    Parsed(Box<Parser>),
+    /// an artifact was asked for with `--generate`, and this is it, rendered
+    Generated(String),
+}
 
 /// concentates option and parameter parsing into one place
 pub struct Parser {
@@ -40,10 +124,35 @@ pub struct Parser {
     expected_parameters: Vec<parameter_args::ClParameter>,
     option_arguments_found: Vec<option_args::ClOption>,
     parameter_arguments_found: Vec<parameter_args::ClParameter>,
+    settings: parser_settings::ParserSettings,
+    raw_args: Option<Vec<String>>,
+    warnings: Vec<String>,
+    duplicated_flags: Vec<String>,
+    given_order: Vec<usize>,
+    prompted: Vec<String>,
+    unmatched_args: Vec<String>,
+    report: Option<parse_report::ParseReport>,
+    subcommand: Option<String>,
+    metrics: Option<parse_metrics::ParseMetrics>,
 }
 impl Parser {
     /// create a new Parser, and parses the specified `args`
     /// 
+    /// # Notes:
+    /// empty inputs are all well-defined, none of them panic:
+    /// - empty `args` have no flags and no parameters in them, so they're fine so long as no parameters are expected
+    /// - empty `valid_options` make every flag in `args` an unknown flag
+    /// - empty `expected_parameters` make every non-flag in `args` an unexpected positional, unless they're allowed, see `Parser::get_unmatched_arguments()`
+    /// 
+    /// # Errors
+    /// - `valid_options` or `expected_parameters` are defined wrong, see `Parser::validate_definition()`, this is checked before anything else
+    /// - the same errors as `option_parser::parse_for_options()` and `parameter_parser::parse_for_parameters()`
+    /// 
+    /// every error from either is reported together, as a `parse_error::ParseErrors`,
+    /// in the order of the validation phases (see `parse_error::ValidationPhase`), then the order of the args
+    /// 
+    /// unless the flag of an option whose long flag is `--help` was given, then the only error is a `ParseErrorKind::HelpRequested`, so help always wins
+    /// 
     /// # Examples
     /// 
     /// ```
@@ -65,31 +174,995 @@ impl Parser {
     ///     //create a new parser
     ///     let parser = Parser::new(&args, &valid_options, &expected_parameters);
     /// ```
+    /// 
+    /// every combination of empty and non-empty inputs, and the result of every entry point for them
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parameter_args::ClParameter, parameter_parser, Parser};
+    /// //...
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-r"), String::from("path/to/search")];
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     
+    ///     //(args, options, parameters): Parser::new() is ok, parse_for_options() is ok, parse_for_parameters() is ok
+    ///     let table: [((bool, bool, bool), (bool, bool, bool)); 8] = [
+    ///         ((false, false, false), (true,  true,  true )),
+    ///         ((false, false, true ), (false, true,  false)), //too few args for the parameters
+    ///         ((false, true,  false), (true,  true,  true )),
+    ///         ((false, true,  true ), (false, true,  false)),
+    ///         ((true,  false, false), (false, false, true )), //-r is an unknown flag
+    ///         ((true,  false, true ), (false, false, true )),
+    ///         ((true,  true,  false), (false, true,  true )), //path/to/search is an unexpected positional
+    ///         ((true,  true,  true ), (true,  true,  true )),
+    ///     ];
+    ///     
+    ///     for ((has_args, has_options, has_parameters), (parser_ok, options_ok, parameters_ok)) in table {
+    ///         let args: &[String] = if has_args {&args} else {&[]};
+    ///         let valid_options: &[ClOption] = if has_options {&valid_options} else {&[]};
+    ///         let expected_parameters: &[ClParameter] = if has_parameters {&expected_parameters} else {&[]};
+    ///         
+    ///         assert_eq!(Parser::new(args, valid_options, expected_parameters).is_ok(), parser_ok);
+    ///         assert_eq!(option_parser::parse_for_options(args, valid_options).is_ok(), options_ok);
+    ///         assert_eq!(parameter_parser::parse_for_parameters(args, expected_parameters).is_ok(), parameters_ok);
+    ///         
+    ///         //the help message only has the sections it has content for
+    ///         let help = Parser::help("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", valid_options, expected_parameters);
+    ///         assert_eq!(help.contains("OPTIONS:\n"), has_options);
+    ///         assert_eq!(help.contains("PARAMETER ARGUMENTS:\n"), has_parameters);
+    ///         
+    ///         //successful parses find a copy of every option and parameter
+    ///         if let Ok(parser) = Parser::new(args, valid_options, expected_parameters) {
+    ///             assert_eq!(parser.get_option_arguments_found().len(), valid_options.len());
+    ///             assert_eq!(parser.get_parameter_arguments_found().len(), expected_parameters.len());
+    ///             assert_eq!(parser.get_option_arguments_found().iter().any(|option| option.get_present()), has_args && has_options);
+    ///         }
+    ///     }
+    /// ```
+    /// 
+    /// errors from different phases are always reported in the same order
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parse_error::{ParseErrors, ValidationPhase}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "NUMERIC"])];
+    ///     let expected_parameters: Vec<ClParameter> = vec![
+    ///         ClParameter::new("PATH", "Path to search in"),
+    ///         ClParameter::new("QUERY", "String to search for"),
+    ///         ClParameter::new("OUTPUT", "File to write results to"),
+    ///         ClParameter::new("LIMIT", "Most results to write"),
+    ///     ];
+    ///     //an invalid value, an unknown flag, and too few args for the parameters
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--format"), String::from("FANCY"), String::from("-z")];
+    ///     
+    ///     for _ in 0..10 {
+    ///         let error = Parser::new(&args, &valid_options, &expected_parameters).err().unwrap();
+    ///         let errors = error.downcast_ref::<ParseErrors>().unwrap();
+    ///         assert_eq!(
+    ///             errors.get_errors().iter().map(|error| (error.get_phase(), error.get_arg_index())).collect::<Vec<_>>(),
+    ///             vec![(ValidationPhase::UnknownFlags, Some(3)), (ValidationPhase::ValueValidation, Some(2)), (ValidationPhase::Parameters, None)]
+    ///         );
+    ///         assert_eq!(error.to_string(), [
+    ///             "User Error: unknown options: '-z' (arg 3)",
+    ///             "User Error: invalid value 'FANCY' for flag(--format), possible values are: DEFAULT, NUMERIC",
+    ///             "User Error: missing parameters: PATH, QUERY, OUTPUT, LIMIT (0 positional arguments given, 4 required)",
+    ///         ].join("\n"));
+    ///     }
+    /// ```
+    /// 
+    /// the list/data of a long flag can be given after an `=`, in the same arg
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parse_error::{ParseErrorKind, ParseErrors}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to count").unwrap(), "EXTENSIONS"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let parse = |args: &[&str]| Parser::new(&args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>(), &valid_options, &expected_parameters);
+    ///     
+    ///     let parser = parse(&["foo.exe", "--filter=rs,toml", "--format=key=value", "path"]).unwrap();
+    ///     assert_eq!(parser.get_option_arguments_found()[0].get_list().unwrap(), &vec![String::from("rs"), String::from("toml")]);
+    ///     assert_eq!(parser.get_option_arguments_found()[1].get_data().unwrap(), "key=value"); //only the first '=' separates the flag from its value
+    ///     assert_eq!(parser.get_parameter_arguments_found()[0].get_data(), "path");
+    ///     
+    ///     //there must be something after the '='
+    ///     let error = parse(&["foo.exe", "--format=", "path"]).err().unwrap();
+    ///     assert_eq!(error.downcast_ref::<ParseErrors>().unwrap().get_errors()[0].get_kind(), ParseErrorKind::MissingValue);
+    ///     assert_eq!(error.to_string().lines().next(), Some("User Error: no value given after '=' for flag(--format)"));
+    ///     
+    ///     //short flags, and flags without a list/data, don't take values this way
+    ///     assert!(parse(&["foo.exe", "-F=json", "path"]).is_err());
+    /// ```
+    /// 
+    /// help wins over errors, wherever it's given
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parse_error::{ParseErrorKind, ParseErrors}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-h", "--help", "Show help").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let parse = |args: &[&str]| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         Parser::new(&args, &valid_options, &expected_parameters)
+    ///     };
+    ///     let errors = |args: &[&str]| parse(args).err().unwrap().downcast_ref::<ParseErrors>().unwrap().get_errors().clone();
+    ///     
+    ///     //before or after an unknown flag, and with the parameter missing
+    ///     for args in [vec!["foo.exe", "--help", "--bogus", "path"], vec!["foo.exe", "--bogus", "-h", "path"], vec!["foo.exe", "--help"], vec!["foo.exe", "--bogus", "--help"]] {
+    ///         let errors = errors(&args);
+    ///         assert_eq!(errors.len(), 1);
+    ///         assert_eq!(errors[0].get_kind(), ParseErrorKind::HelpRequested);
+    ///     }
+    ///     assert_eq!(errors(&["foo.exe", "--bogus", "-h"])[0].to_string(), "help was requested with '-h' (arg 2)");
+    ///     
+    ///     //without errors, the help option is just present
+    ///     assert!(parse(&["foo.exe", "--help", "path"]).unwrap().get_option_arguments_found()[0].get_present());
+    ///     
+    ///     //a flag is never data, so an option missing its data doesn't hide the help flag either
+    ///     assert_eq!(errors(&["foo.exe", "--format", "--help"])[0].get_kind(), ParseErrorKind::HelpRequested);
+    ///     //but the program name is never the help flag
+    ///     assert_eq!(errors(&["--help", "--bogus", "path"])[0].get_kind(), ParseErrorKind::UnknownFlag);
+    /// ```
     pub fn new(args: &[String], valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter]) -> Result<Parser, Box<dyn Error>> {
+        Parser::new_with_settings(args, valid_options, expected_parameters, &parser_settings::ParserSettings::default())
+    }
+
+    /// create a new Parser, like `Parser::new_with_settings()`, unless an artifact is asked for with `--generate <ARTIFACT>` (see `ParserSettings::with_generators()`),
+    /// then the artifact is rendered from `valid_options` and `expected_parameters` instead, for the program named by the first of `args`
+    /// 
+    /// like a help request, asking for an artifact takes priority over any other errors in `args`
+    /// 
+    /// # Errors
+    /// - `--generate` isn't given one of the artifacts in `settings`
+    /// - same as `Parser::new_with_settings()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{artifact::Artifact, completion, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, ParseOutcome, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let settings = ParserSettings::new().with_generators(&[Artifact::Help, Artifact::FishCompletions, Artifact::DynamicFishCompletions]);
+    ///     let outcome = |args: &[&str]| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         Parser::new_or_generate(&args, &valid_options, &expected_parameters, &settings)
+    ///     };
+    ///     
+    ///     //every artifact is the same as calling its generator
+    ///     for artifact in settings.get_generators() {
+    ///         match outcome(&["path/to/foo", "--generate", artifact.get_name()]).unwrap() {
+    ///             ParseOutcome::Generated(generated) => assert_eq!(generated, artifact.render("foo", &valid_options, &expected_parameters)),
+    ///             ParseOutcome::Parsed(_) => panic!("expected {} to be generated", artifact.get_name()),
+    ///         }
+    ///     }
+    ///     match outcome(&["foo", "--generate", "fish-completions"]).unwrap() {
+    ///         ParseOutcome::Generated(generated) => assert_eq!(generated, completion::generate_fish("foo", &valid_options, &expected_parameters)),
+    ///         ParseOutcome::Parsed(_) => panic!("expected completions"),
+    ///     }
+    ///     
+    ///     //otherwise the args are parsed as usual
+    ///     assert!(matches!(outcome(&["foo", "-r", "path/to/search"]).unwrap(), ParseOutcome::Parsed(_)));
+    ///     
+    ///     //only the artifacts in settings can be generated
+    ///     assert!(outcome(&["foo", "--generate", "man"]).is_err());
+    /// ```
+    pub fn new_or_generate(args: &[String], valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], settings: &parser_settings::ParserSettings) -> Result<ParseOutcome, Box<dyn Error>> {
+        if settings.get_generators().is_empty() || !option_parser::flag_present(args, "", artifact::GENERATE_FLAG) {
+            return Parser::new_with_settings(args, valid_options, expected_parameters, settings).map(|parser| ParseOutcome::Parsed(Box::new(parser)));
+        }
+
+        //parse for just the generate option, checking the artifact is one of the allowed ones
+        let names: Vec<&str> = settings.get_generators().iter().map(|artifact| artifact.get_name()).collect();
+        let generate_option = option_args::ClOption::new_flag_data(&option_args::ClOptionInfo::new("", artifact::GENERATE_FLAG, "Generate an artifact")?, "ARTIFACT").with_allowed_values(&names);
+        let found = option_parser::parse_for_options_with_settings(args, &[generate_option], &parser_settings::ParserSettings::new().allow_unknown_flags(true))?;
+        let artifact: artifact::Artifact = found[0].get_data().and_then(artifact::Artifact::from_name).ok_or("BUG: an allowed artifact has no name")?;
+
+        let program_name: &str = args.first().map_or("", |arg| std::path::Path::new(arg).file_name().and_then(|name| name.to_str()).unwrap_or(arg));
+        Ok(ParseOutcome::Generated(artifact.render(program_name, valid_options, expected_parameters)))
+    }
+
+    /// create a new Parser, like `Parser::new()`, but parse the specified `args` according to `settings`
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("--recursive "), String::from("path/to/search")];
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     
+    ///     //create a new parser that tolerates trailing whitespace on flags
+    ///     let settings = ParserSettings::new().trim_flag_whitespace(true);
+    ///     let parser = Parser::new_with_settings(&args, &valid_options, &expected_parameters, &settings).unwrap();
+    ///     assert!(parser.get_option_arguments_found()[0].get_present());
+    /// ```
+    pub fn new_with_settings(args: &[String], valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], settings: &parser_settings::ParserSettings) -> Result<Parser, Box<dyn Error>> {
+        //a mistake in the definition is a bug, whatever the args are
+        Parser::validate_definition(valid_options, expected_parameters)?;
+
         //DATA
         let mut parser = Parser {
             valid_options: Vec::from(valid_options),
             expected_parameters: Vec::from(expected_parameters),
             option_arguments_found: Vec::new(),
             parameter_arguments_found: Vec::new(),
+            settings: settings.clone(),
+            raw_args: if settings.get_keep_raw_args() {Some(Vec::from(args))} else {None},
+            warnings: Vec::new(),
+            duplicated_flags: Vec::new(),
+            given_order: Vec::new(),
+            prompted: Vec::new(),
+            unmatched_args: Vec::new(),
+            report: if settings.get_with_report() {Some(parse_report::ParseReport::new())} else {None},
+            subcommand: args.get(1).filter(|arg| !option_parser::is_flag(arg) && settings.get_subcommands().contains(arg)).cloned(),
+            metrics: None,
+        };
+
+        //the options of the subcommand are valid too
+        if let Some(subcommand_options) = parser.subcommand.as_ref().and_then(|subcommand| settings.get_subcommand_options(subcommand)) {
+            for option in subcommand_options.iter() {
+                if !parser.valid_options.contains(option) {
+                    parser.valid_options.push(option.clone());
+                }
+            }
+        }
+
+        //time each phase, only if metrics are wanted
+        let option_start: Option<Instant> = settings.get_metrics().then(Instant::now);
+
+        //expand response files, so the args in them are parsed for parameters too
+        let expanded_args: Vec<String>;
+        let args: &[String] = if settings.get_expand_response_files() {
+            expanded_args = expansion::Expander::new(settings.get_max_expansion_depth()).expand_response_files(args)
+                .map_err(|e| parse_error::ParseErrors::new(vec![settings.get_messages().localize(&e)]))?;
+            &expanded_args
+        } else {
+            args
         };
 
-        //parse for valid options
-        parser.option_arguments_found = match option_parser::parse_for_options(args, &parser.valid_options) {
-            Ok(options) => options,
-            Err(e) => return Err(e),
+        //options are parsed from the args as they were given, so their errors point at them
+        let given_args: &[String] = args;
+
+        //the args after a terminator are positionals as they were typed, so only the ones before it are tidied below
+        let terminated_args: &[String] = &args[option_parser::before_terminator(args).len()..];
+        let args: &[String] = option_parser::before_terminator(args);
+
+        //replace the unicode dashes flags were typed with, so they're recognized when parsing for parameters too
+        let normalized_args: Vec<String>;
+        let args: &[String] = if settings.get_normalize_unicode_dashes() {
+            normalized_args = option_parser::normalize_unicode_dashes(args, &parser.valid_options);
+            &normalized_args
+        } else {
+            args
         };
 
-        //parse for parameter arguments
-        parser.parameter_arguments_found = match parameter_parser::parse_for_parameters(args, &parser.expected_parameters) {
-            Ok(parameters) => parameters,
-            Err(e) => return Err(e),
+        //join bracketed lists spread across args, so their items aren't parsed as parameters, keeping the index each arg was given at for errors
+        let (mut joined_args, mut origins): (Vec<String>, Vec<usize>) = if parser.valid_options.iter().any(|option| option.get_bracketed_lists() == Some(true)) {
+            option_parser::join_bracketed_lists(args, &parser.valid_options, settings)
+        } else {
+            (args.to_vec(), (0..args.len()).collect())
         };
 
+        //flags typed with unicode dashes are errors when they aren't normalized, so they aren't parameters too
+        if !settings.get_normalize_unicode_dashes() && !settings.get_allow_unknown_flags() {
+            let typos: Vec<usize> = option_parser::find_unicode_dash_flags(&joined_args, &parser.valid_options);
+            let order: Vec<usize> = (0..joined_args.len()).filter(|index| !typos.contains(index)).collect();
+            joined_args = order.iter().map(|index| joined_args[*index].clone()).collect();
+            origins = order.iter().map(|index| origins[*index]).collect();
+        }
+        origins.extend(args.len()..args.len() + terminated_args.len());
+        joined_args.extend_from_slice(terminated_args);
+        let args: &[String] = &joined_args;
+        let classifier: classifier::Classifier = classifier::Classifier::new(&parser.valid_options, settings);
+
+        //stray args are errors, or moved to just before the parameters, if wanted
+        let first_arg: usize = if parser.subcommand.is_some() {2} else {1};
+        let stray_args: Vec<usize> = if settings.get_stray_args() == parser_settings::StrayArgs::Ignore {Vec::new()} else {option_parser::find_stray_args(args, &parser.valid_options, settings)};
+        if settings.get_stray_args() == parser_settings::StrayArgs::Positional && !stray_args.is_empty() {
+            let first_positional: usize = parameter_parser::find_positionals(args, &classifier, first_arg).first().copied().unwrap_or(args.len());
+            let order: Vec<usize> = (0..first_positional).filter(|index| !stray_args.contains(index)).chain(stray_args.iter().copied()).chain(first_positional..args.len()).collect();
+            joined_args = order.iter().map(|index| joined_args[*index].clone()).collect();
+            origins = order.iter().map(|index| origins[*index]).collect();
+        }
+        let args: &[String] = &joined_args;
+
+        //parse for valid options and parameter arguments, reporting every error from either
+        let (option_arguments_found, mut errors) = option_parser::collect_options(given_args, &parser.valid_options, settings, parser.report.as_mut());
+        if settings.get_stray_args() == parser_settings::StrayArgs::Error {
+            errors.extend(stray_args.iter().map(|index| parse_error::ParseError::new(
+                parse_error::ParseErrorKind::UnexpectedArgument,
+                Some(origins[*index]),
+                &format!("User Error: unexpected argument '{}' (arg {}) before options finished", args[*index], origins[*index]),
+            )));
+        }
+        let parameter_start: Option<Instant> = settings.get_metrics().then(Instant::now);
+        let strict: bool = !settings.get_allow_unknown_flags() && !settings.get_allow_unmatched_args();
+        let (parameter_arguments_found, parameter_errors) = parameter_parser::collect_parameters(args, &origins, &parser.expected_parameters, &classifier, first_arg, strict, settings.get_stdin());
+        let validation_start: Option<Instant> = settings.get_metrics().then(Instant::now);
+        let options_found: bool = errors.is_empty();
+        errors.extend(parameter_errors);
+        parser.option_arguments_found = option_arguments_found;
+        parser.parameter_arguments_found = parameter_arguments_found;
+
+        //check the constraints between what was found, if the options were, reporting the ones that passed
+        if options_found {
+            for constraint in settings.get_constraints().get_constraints() {
+                let constraint_errors: Vec<parse_error::ParseError> = constraint.check(&parser);
+                if constraint_errors.is_empty() {
+                    if let Some(report) = parser.report.as_mut() {
+                        report.push(parse_report::ReportEntry::ConstraintPassed(constraint.clone()));
+                    }
+                }
+                errors.extend(constraint_errors);
+            }
+        }
+        if !errors.is_empty() {
+            //help takes priority over errors, so the user can find out what they did wrong
+            if let Some(index) = option_parser::find_help_flag(args, &parser.valid_options) {
+                errors = vec![parse_error::ParseError::new(parse_error::ParseErrorKind::HelpRequested, Some(origins[index]), &format!("help was requested with '{}' (arg {})", args[index], origins[index]))];
+            }
+            return Err(Box::new(parse_error::ParseErrors::new(errors.iter().map(|error| settings.get_messages().localize(error)).collect())));
+        }
+
+        //keep the positionals no parameter was given, when they aren't errors
+        if !strict {
+            let positionals: Vec<usize> = parameter_parser::find_positionals(args, &classifier, first_arg);
+            parser.unmatched_args = parameter_parser::find_unmatched(&parser.expected_parameters, &positionals).iter().map(|index| args[*index].clone()).collect();
+        }
+
+        //warn about flags that were accepted despite having the wrong number of dashes
+        if settings.get_correct_dash_mistakes() {
+            parser.warnings.extend(option_parser::find_dash_mistakes(args, &parser.valid_options));
+        }
+
+        //warn about parameters that look like they were meant to be flags
+        parser.warnings.extend(parameter_parser::find_flag_like_parameters(&parser.parameter_arguments_found, &parser.valid_options));
+
+        //warn about a first arg that looks like a misspelled subcommand, it could be a parameter so it isn't an error
+        if let Some(first_arg) = args.get(1).filter(|arg| parser.subcommand.is_none() && !option_parser::is_flag(arg)) {
+            if let Some(closest) = suggest::closest(first_arg, settings.get_subcommands().iter().map(|subcommand| subcommand.as_str())) {
+                parser.warnings.push(format!("'{}' isn't a subcommand, did you mean '{}'?", first_arg, closest));
+            }
+        }
+        if let Some(report) = parser.report.as_mut() {
+            for warning in parser.warnings.iter() {
+                report.push(parse_report::ReportEntry::Warning(warning.clone()));
+            }
+        }
+
+        //track options that were given more than once
+        parser.duplicated_flags = option_parser::find_duplicated_flags(args, &parser.valid_options);
+        parser.given_order = option_parser::order_by_first_occurrence(args, &parser.option_arguments_found, settings);
+
+        if let (Some(option_start), Some(parameter_start), Some(validation_start)) = (option_start, parameter_start, validation_start) {
+            parser.metrics = Some(parse_metrics::ParseMetrics::new(
+                args.len(),
+                option_parser::before_terminator(args).iter().skip(1).filter(|arg| option_parser::is_flag(arg)).count(),
+                parameter_start - option_start,
+                validation_start - parameter_start,
+                validation_start.elapsed(),
+            ));
+        }
+
         //return
-        return Ok(parser);
+        Ok(parser)
     } 
 
+    /// create a new Parser, like `Parser::new_with_settings()`, but if the only problem with `args` is that required parameters or options are missing,
+    /// and stdin and stdout are terminals, the user is asked for each of them, see `Parser::parse_or_prompt_with()`
+    /// 
+    /// # Errors
+    /// - the same as `Parser::new_with_settings()`, if stdin or stdout aren't terminals, or prompts are disabled with `ParserSettings::disable_prompts()`
+    /// - the user doesn't give a valid value for a missing parameter or option, see `prompt::ask()` and `prompt::ask_option()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let args: Vec<String> = vec![String::from("foo.exe")];
+    ///     
+    ///     //stdin isn't a terminal when testing, so this fails like Parser::new_with_settings() does
+    ///     assert_eq!(
+    ///         Parser::parse_or_prompt(&args, &[], &expected_parameters, &ParserSettings::new()).err().unwrap().to_string(),
+    ///         Parser::new_with_settings(&args, &[], &expected_parameters, &ParserSettings::new()).err().unwrap().to_string()
+    ///     );
+    /// ```
+    pub fn parse_or_prompt(args: &[String], valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], settings: &parser_settings::ParserSettings) -> Result<Parser, Box<dyn Error>> {
+        use std::io::IsTerminal;
+
+        if !(std::io::stdin().is_terminal() && std::io::stdout().is_terminal()) {
+            return Parser::new_with_settings(args, valid_options, expected_parameters, settings);
+        }
+        Parser::parse_or_prompt_with(args, valid_options, expected_parameters, settings, &mut std::io::stdin().lock(), &mut std::io::stdout())
+    }
+
+    /// create a new Parser, like `Parser::parse_or_prompt()`, but prompts are written to `output` and answers read from `input`, whether they're terminals or not
+    /// 
+    /// each missing parameter is asked for with `prompt::ask()`, then each missing required option (see `prompt::missing_options()`) with `prompt::ask_option()`,
+    /// then `args` are parsed as if the answers had been given in them, the parameters after the other positionals, and the options before them
+    /// 
+    /// the names of the parameters, and flags of the options, that were asked for can be accessed with `Parser::get_prompted()`,
+    /// and each is in the report as a `ReportEntry::Prompted`, if there is one
+    /// 
+    /// # Errors
+    /// - the same as `Parser::new_with_settings()`, if anything other than missing parameters or options is wrong with `args`, or prompts are disabled with `ParserSettings::disable_prompts()`
+    /// - the user doesn't give a valid value for a missing parameter or option, see `prompt::ask()` and `prompt::ask_option()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, value_parser::ValueParser, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![
+    ///         ClParameter::new("PATH", "Path to file/folder to search"),
+    ///         ClParameter::new("COUNT", "How many results to show").with_value_parser(ValueParser::IntParser),
+    ///     ];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-r")];
+    ///     
+    ///     //a scripted session
+    ///     let mut input = std::io::Cursor::new("path/to/search\nten\n10\n");
+    ///     let mut output: Vec<u8> = Vec::new();
+    ///     let parser = Parser::parse_or_prompt_with(&args, &valid_options, &expected_parameters, &ParserSettings::new(), &mut input, &mut output).unwrap();
+    ///     assert_eq!(
+    ///         String::from_utf8(output).unwrap(),
+    ///         "PATH — Path to file/folder to search: COUNT — How many results to show: invalid value 'ten': expected an integer\nCOUNT — How many results to show: "
+    ///     );
+    ///     assert!(parser.get_flag("recursive"));
+    ///     assert_eq!(parser.get_one("PATH"), Some("path/to/search"));
+    ///     assert_eq!(parser.get_one("COUNT"), Some("10"));
+    ///     assert_eq!(parser.get_prompted(), &vec![String::from("PATH"), String::from("COUNT")]);
+    ///     
+    ///     //giving up after too many invalid values
+    ///     let settings = ParserSettings::new().max_prompt_attempts(2);
+    ///     let mut input = std::io::Cursor::new("path/to/search\nten\neleven\n10\n");
+    ///     assert_eq!(
+    ///         Parser::parse_or_prompt_with(&args, &valid_options, &expected_parameters, &settings, &mut input, &mut std::io::sink()).err().unwrap().to_string(),
+    ///         "User Error: no valid value for parameter(COUNT) after 2 attempts"
+    ///     );
+    ///     
+    ///     //nothing is asked if prompts are disabled, or if that isn't the only problem
+    ///     let mut output: Vec<u8> = Vec::new();
+    ///     let settings = ParserSettings::new().disable_prompts(true);
+    ///     assert!(Parser::parse_or_prompt_with(&args, &valid_options, &expected_parameters, &settings, &mut std::io::Cursor::new("a\n1\n"), &mut output).is_err());
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-z")];
+    ///     assert!(Parser::parse_or_prompt_with(&args, &valid_options, &expected_parameters, &ParserSettings::new(), &mut std::io::Cursor::new("a\n1\n"), &mut output).is_err());
+    ///     assert!(output.is_empty());
+    ///     
+    ///     //nor if nothing is missing
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("path/to/search"), String::from("10")];
+    ///     let parser = Parser::parse_or_prompt_with(&args, &valid_options, &expected_parameters, &ParserSettings::new(), &mut std::io::empty(), &mut output).unwrap();
+    ///     assert!(parser.get_prompted().is_empty());
+    /// ```
+    /// 
+    /// required options, see `constraints::Constraint::RequiredUnless`
+    /// ```
+    /// use clia::{constraints::{Constraint, Constraints}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parse_report::ReportEntry, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"]),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-q", "--quiet", "Don't output anything").unwrap()),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to file/folder to search")];
+    ///     let settings = ParserSettings::new()
+    ///         .with_report(true)
+    ///         .with_constraints(Constraints::new().with(Constraint::RequiredUnless(String::from("format"), vec![String::from("quiet")])));
+    ///     let args: Vec<String> = vec![String::from("foo.exe")];
+    ///     
+    ///     let mut input = std::io::Cursor::new("path/to/search\nTABLE\nBULLET\n");
+    ///     let mut output: Vec<u8> = Vec::new();
+    ///     let parser = Parser::parse_or_prompt_with(&args, &valid_options, &expected_parameters, &settings, &mut input, &mut output).unwrap();
+    ///     assert_eq!(
+    ///         String::from_utf8(output).unwrap(),
+    ///         "PATH — Path to file/folder to search: --format — Format the output: \
+    ///         invalid value 'TABLE': User Error: invalid value 'TABLE' for flag(--format), possible values are: DEFAULT, BULLET\n\
+    ///         --format — Format the output: "
+    ///     );
+    ///     assert_eq!(parser.get_one("format"), Some("BULLET"));
+    ///     assert_eq!(parser.get_one("PATH"), Some("path/to/search"));
+    ///     assert_eq!(parser.get_prompted(), &vec![String::from("PATH"), String::from("--format")]);
+    ///     
+    ///     //the prompts are the source of the values
+    ///     let entries = parser.report().unwrap().get_entries();
+    ///     assert_eq!(entries[..2], [ReportEntry::Prompted { name: String::from("PATH") }, ReportEntry::Prompted { name: String::from("--format") }]);
+    ///     
+    ///     //options that are excused aren't asked for
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-q"), String::from("path/to/search")];
+    ///     let parser = Parser::parse_or_prompt_with(&args, &valid_options, &expected_parameters, &settings, &mut std::io::empty(), &mut std::io::sink()).unwrap();
+    ///     assert!(parser.get_prompted().is_empty());
+    /// ```
+    pub fn parse_or_prompt_with(args: &[String], valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], settings: &parser_settings::ParserSettings, input: &mut dyn std::io::BufRead, output: &mut dyn std::io::Write) -> Result<Parser, Box<dyn Error>> {
+        let error: Box<dyn Error> = match Parser::new_with_settings(args, valid_options, expected_parameters, settings) {
+            Ok(parser) => return Ok(parser),
+            Err(e) => e,
+        };
+
+        //only prompt if every error is a missing parameter, or a required option that's missing
+        let missing: Vec<&parameter_args::ClParameter> = prompt::missing_parameters(args, expected_parameters, valid_options);
+        let missing_options: Vec<&option_args::ClOption> = prompt::missing_options(args, valid_options, expected_parameters, settings);
+        let only_missing: bool = error.downcast_ref::<parse_error::ParseErrors>().is_some_and(|errors| {
+            let kinds = errors.get_errors().iter().map(|error| error.get_kind());
+            kinds.clone().all(|kind| kind == parse_error::ParseErrorKind::MissingParameters || kind == parse_error::ParseErrorKind::ConstraintViolated)
+            && kinds.filter(|kind| *kind == parse_error::ParseErrorKind::ConstraintViolated).count() == missing_options.len()
+        });
+        if settings.get_disable_prompts() || !only_missing || (missing.is_empty() && missing_options.is_empty()) {
+            return Err(error);
+        }
+
+        //ask for the missing parameters, then the missing options
+        let mut answers: Vec<String> = Vec::with_capacity(missing.len());
+        for parameter in missing.iter() {
+            answers.push(prompt::ask(parameter, input, output, settings.get_max_prompt_attempts())?);
+        }
+        let mut option_answers: Vec<String> = Vec::with_capacity(missing_options.len() * 2);
+        for option in missing_options.iter() {
+            let value: String = prompt::ask_option(option, input, output, settings.get_max_prompt_attempts())?;
+            option_answers.extend([option_parser::get_display_flag(option).to_string(), value]);
+        }
+
+        //then parse as if the parameters had been given after the other positionals, and the options before them
+        let positionals: Vec<usize> = parameter_parser::find_positionals(args, &classifier::Classifier::new(valid_options, settings), 1);
+        let parameters_at: usize = positionals.last().map_or(option_parser::before_terminator(args).len(), |last| last + 1);
+        let first_arg: usize = if args.get(1).is_some_and(|arg| !option_parser::is_flag(arg) && settings.get_subcommands().contains(arg)) {2} else {1};
+        let options_at: usize = positionals.first().copied().unwrap_or(parameters_at).max(first_arg).min(parameters_at);
+        let mut prompted_args: Vec<String> = Vec::from(args);
+        prompted_args.splice(parameters_at..parameters_at, answers);
+        prompted_args.splice(options_at..options_at, option_answers);
+        let mut parser = Parser::new_with_settings(&prompted_args, valid_options, expected_parameters, settings)?;
+        parser.prompted = missing.iter().map(|parameter| parameter.get_name().to_string())
+            .chain(missing_options.iter().map(|option| option_parser::get_display_flag(option).to_string()))
+            .collect();
+
+        //the prompts happened before the parse, so they come first in the report
+        if let Some(report) = parser.report.as_mut() {
+            let mut prompted_report: parse_report::ParseReport = parse_report::ParseReport::new();
+            for name in parser.prompted.iter() {
+                prompted_report.push(parse_report::ReportEntry::Prompted { name: name.clone() });
+            }
+            for entry in report.get_entries() {
+                prompted_report.push(entry.clone());
+            }
+            *report = prompted_report;
+        }
+
+        Ok(parser)
+    }
+
+    /// checks that `valid_options` and `expected_parameters` make sense together, this is done by `Parser::new()` before parsing,
+    /// so a mistake in the definition of a program is found the first time it's run, rather than the parser quietly picking one of two options
+    /// 
+    /// # Errors
+    /// - a short or long flag is the flag of more than one option in `valid_options`
+    /// - a long flag is the short flag of another option with an extra dash (ei. `--r` and `-r`), which can't be told apart from a dash mistake
+    /// - a name is the name of more than one parameter in `expected_parameters`
+    /// - more than one parameter in `expected_parameters` is variadic, so there's no telling where one ends and the next begins
+    /// 
+    /// every problem found is reported, as a `parse_error::ParseErrors`, with the kind `ParseErrorKind::Other`, each naming the descriptions of both options (or parameters)
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     
+    ///     assert!(Parser::validate_definition(&valid_options[..1], &expected_parameters).is_ok());
+    ///     assert_eq!(
+    ///         Parser::validate_definition(&valid_options, &expected_parameters).unwrap_err().to_string(),
+    ///         "BUG in program definition: flag(-f) is defined by both 'Extensions to search' and 'Format the output'"
+    ///     );
+    ///     
+    ///     //and Parser::new reports it before parsing anything
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-f"), String::from("rs")];
+    ///     assert_eq!(
+    ///         Parser::new(&args, &valid_options, &expected_parameters).err().unwrap().to_string(),
+    ///         "BUG in program definition: flag(-f) is defined by both 'Extensions to search' and 'Format the output'"
+    ///     );
+    ///     
+    ///     //a long flag that's a short flag with an extra dash, and repeated parameters
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("", "--r", "Reverse the output").unwrap()),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in"), ClParameter::new("PATH", "Path to write to")];
+    ///     assert_eq!(
+    ///         Parser::validate_definition(&valid_options, &expected_parameters).unwrap_err().to_string(),
+    ///         "BUG in program definition: flag(--r) of 'Reverse the output' is flag(-r) of 'Search through subdirectories' with an extra dash\n\
+    ///         BUG in program definition: parameter(PATH) is defined by both 'Path to search in' and 'Path to write to'"
+    ///     );
+    ///     
+    ///     //more than one variadic parameter, found before parsing anything
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("SRC", "Files to copy").variadic(true), ClParameter::new("DEST", "Where to copy them").variadic(true)];
+    ///     assert_eq!(
+    ///         Parser::validate_definition(&[], &expected_parameters).unwrap_err().to_string(),
+    ///         "BUG in program definition: only one parameter can be variadic, but SRC and DEST are"
+    ///     );
+    ///     let args: Vec<String> = vec![String::from("cp"), String::from("a.txt"), String::from("dir")];
+    ///     assert_eq!(
+    ///         Parser::new(&args, &[], &expected_parameters).err().unwrap().to_string(),
+    ///         "BUG in program definition: only one parameter can be variadic, but SRC and DEST are"
+    ///     );
+    /// ```
+    pub fn validate_definition(valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter]) -> Result<(), Box<dyn Error>> {
+        //DATA
+        let mut errors: Vec<parse_error::ParseError> = Vec::new();
+        let bug = |message: String| parse_error::ParseError::new(parse_error::ParseErrorKind::Other, None, &format!("BUG in program definition: {}", message));
+
+        //flags of more than one option
+        for (index, option) in valid_options.iter().enumerate() {
+            for flag in [option.get_short_flag(), option.get_long_flag()].into_iter().filter(|flag| !flag.is_empty()) {
+                if let Some(first) = valid_options[..index].iter().find(|other| other.has_flag(flag)) {
+                    errors.push(bug(format!("flag({}) is defined by both '{}' and '{}'", flag, first.get_description(), option.get_description())).with_flag(flag));
+                }
+            }
+        }
+
+        //long flags that are short flags with an extra dash
+        for option in valid_options.iter() {
+            let Some(short_flag) = option.get_long_flag().strip_prefix('-').filter(|flag| flag.chars().count() == 2) else {continue};
+            if let Some(other) = valid_options.iter().find(|other| other.get_short_flag().eq(short_flag)) {
+                errors.push(bug(format!("flag({}) of '{}' is flag({}) of '{}' with an extra dash", option.get_long_flag(), option.get_description(), short_flag, other.get_description())).with_flag(option.get_long_flag()));
+            }
+        }
+
+        //parameters with the same name
+        for (index, parameter) in expected_parameters.iter().enumerate() {
+            if let Some(first) = expected_parameters[..index].iter().find(|other| other.get_name().eq(parameter.get_name())) {
+                errors.push(bug(format!("parameter({}) is defined by both '{}' and '{}'", parameter.get_name(), first.get_description(), parameter.get_description())));
+            }
+        }
+
+        //more than one variadic parameter
+        let variadics: Vec<&str> = expected_parameters.iter().filter(|parameter| parameter.get_variadic()).map(|parameter| parameter.get_name()).collect();
+        if variadics.len() > 1 {
+            errors.push(bug(format!("only one parameter can be variadic, but {} are", variadics.join(" and "))));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Box::new(parse_error::ParseErrors::new(errors)))
+        }
+    }
+
+    /// create a new Parser directly from already parsed options and parameters, without parsing any args,
+    /// ei. to unit test code that takes a `&Parser`
+    /// 
+    /// # Errors
+    /// - `option_arguments_found` doesn't have an option with the same flags, and of the same type, as every option in `valid_options`, in the same order
+    /// - `parameter_arguments_found` doesn't have a parameter with the same name as every parameter in `expected_parameters`, in the same order
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     
+    ///     //the results of parsing, however you want to get them
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-r")];
+    ///     let found_options: Vec<ClOption> = option_parser::parse_for_options(&args, &valid_options).unwrap();
+    ///     let mut found_parameters: Vec<ClParameter> = expected_parameters.clone();
+    ///     found_parameters[0].set_data("path/to/search");
+    ///     
+    ///     let parser = Parser::from_parts(&valid_options, &expected_parameters, &found_options, &found_parameters).unwrap();
+    ///     assert!(parser.get_flag("recursive"));
+    ///     assert_eq!(parser.get_parameter_arguments_found()[0].get_data(), "path/to/search");
+    ///     
+    ///     //mismatched parts are rejected
+    ///     let other_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-v", "--verbose", "Print more").unwrap())];
+    ///     assert!(Parser::from_parts(&valid_options, &expected_parameters, &other_options, &found_parameters).is_err());
+    ///     assert!(Parser::from_parts(&valid_options, &expected_parameters, &[], &found_parameters).is_err());
+    ///     assert!(Parser::from_parts(&valid_options, &expected_parameters, &found_options, &[ClParameter::new("QUERY", "String to search for")]).is_err());
+    /// ```
+    pub fn from_parts(valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], option_arguments_found: &[option_args::ClOption], parameter_arguments_found: &[parameter_args::ClParameter]) -> Result<Parser, Box<dyn Error>> {
+        //found options must correspond to valid options
+        if valid_options.len() != option_arguments_found.len() {
+            return Err(format!("BUG: expected {} found options (one per valid option), got {}", valid_options.len(), option_arguments_found.len()).into());
+        }
+        for (valid_option, found_option) in valid_options.iter().zip(option_arguments_found.iter()) {
+            if valid_option.get_short_flag() != found_option.get_short_flag()
+            || valid_option.get_long_flag() != found_option.get_long_flag()
+            || std::mem::discriminant(valid_option) != std::mem::discriminant(found_option) {
+                return Err(format!("BUG: found option ({} {}) doesn't correspond to valid option ({} {})", found_option.get_short_flag(), found_option.get_long_flag(), valid_option.get_short_flag(), valid_option.get_long_flag()).into());
+            }
+        }
+
+        //found parameters must correspond to expected parameters
+        if expected_parameters.len() != parameter_arguments_found.len() {
+            return Err(format!("BUG: expected {} found parameters (one per expected parameter), got {}", expected_parameters.len(), parameter_arguments_found.len()).into());
+        }
+        for (expected_parameter, found_parameter) in expected_parameters.iter().zip(parameter_arguments_found.iter()) {
+            if expected_parameter.get_name() != found_parameter.get_name() {
+                return Err(format!("BUG: found parameter ({}) doesn't correspond to expected parameter ({})", found_parameter.get_name(), expected_parameter.get_name()).into());
+            }
+        }
+
+        Ok(Parser {
+            valid_options: Vec::from(valid_options),
+            expected_parameters: Vec::from(expected_parameters),
+            option_arguments_found: Vec::from(option_arguments_found),
+            parameter_arguments_found: Vec::from(parameter_arguments_found),
+            settings: parser_settings::ParserSettings::default(),
+            raw_args: None,
+            warnings: Vec::new(),
+            duplicated_flags: Vec::new(),
+            given_order: option_arguments_found.iter().enumerate().filter(|(_, option)| option.get_present()).map(|(index, _)| index).collect(),
+            prompted: Vec::new(),
+            unmatched_args: Vec::new(),
+            report: None,
+            subcommand: None,
+            metrics: None,
+        })
+    }
+
+    /// create a new Parser, like `Parser::new()`, but with the valid options and expected parameters defined by `spec`, one per line, see `spec::load_lines()`
+    /// 
+    /// # Errors
+    /// - `spec` isn't valid, see `spec::load_lines()`
+    /// - the same as `Parser::new()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::Parser;
+    /// //...
+    ///     let spec = r#"
+    ///         flag -r --recursive "Search through subdirectories"
+    ///         data -F --format FORMAT "Format the output"
+    ///         param PATH "Path to search in"
+    ///     "#;
+    ///     let args: Vec<String> = ["foo.exe", "-r", "--format", "json", "src"].iter().map(|arg| arg.to_string()).collect();
+    ///     
+    ///     let parser = Parser::from_spec(spec, &args).unwrap();
+    ///     assert!(parser.get_option_arguments_found()[0].get_present());
+    ///     assert_eq!(parser.get_one("format"), Some("json"));
+    ///     assert_eq!(parser.get_parameter_arguments_found()[0].get_data(), "src");
+    ///     
+    ///     //mistakes in the spec are reported before args are parsed
+    ///     assert_eq!(
+    ///         Parser::from_spec("flag -r --recursive\nparam PATH Path to search in", &args).err().unwrap().to_string(),
+    ///         "BUG: invalid spec at line 2: unexpected 'Path', descriptions must be quoted"
+    ///     );
+    /// ```
+    #[cfg(feature = "build-spec")]
+    pub fn from_spec(spec: &str, args: &[String]) -> Result<Parser, Box<dyn Error>> {
+        let spec: spec::CliSpec = spec::load_lines(spec)?;
+        Parser::new(args, spec.get_valid_options(), spec.get_expected_parameters())
+    }
+
+    /// parses `args` against `spec`, printing the help message or errors instead of returning them, this is what most programs want from their `main()`
+    /// 
+    /// - if `args` contain the flag of an option in `spec` whose long flag is `--help`, the help message is printed to stdout
+    /// - if `args` contain the flag of an option in `spec` whose long flag is `--version`, `{title} {version}` is printed to stdout
+    /// - if parsing fails, the errors are printed to stderr, see `ParseErrors::render()`
+    /// 
+    /// # None
+    /// - returns none if anything was printed, so the caller should exit
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::env;
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec, Parser};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag(&ClOptionInfo::new("-h", "--help", "Show help").unwrap())], &[])
+    ///         .with_about("foo.exe", "by Anthony Rubick", "Just here as an example");
+    ///     
+    ///     let parser = match Parser::parse_or_report(&spec, &env::args().collect::<Vec<String>>()) {
+    ///         Some(parser) => parser,
+    ///         None => return, //or std::process::exit(), in main()
+    ///     };
+    /// ```
+    pub fn parse_or_report(spec: &spec::CliSpec, args: &[String]) -> Option<Parser> {
+        Parser::parse_or_report_to(spec, args, &mut std::io::stdout(), &mut std::io::stderr())
+    }
+
+    /// parses `args` against `spec`, like `Parser::parse_or_report()`, but prints to `out` instead of stdout, and `err` instead of stderr
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec, Parser};
+    /// //...
+    ///     let spec = CliSpec::new(
+    ///         &[
+    ///             ClOption::new_flag(&ClOptionInfo::new("-h", "--help", "Show help").unwrap()),
+    ///             ClOption::new_flag(&ClOptionInfo::new("-V", "--version", "Show version").unwrap()),
+    ///         ],
+    ///         &[ClParameter::new("PATH", "Path to search in")],
+    ///     ).with_about("foo.exe", "by Anthony Rubick", "Just here as an example").with_version("1.2.0");
+    ///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    ///     
+    ///     //success
+    ///     let (mut out, mut err): (Vec<u8>, Vec<u8>) = (Vec::new(), Vec::new());
+    ///     let parser = Parser::parse_or_report_to(&spec, &to_args(&["foo.exe", "path/to/search"]), &mut out, &mut err);
+    ///     assert_eq!(parser.unwrap().get_parameter_arguments_found()[0].get_data(), "path/to/search");
+    ///     assert!(out.is_empty() && err.is_empty());
+    ///     
+    ///     //parse error
+    ///     let (mut out, mut err): (Vec<u8>, Vec<u8>) = (Vec::new(), Vec::new());
+    ///     assert!(Parser::parse_or_report_to(&spec, &to_args(&["foo.exe", "--frmat", "path/to/search"]), &mut out, &mut err).is_none());
+    ///     assert!(out.is_empty());
+    ///     assert_eq!(
+    ///         String::from_utf8(err).unwrap(),
+    ///         "error: [unknown_flag] unknown options: '--frmat' (arg 1) (at '--frmat')\n\nUSAGE: foo.exe [OPTIONS]... <PATH>\ntry 'foo.exe --help' for more information\n"
+    ///     );
+    ///     
+    ///     //help request, even if the args are otherwise invalid
+    ///     let (mut out, mut err): (Vec<u8>, Vec<u8>) = (Vec::new(), Vec::new());
+    ///     assert!(Parser::parse_or_report_to(&spec, &to_args(&["foo.exe", "--help"]), &mut out, &mut err).is_none());
+    ///     assert_eq!(String::from_utf8(out).unwrap(), spec.help() + "\n");
+    ///     assert!(err.is_empty());
+    ///     
+    ///     //but not after a terminator, where it's a parameter
+    ///     let (mut out, mut err): (Vec<u8>, Vec<u8>) = (Vec::new(), Vec::new());
+    ///     let parser = Parser::parse_or_report_to(&spec, &to_args(&["foo.exe", "--", "--help"]), &mut out, &mut err);
+    ///     assert_eq!(parser.unwrap().get_parameter_arguments_found()[0].get_data(), "--help");
+    ///     assert!(out.is_empty() && err.is_empty());
+    ///     
+    ///     //version request
+    ///     let (mut out, mut err): (Vec<u8>, Vec<u8>) = (Vec::new(), Vec::new());
+    ///     assert!(Parser::parse_or_report_to(&spec, &to_args(&["foo.exe", "-V"]), &mut out, &mut err).is_none());
+    ///     assert_eq!(String::from_utf8(out).unwrap(), "foo.exe 1.2.0\n");
+    ///     
+    ///     //completion request from the shell, see completion::respond()
+    ///     let (mut out, mut err): (Vec<u8>, Vec<u8>) = (Vec::new(), Vec::new());
+    ///     assert!(Parser::parse_or_report_to(&spec, &to_args(&["foo.exe", "__complete", "foo.exe", "--v"]), &mut out, &mut err).is_none());
+    ///     assert_eq!(String::from_utf8(out).unwrap(), "--version\n");
+    /// ```
+    pub fn parse_or_report_to(spec: &spec::CliSpec, args: &[String], out: &mut dyn std::io::Write, err: &mut dyn std::io::Write) -> Option<Parser> {
+        //returns true if an option with the long flag `long_flag` was given, before any `--`, since nothing after it is a flag
+        let requested = |long_flag: &str| spec.get_valid_options().iter()
+            .filter(|option| option.get_long_flag().eq(long_flag))
+            .any(|option| option_parser::before_terminator(args).iter().skip(1).any(|arg| option.has_flag(arg)));
+
+        //completion requests from the shell, see completion::respond()
+        if completion::respond(args, spec.get_valid_options(), &parser_settings::ParserSettings::new(), out) {
+            return None;
+        }
+
+        //help and version requests take priority over errors
+        if requested("--help") {
+            let _ = writeln!(out, "{}", spec.help());
+            return None;
+        }
+        if requested("--version") {
+            let _ = writeln!(out, "{} {}", spec.get_title(), spec.get_version());
+            return None;
+        }
+
+        match Parser::new_with_settings(args, spec.get_valid_options(), spec.get_expected_parameters(), &parser_settings::ParserSettings::new().with_constraints(spec.get_constraints().clone())) {
+            Ok(parser) => Some(parser),
+            Err(e) => {
+                match e.downcast_ref::<parse_error::ParseErrors>() {
+                    Some(errors) => {let _ = writeln!(err, "{}", errors.render(spec, args));},
+                    None => {let _ = writeln!(err, "{}", e);},
+                }
+                None
+            },
+        }
+    }
+
+    /// create a new Parser by parsing the args this Parser was created with again, against its `valid_options` plus `extra_options`
+    /// 
+    /// this is useful when options aren't all known up front, ei. when a `--plugin <NAME>` option loads a plugin that contributes more options:
+    /// parse once with `ParserSettings::allow_unknown_flags(true)` (so the plugins options aren't errors yet) and `ParserSettings::keep_raw_args(true)`,
+    /// then reparse with the plugins options
+    /// 
+    /// the new Parser uses the same settings as this one, except that unknown flags are no longer allowed,
+    /// since after this every option should be known
+    /// 
+    /// # Errors
+    /// - this Parser wasn't created with `ParserSettings::keep_raw_args(true)`, so there are no args to reparse
+    /// - the same errors as `Parser::new()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let args: Vec<String> = ["path/to/executable/", "--plugin", "verbose-logs", "--log-level", "DEBUG", "path/to/search"].iter().map(|arg| arg.to_string()).collect();
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data(&ClOptionInfo::new("-p", "--plugin", "Plugin to load").unwrap(), "NAME")];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     
+    ///     //first pass, to find out what plugin to load
+    ///     let settings = ParserSettings::new().allow_unknown_flags(true).keep_raw_args(true);
+    ///     let first_pass = Parser::new_with_settings(&args, &valid_options, &expected_parameters, &settings).unwrap();
+    ///     assert_eq!(first_pass.raw_args(), Some(args.as_slice()));
+    ///     assert_eq!(first_pass.get_option_arguments_found()[0].get_data(), Some("verbose-logs"));
+    ///     
+    ///     //load the plugin, and parse again with the options it contributes
+    ///     let plugin_options: Vec<ClOption> = vec![ClOption::new_flag_data(&ClOptionInfo::new("", "--log-level", "How much to log").unwrap(), "LEVEL")];
+    ///     let second_pass = first_pass.reparse_with(&plugin_options).unwrap();
+    ///     assert_eq!(second_pass.get_valid_options().len(), 2);
+    ///     assert_eq!(second_pass.get_option_arguments_found()[1].get_data(), Some("DEBUG"));
+    ///     assert_eq!(second_pass.get_parameter_arguments_found()[0].get_data(), "path/to/search");
+    ///     
+    ///     //unknown flags are errors in the second pass
+    ///     assert!(first_pass.reparse_with(&[]).is_err());
+    ///     
+    ///     //and there's nothing to reparse if the args weren't kept
+    ///     let not_kept = Parser::new_with_settings(&args, &valid_options, &expected_parameters, &ParserSettings::new().allow_unknown_flags(true)).unwrap();
+    ///     assert_eq!(not_kept.raw_args(), None);
+    ///     assert!(not_kept.reparse_with(&plugin_options).is_err());
+    /// ```
+    pub fn reparse_with(&self, extra_options: &[option_args::ClOption]) -> Result<Parser, Box<dyn Error>> {
+        //DATA
+        let args: &[String] = match &self.raw_args {
+            Some(args) => args,
+            None => return Err("BUG: can't reparse a Parser that didn't keep its raw args, use ParserSettings::keep_raw_args(true)".into()),
+        };
+        let mut combined_options: Vec<option_args::ClOption> = self.valid_options.clone();
+        combined_options.extend_from_slice(extra_options);
+
+        Parser::new_with_settings(args, &combined_options, &self.expected_parameters, &self.settings.clone().allow_unknown_flags(false))
+    }
+
+    /// returns an error if any flag in `args` isn't a flag of the valid options of this Parser, even if the Parser allowed unknown flags,
+    /// so how strict to be can be decided after parsing, ei. after a lenient first pass
+    /// 
+    /// flags are recognized the way this Parser recognized them, ei. flags with the wrong number of dashes are accepted if `ParserSettings::correct_dash_mistakes()` was set
+    /// 
+    /// # Errors
+    /// - there are unknown flags in `args`, the error is the same as the one `Parser::new()` would return for them, of kind `ParseErrorKind::UnknownFlag`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parse_error::ParseErrorKind, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///     ];
+    ///     let to_args = |args: &[&str]| -> Vec<String> {args.iter().map(|arg| arg.to_string()).collect()};
+    ///     let clean = to_args(&["foo.exe", "-r", "--output", "a.txt", "path"]);
+    ///     let dirty = to_args(&["foo.exe", "-z", "-r", "--verbose"]);
+    ///     
+    ///     let parser = Parser::new_with_settings(&dirty, &valid_options, &[], &ParserSettings::new().allow_unknown_flags(true)).unwrap();
+    ///     assert!(parser.assert_no_unknown_flags(&clean).is_ok());
+    ///     
+    ///     let error = parser.assert_no_unknown_flags(&dirty).unwrap_err();
+    ///     assert_eq!(error.get_kind(), ParseErrorKind::UnknownFlag);
+    ///     assert_eq!(error.get_arg_index(), Some(1));
+    ///     assert_eq!(error.to_string(), "User Error: unknown options: '-z' (arg 1), '--verbose' (arg 3)");
+    ///     assert_eq!(error.to_string(), Parser::new(&dirty, &valid_options, &[]).err().unwrap().to_string());
+    ///     
+    ///     //flags are recognized like the Parser recognized them
+    ///     let typo = to_args(&["foo.exe", "-recursive"]);
+    ///     assert!(parser.assert_no_unknown_flags(&typo).is_err());
+    ///     let parser = Parser::new_with_settings(&typo, &valid_options, &[], &ParserSettings::new().correct_dash_mistakes(true)).unwrap();
+    ///     assert!(parser.assert_no_unknown_flags(&typo).is_ok());
+    /// ```
+    /// 
+    /// it words unknown flags the same as `Parser::new()` and `option_parser::parse_iter()`
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let parser = Parser::new_with_settings(&[String::from("foo.exe")], &valid_options, &[], &ParserSettings::new()).unwrap();
+    ///     
+    ///     for flag in ["-z", "--verbose", "-rzq"] {
+    ///         let args: Vec<String> = vec![String::from("foo.exe"), String::from(flag)];
+    ///         let error: String = Parser::new(&args, &valid_options, &[]).err().unwrap().to_string();
+    ///         assert_eq!(parser.assert_no_unknown_flags(&args).unwrap_err().to_string(), error);
+    ///         assert_eq!(option_parser::parse_iter(&args, &valid_options).find_map(Result::err).unwrap().to_string(), error);
+    ///     }
+    /// ```
+    pub fn assert_no_unknown_flags(&self, args: &[String]) -> Result<(), parse_error::ParseError> {
+        //DATA
+        let classifier: classifier::Classifier = classifier::Classifier::new(&self.valid_options, &self.settings);
+        let unknown: Vec<(usize, &str)> = option_parser::before_terminator(args).iter().enumerate().skip(1)
+        .filter(|(index, arg)| classifier.classify(arg, Some(&args[index - 1])) == classifier::TokenClass::UnknownFlag)
+        .map(|(index, arg)| (index, arg.as_str()))
+        .collect();
+
+        match unknown.is_empty() {
+            true => Ok(()),
+            false => Err(self.settings.get_messages().localize(&option_parser::unknown_flags_error(&unknown, &option_parser::get_valid_flags(&self.valid_options)))),
+        }
+    }
+
     /// returns a string containing help documentation for your command line program, which you can then print
     /// 
     /// here's the format:
@@ -107,55 +1180,470 @@ impl Parser {
     /// PARAMETER ARGUMENTS:
     /// {help lines for parameter arguments}
     /// 
-    /// ```
+    /// ```
+    /// 
+    /// # Notes:
+    /// - if there are no `valid_options`, the OPTIONS section and the `[OPTIONS]...` in USAGE are left out
+    /// - if there are no `expected_parameters`, the PARAMETER ARGUMENTS section is left out
+    /// - no line ends in whitespace, even if a description is empty, indentation is kept
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::env;
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    /// 
+    ///     //define valid options
+    ///     let mut valid_options: Vec<ClOption> = Vec::new();
+    ///     //...
+    ///     
+    ///     //define expected parameters
+    ///     let mut expected_parameters: Vec<ClParameter> = Vec::new();
+    ///     //..
+    ///     
+    ///     //to print help message
+    ///     println!("{}", Parser::help("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", &valid_options, &expected_parameters));
+    /// ```
+    /// 
+    /// lines never end in whitespace
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "").unwrap()), //empty description
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--a-really-long-flag-name", "Format the output").unwrap(), "FORMAT"), //description on the next line
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "")];
+    ///     
+    ///     let help = Parser::help("foo.exe", "by Anthony Rubick", "", &valid_options, &expected_parameters);
+    ///     assert!(help.lines().all(|line| !line.ends_with(' ')));
+    ///     assert!(Parser::help_with_width("foo.exe", "by Anthony Rubick", "", &valid_options, &expected_parameters, 40).lines().all(|line| !line.ends_with(' ')));
+    ///     
+    ///     //indentation is kept
+    ///     assert!(help.contains("\n    -r, --recursive\n"));
+    ///     assert!(help.contains("USAGE: foo.exe [OPTIONS]... <PATH>\n"));
+    /// ```
+    pub fn help(title: &str, author: &str, program_description: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter]) -> String {
+        Parser::render_help(title, author, program_description, valid_options, expected_parameters, None, &help::HelpTemplate::default())
+    }
+
+    /// returns a string containing help documentation for your command line program, like `Parser::help()`,
+    /// but with the program description and the descriptions of every option and parameter wrapped
+    /// so that no line is wider than `width` (unless a single word doesn't fit)
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output in a list, valid formats are: DEFAULT, BULLET, MARKDOWN, and NUMERIC").unwrap(), "FORMAT"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to file/folder to search")];
+    ///     
+    ///     let help = Parser::help_with_width("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", &valid_options, &expected_parameters, 60);
+    ///     assert!(help.lines().all(|line| line.len() <= 60));
+    ///     
+    ///     //if everything already fits, the output is the same as Parser::help()
+    ///     assert_eq!(
+    ///         Parser::help_with_width("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", &valid_options, &expected_parameters, 200),
+    ///         Parser::help("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", &valid_options, &expected_parameters)
+    ///     );
+    /// ```
+    pub fn help_with_width(title: &str, author: &str, program_description: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], width: usize) -> String {
+        Parser::render_help(title, author, program_description, valid_options, expected_parameters, Some(width), &help::HelpTemplate::default())
+    }
+
+    /// returns how many lines the help message generated by `Parser::help_with_width()` would occupy, useful for sizing things like a help pane in a TUI
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output in a list, valid formats are: DEFAULT, BULLET, MARKDOWN, and NUMERIC").unwrap(), "FORMAT"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to file/folder to search")];
+    ///     
+    ///     for width in [40, 60, 80, 200] {
+    ///         let help = Parser::help_with_width("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", &valid_options, &expected_parameters, width);
+    ///         assert_eq!(
+    ///             Parser::help_line_count("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", &valid_options, &expected_parameters, width),
+    ///             help.matches('\n').count()
+    ///         );
+    ///     }
+    ///     
+    ///     //narrower help is taller
+    ///     assert!(
+    ///         Parser::help_line_count("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", &valid_options, &expected_parameters, 60)
+    ///         > Parser::help_line_count("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", &valid_options, &expected_parameters, 200)
+    ///     );
+    /// ```
+    pub fn help_line_count(title: &str, author: &str, program_description: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], width: usize) -> usize {
+        Parser::help_with_width(title, author, program_description, valid_options, expected_parameters, width).lines().count()
+    }
+
+    /// returns a string containing help documentation for your command line program, like `Parser::help()`,
+    /// but with the section titles and order given by `template`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{help::{HelpSection, HelpTemplate}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     
+    ///     //the default template is the same as Parser::help()
+    ///     assert_eq!(
+    ///         Parser::help_with_template("foo.exe", "by Anthony Rubick", "Just here as an example", &valid_options, &expected_parameters, &HelpTemplate::new()),
+    ///         Parser::help("foo.exe", "by Anthony Rubick", "Just here as an example", &valid_options, &expected_parameters)
+    ///     );
+    ///     
+    ///     //parameters before options, with translated titles
+    ///     let template = HelpTemplate::new()
+    ///         .usage_title("USO:")
+    ///         .options_title("OPCIONES:")
+    ///         .parameters_title("ARGUMENTOS:")
+    ///         .section_order(&[HelpSection::Usage, HelpSection::Parameters, HelpSection::Options]);
+    ///     assert_eq!(
+    ///         Parser::help_with_template("foo.exe", "by Anthony Rubick", "Just here as an example", &valid_options, &expected_parameters, &template),
+    ///         "foo.exe\nby Anthony Rubick\n\nJust here as an example\n\nUSO: foo.exe [OPTIONS]... <PATH>\n\nARGUMENTOS:\n    PATH:\n        Path to search in\n\nOPCIONES:\n    -r, --recursive                   Search through subdirectories\n"
+    ///     );
+    /// ```
+    pub fn help_with_template(title: &str, author: &str, program_description: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], template: &help::HelpTemplate) -> String {
+        Parser::render_help(title, author, program_description, valid_options, expected_parameters, None, template)
+    }
+
+    /// returns the sections of the help message generated by `Parser::help()`, in order, so they can be shown one at a time,
+    /// ei. piped through a pager, or for things like `foo.exe --help options`
+    ///
+    /// # Notes:
+    /// - the sections joined by blank lines are the help message generated by `Parser::help()`
+    /// - sections that `Parser::help()` leaves out, ei. the options when there are no `valid_options`, are left out
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{help::{HelpSection, SectionTitle}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///
+    ///     let sections = Parser::render_help_sections("foo.exe", "by Anthony Rubick", "Just here as an example", &valid_options, &expected_parameters);
+    ///     assert_eq!(
+    ///         sections.iter().map(|(section_title, _)| *section_title).collect::<Vec<SectionTitle>>(),
+    ///         vec![SectionTitle::Header, SectionTitle::Section(HelpSection::Usage), SectionTitle::Section(HelpSection::Options), SectionTitle::Section(HelpSection::Parameters)]
+    ///     );
+    ///     assert_eq!(sections[2].1, "OPTIONS:\n    -r, --recursive                   Search through subdirectories\n");
+    ///     assert_eq!(
+    ///         sections.iter().map(|(_, section)| section.as_str()).collect::<Vec<&str>>().join("\n"),
+    ///         Parser::help("foo.exe", "by Anthony Rubick", "Just here as an example", &valid_options, &expected_parameters)
+    ///     );
+    ///
+    ///     //no parameters, no parameters section
+    ///     assert_eq!(Parser::render_help_sections("foo.exe", "by Anthony Rubick", "Just here as an example", &valid_options, &[]).len(), 3);
+    ///     //and the same for options
+    ///     assert_eq!(Parser::render_help_sections("foo.exe", "by Anthony Rubick", "Just here as an example", &[], &[]).len(), 2);
+    /// ```
+    pub fn render_help_sections(title: &str, author: &str, program_description: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter]) -> Vec<(help::SectionTitle, String)> {
+        Parser::gen_help_sections(title, author, program_description, valid_options, expected_parameters, valid_options, None, &help::HelpTemplate::default())
+    }
+
+    /// returns a string containing help documentation for your command line program, like `Parser::help()`,
+    /// but with only the options that `predicate` returns true for, ei. the options of one group, or with a flag containing some text,
+    /// the help message ends with a note of how many options were hidden, if any were
+    ///
+    /// # Notes:
+    /// - `[OPTIONS]...` is in the usage if there are any `valid_options`, even if they're all hidden
+    /// - the note says to use `--help-all`, it's up to you to show every option (ei. with `Parser::help()`) when it's given
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let mut valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     //hundreds of generated options, ei. --lint-aa, --lint-ab, ...
+    ///     for index in 0..400_u32 {
+    ///         let name: String = [index / 26, index % 26].iter().map(|letter| char::from_u32('a' as u32 + letter).unwrap()).collect();
+    ///         valid_options.push(ClOption::new_flag(&ClOptionInfo::new("", &format!("--lint-{}", name), "Enable a lint").unwrap().with_meta("group", "lints")));
+    ///     }
+    ///
+    ///     let help = Parser::help_filter("foo.exe", "by Anthony Rubick", "Just here as an example", &valid_options, &[], |option| option.get_meta("group").is_none());
+    ///     assert_eq!(
+    ///         help,
+    ///         "foo.exe\nby Anthony Rubick\n\nJust here as an example\n\nUSAGE: foo.exe [OPTIONS]...\n\nOPTIONS:\n    -r, --recursive                   Search through subdirectories\n\n400 options hidden; use --help-all\n"
+    ///     );
+    ///
+    ///     //by substring
+    ///     let help = Parser::help_filter("foo.exe", "by Anthony Rubick", "Just here as an example", &valid_options, &[], |option| option.get_long_flag().ends_with("-bq"));
+    ///     assert!(help.contains("--lint-bq ") && !help.contains("--recursive"));
+    ///     assert!(help.ends_with("\n400 options hidden; use --help-all\n"));
+    ///
+    ///     //nothing hidden, no note
+    ///     assert_eq!(
+    ///         Parser::help_filter("foo.exe", "by Anthony Rubick", "Just here as an example", &valid_options, &[], |_| true),
+    ///         Parser::help("foo.exe", "by Anthony Rubick", "Just here as an example", &valid_options, &[])
+    ///     );
+    /// ```
+    pub fn help_filter(title: &str, author: &str, program_description: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], predicate: impl Fn(&option_args::ClOption) -> bool) -> String {
+        //DATA
+        let shown_options: Vec<option_args::ClOption> = valid_options.iter().filter(|option| predicate(option)).cloned().collect();
+        let hidden: usize = valid_options.len() - shown_options.len();
+        let mut sections: Vec<String> = Parser::gen_help_sections(title, author, program_description, &shown_options, expected_parameters, valid_options, None, &help::HelpTemplate::default())
+        .into_iter().map(|(_, section)| section).collect();
+
+        //say how many options were hidden
+        if hidden > 0 {
+            sections.push(format!("{} option{} hidden; use --help-all\n", hidden, if hidden == 1 {""} else {"s"}));
+        }
+
+        sections.join("\n")
+    }
+
+    /// returns a string containing help documentation for a command line program made up of several Parsers, ei. a core Parser and one for the options of each plugin,
+    /// like `Parser::help()`, with the options of each Parser in their own section, titled with its label (see `ParserSettings::label()`)
+    /// 
+    /// # Notes:
+    /// - the same option (see `ClOption::same_option()`) in more than one Parser is only shown in the section of the first
+    /// - options that share a flag with an option of an earlier Parser, but are defined differently, conflict, only the first is shown, see `Parser::find_conflicting_flags()`
+    /// - parameters with the same name are only shown once
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("path/to/search")];
+    ///     let recursive = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap());
+    ///     let core_options: Vec<ClOption> = vec![recursive.clone()];
+    ///     let plugin_options: Vec<ClOption> = vec![recursive, ClOption::new_flag(&ClOptionInfo::new("-p", "--pretty", "Pretty print the output").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     
+    ///     let core = Parser::new(&args, &core_options, &expected_parameters).unwrap();
+    ///     let plugin = Parser::new_with_settings(&args, &plugin_options, &expected_parameters, &ParserSettings::new().label("pretty")).unwrap();
+    ///     
+    ///     assert_eq!(
+    ///         Parser::combined_help(&[&core, &plugin], "foo.exe", "by Anthony Rubick", "Just here as an example"),
+    ///         "foo.exe\nby Anthony Rubick\n\nJust here as an example\n\nUSAGE: foo.exe [OPTIONS]... <PATH>\n\nOPTIONS:\n    -r, --recursive                   Search through subdirectories\n\nOPTIONS (pretty):\n    -p, --pretty                      Pretty print the output\n\nPARAMETER ARGUMENTS:\n    PATH:\n        Path to search in\n"
+    ///     );
+    /// ```
+    /// 
+    /// conflicting flags
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let args: Vec<String> = vec![String::from("foo.exe")];
+    ///     let core_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-p", "--path", "Print the path").unwrap())];
+    ///     let plugin_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-p", "--pretty", "Pretty print the output").unwrap())];
+    ///     
+    ///     let core = Parser::new(&args, &core_options, &[]).unwrap();
+    ///     let plugin = Parser::new_with_settings(&args, &plugin_options, &[], &ParserSettings::new().label("pretty")).unwrap();
+    ///     
+    ///     let help = Parser::combined_help(&[&core, &plugin], "foo.exe", "by Anthony Rubick", "Just here as an example");
+    ///     assert!(help.contains("--path") && !help.contains("--pretty"));
+    ///     assert_eq!(Parser::find_conflicting_flags(&[&core, &plugin]), vec![String::from("flag(-p) of --pretty (pretty) conflicts with --path (parser 1)")]);
+    /// ```
+    pub fn combined_help(parsers: &[&Parser], title: &str, author: &str, program_description: &str) -> String {
+        //DATA
+        let groups: Vec<(String, Vec<&option_args::ClOption>)> = Parser::group_options(parsers).0;
+        let mut expected_parameters: Vec<&parameter_args::ClParameter> = Vec::new();
+        for parameter in parsers.iter().flat_map(|parser| parser.get_expected_parameters().iter()) {
+            if !expected_parameters.iter().any(|shown| shown.get_name().eq(parameter.get_name())) {
+                expected_parameters.push(parameter);
+            }
+        }
+        let expected_parameters: Vec<parameter_args::ClParameter> = expected_parameters.into_iter().cloned().collect();
+        let all_options: Vec<option_args::ClOption> = groups.iter().flat_map(|(_, options)| options.iter().map(|option| (*option).clone())).collect();
+        let template: help::HelpTemplate = help::HelpTemplate::default();
+        let options_section: help::SectionTitle = help::SectionTitle::Section(help::HelpSection::Options);
+        let mut sections: Vec<String> = Vec::new();
+
+        //the sections of the help of every option, with the options section split into one for each parser that has any left
+        for (section_title, section) in Parser::gen_help_sections(title, author, program_description, &all_options, &expected_parameters, &all_options, None, &template) {
+            if section_title != options_section {
+                sections.push(section);
+                continue;
+            }
+            for (label, options) in groups.iter().filter(|(_, options)| !options.is_empty()) {
+                let options: Vec<option_args::ClOption> = options.iter().map(|option| (*option).clone()).collect();
+                let Some((_, option_help)) = Parser::gen_help_sections(title, author, program_description, &options, &[], &options, None, &template.clone().section_order(&[help::HelpSection::Options]))
+                    .into_iter().find(|(section_title, _)| *section_title == options_section) else {continue};
+                match option_help.strip_prefix(template.get_options_title()).filter(|_| !label.is_empty()) {
+                    Some(option_lines) => sections.push(format!("{} ({}):{}", template.get_options_title().trim_end_matches(':'), label, option_lines)),
+                    None => sections.push(option_help),
+                }
+            }
+        }
+
+        //sections are separated by a blank line
+        sections.join("\n")
+    }
+
+    /// returns a message for every option of `parsers` that shares a flag with an option of an earlier Parser, but is defined differently,
+    /// these are left out of `Parser::combined_help()`
+    /// 
+    /// Parsers are named by their label (see `ParserSettings::label()`), or their position in `parsers` if they don't have one
     /// 
     /// # Examples
     /// ```
-    /// use std::env;
-    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, Parser};
     /// //...
-    /// 
-    ///     //define valid options
-    ///     let mut valid_options: Vec<ClOption> = Vec::new();
-    ///     //...
-    ///     
-    ///     //define expected parameters
-    ///     let mut expected_parameters: Vec<ClParameter> = Vec::new();
-    ///     //..
+    ///     let args: Vec<String> = vec![String::from("foo.exe")];
+    ///     let core = Parser::new(&args, &[ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())], &[]).unwrap();
+    ///     let plugin = Parser::new(&args, &[ClOption::new_flag(&ClOptionInfo::new("-p", "--pretty", "Pretty print the output").unwrap())], &[]).unwrap();
     ///     
-    ///     //to print help message
-    ///     println!("{}", Parser::help("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", &valid_options, &expected_parameters));
+    ///     //distinct flags don't conflict
+    ///     assert!(Parser::find_conflicting_flags(&[&core, &plugin]).is_empty());
+    ///     //and neither does the same option
+    ///     assert!(Parser::find_conflicting_flags(&[&core, &core]).is_empty());
+    ///     //even with its own default
+    ///     let format = ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT");
+    ///     let core = Parser::new(&args, &[format.clone().default_with(|| String::from("DEFAULT"))], &[]).unwrap();
+    ///     let plugin = Parser::new(&args, &[format.default_with(|| String::from("DEFAULT"))], &[]).unwrap();
+    ///     assert!(Parser::find_conflicting_flags(&[&core, &plugin]).is_empty());
     /// ```
-    pub fn help(title: &str, author: &str, program_description: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter]) -> String {
-        format!("{}\n{}\n\n{}\n\nUSAGE: {} [OPTIONS]... {}\n\nOPTIONS:\n{}\nPARAMETER ARGUMENTS:\n{}",
-            title,
-            author,
-            program_description,
-            title,
-            {
-                let mut param_usage: String = String::new();
-                for parameter in expected_parameters.into_iter() {
-                    param_usage += format!("[{}] ",parameter.get_name()).as_str();
-                }
-                param_usage
-            },
-            {
-                let mut option_help: String = String::new();
-                for option in valid_options.iter() {
-                    option_help += &option.gen_help_line();
-                    option_help += "\n";
+    pub fn find_conflicting_flags(parsers: &[&Parser]) -> Vec<String> {
+        Parser::group_options(parsers).1
+    }
+
+    /// groups the options of `parsers` by the label of their Parser, leaving out options already in an earlier group,
+    /// returns the groups and a message for every option that was left out because it conflicts with one in an earlier group
+    fn group_options<'a>(parsers: &[&'a Parser]) -> (Vec<(String, Vec<&'a option_args::ClOption>)>, Vec<String>) {
+        //DATA
+        let mut groups: Vec<(String, Vec<&option_args::ClOption>)> = Vec::new();
+        let mut shown: Vec<(&option_args::ClOption, String)> = Vec::new();
+        let mut conflicts: Vec<String> = Vec::new();
+
+        for (index, parser) in parsers.iter().enumerate() {
+            let name: String = if parser.settings.get_label().is_empty() {format!("parser {}", index + 1)} else {parser.settings.get_label().to_string()};
+            let mut options: Vec<&option_args::ClOption> = Vec::new();
+
+            for option in parser.get_valid_options().iter() {
+                //the same option is only shown once
+                if shown.iter().any(|(shown_option, _)| option.same_option(shown_option)) {
+                    continue;
                 }
-                option_help
-            },
-            {
-                let mut parameter_help: String = String::new();
-                for option in expected_parameters.iter() {
-                    parameter_help += &option.gen_help_line();
-                    parameter_help += "\n";
+
+                //options that share a flag with a different option conflict
+                let conflict = shown.iter().find_map(|(shown_option, shown_name)| {
+                    [option.get_short_flag(), option.get_long_flag()].into_iter()
+                    .find(|flag| shown_option.has_flag(flag))
+                    .map(|flag| (flag, shown_option, shown_name))
+                });
+                if let Some((flag, shown_option, shown_name)) = conflict {
+                    conflicts.push(format!("flag({}) of {} ({}) conflicts with {} ({})", flag, option_parser::get_display_flag(option), name, option_parser::get_display_flag(shown_option), shown_name));
+                    continue;
                 }
-                parameter_help
+
+                shown.push((option, name.clone()));
+                options.push(option);
+            }
+
+            groups.push((parser.settings.get_label().to_string(), options));
+        }
+
+        (groups, conflicts)
+    }
+
+    /// returns the usage line of a help message, ei. `USAGE: foo.exe [OPTIONS]... <PATH>`, rendered from `synopsis`
+    fn gen_usage(usage_title: &str, title: &str, synopsis: &synopsis::Synopsis) -> String {
+        synopsis.usage(usage_title, title) + "\n"
+    }
+
+    /// builds the help message according to `template`, wrapping descriptions to `width` if there is one
+    fn render_help(title: &str, author: &str, program_description: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], width: Option<usize>, template: &help::HelpTemplate) -> String {
+        Parser::gen_help_sections(title, author, program_description, valid_options, expected_parameters, valid_options, width, template)
+        .into_iter().map(|(_, section)| section).collect::<Vec<String>>().join("\n")
+    }
+
+    /// builds the sections of the help message according to `template`, wrapping descriptions to `width` if there is one,
+    /// the usage is rendered from the synopsis of `usage_options`, which can include options that aren't shown
+    #[allow(clippy::too_many_arguments)]
+    fn gen_help_sections(title: &str, author: &str, program_description: &str, valid_options: &[option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], usage_options: &[option_args::ClOption], width: Option<usize>, template: &help::HelpTemplate) -> Vec<(help::SectionTitle, String)> {
+        //DATA
+        let mut sections: Vec<(help::SectionTitle, String)> = Vec::new();
+
+        //header
+        sections.push((help::SectionTitle::Header, format!("{}\n{}\n\n{}\n",
+            title,
+            author,
+            match width {
+                Some(width) => help::wrap(program_description, width).join("\n"),
+                None => program_description.to_string(),
             },
-        )
+        )));
+
+        for section in template.get_section_order().iter() {
+            match section {
+                help::HelpSection::Usage => sections.push((help::SectionTitle::Section(*section), Parser::gen_usage(template.get_usage_title(), title, &synopsis::Synopsis::new(usage_options, expected_parameters)))),
+                //options, if there are any
+                help::HelpSection::Options => if !valid_options.is_empty() {
+                    let mut option_help: String = format!("{}\n", template.get_options_title());
+                    for item in help_items::help_items(valid_options, &[]) {
+                        option_help += &help::reindent(&help::align_flags(&item.gen_help_line(width), template.get_flag_align()), template.get_indent());
+                        option_help += "\n";
+                    }
+                    sections.push((help::SectionTitle::Section(*section), option_help));
+                },
+                //parameters, if there are any
+                help::HelpSection::Parameters => if !expected_parameters.is_empty() {
+                    let mut parameter_help: String = format!("{}\n", template.get_parameters_title());
+                    for item in help_items::help_items(&[], expected_parameters) {
+                        parameter_help += &help::reindent(&item.gen_help_line(width), template.get_indent());
+                        parameter_help += "\n";
+                    }
+                    sections.push((help::SectionTitle::Section(*section), parameter_help));
+                },
+            }
+        }
+
+        //no line ends in whitespace
+        sections.into_iter().map(|(section_title, section)| (section_title, help::trim_line_ends(&section))).collect()
+    }
+
+    /// returns what the help message shows about each option and parameter of this Parser, in the order they're shown, see `help_items::HelpItem`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("src")];
+    ///     let parser = Parser::new(&args, &valid_options, &expected_parameters).unwrap();
+    ///
+    ///     assert_eq!(
+    ///         parser.help_items().map(|item| (item.get_long_flag(), item.get_placeholder())).collect::<Vec<_>>(),
+    ///         vec![("--recursive", None), ("", Some("PATH"))]
+    ///     );
+    /// ```
+    pub fn help_items(&self) -> impl Iterator<Item = help_items::HelpItem<'_>> {
+        help_items::help_items(&self.valid_options, &self.expected_parameters)
+    }
+
+    /// returns a string containing detailed help for the option in `valid_options` with the given short or long `flag`,
+    /// for things like `foo.exe --help --filter`
+    ///
+    /// # None
+    /// - returns None if no option in `valid_options` has the given `flag`, so you can fall back to the full help message
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output in a list, valid formats are: DEFAULT, BULLET, MARKDOWN, and NUMERIC").unwrap(), "FORMAT"),
+    ///     ];
+    ///
+    ///     //look up an option by either of its flags
+    ///     assert_eq!(Parser::help_for_option("--format", &valid_options), Some(String::from("    -F, --format <FORMAT>\n        Format the output in a list, valid formats are: DEFAULT, BULLET, MARKDOWN, and NUMERIC")));
+    ///     assert_eq!(Parser::help_for_option("-F", &valid_options), Parser::help_for_option("--format", &valid_options));
+    ///
+    ///     //unknown flags return None
+    ///     assert_eq!(Parser::help_for_option("--frmat", &valid_options), None);
+    /// ```
+    pub fn help_for_option(flag: &str, valid_options: &[option_args::ClOption]) -> Option<String> {
+        valid_options.iter().find(|option| option.has_flag(flag)).map(|option| option.gen_long_help())
     }
 
     //getter methods
@@ -216,6 +1704,11 @@ impl Parser {
     pub fn get_expected_parameters(&self) -> &Vec<parameter_args::ClParameter> {&self.expected_parameters}
 
     /// get a reference to `option_arguments_found`
+    /// 
+    /// # Notes:
+    /// - this is guaranteed to be in the same order as the `valid_options` the Parser was created with, regardless of the order of the args,
+    ///   so it's safe to index into it by the position of an option in `valid_options`
+    /// 
     /// # Examples 
     /// ```
     /// use std::env;
@@ -241,8 +1734,78 @@ impl Parser {
     ///     
     ///     assert_eq!(parser.get_option_arguments_found().iter().filter(|opt| opt.get_present()).collect::<Vec<&ClOption>>().get(0).unwrap().get_info(), &ClOptionInfo::new("-h", "--help", "Show help").unwrap());
     /// ```
+    /// 
+    /// the order of the args doesn't matter, options are always found in the order they were defined
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///     ];
+    ///     let forward: Vec<String> = ["foo.exe", "-r", "-f", "rs,toml", "-F", "BULLET"].iter().map(|arg| arg.to_string()).collect();
+    ///     let reverse: Vec<String> = ["foo.exe", "-F", "BULLET", "-f", "rs,toml", "-r"].iter().map(|arg| arg.to_string()).collect();
+    ///     
+    ///     for args in [forward, reverse] {
+    ///         let parser = Parser::new(&args, &valid_options, &[]).unwrap();
+    ///         let found = parser.get_option_arguments_found();
+    ///         assert_eq!(found.iter().map(|option| option.get_long_flag()).collect::<Vec<&str>>(), vec!["--recursive", "--filter", "--format"]);
+    ///         assert!(found[0].get_present());
+    ///         assert_eq!(found[1].get_list(), Some(&vec![String::from("rs"), String::from("toml")]));
+    ///         assert_eq!(found[2].get_data(), Some("BULLET"));
+    ///     }
+    /// ```
     pub fn get_option_arguments_found(&self) -> &Vec<option_args::ClOption> {&self.option_arguments_found}
 
+    /// returns the options that are present, in the order their flags were first given in the args,
+    /// unlike `Parser::get_option_arguments_found()`, which is in the order they were defined
+    /// 
+    /// # Notes:
+    /// - options that are present without their flag being given (ei. set by a preset) come last, in the order they were defined
+    /// - a Parser created with `Parser::from_parts()` has no args, so its present options are in the order they were defined
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-a", "--all", "Show hidden files").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-b", "--brief", "Show less").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-c", "--color", "Show colors").unwrap()),
+    ///         ClOption::new_flag_preset(&ClOptionInfo::new("-z", "--zen", "Brief and colorful").unwrap(), &["--brief", "--color"]),
+    ///     ];
+    ///     let flags_in_order = |args: &[&str]| -> Vec<String> {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         Parser::new(&args, &valid_options, &[]).unwrap().present_in_order().iter().map(|option| option.get_short_flag().to_string()).collect()
+    ///     };
+    ///     
+    ///     //neither the definition order nor sorted
+    ///     assert_eq!(flags_in_order(&["foo.exe", "-c", "-a", "-b"]), vec!["-c", "-a", "-b"]);
+    ///     assert_eq!(flags_in_order(&["foo.exe", "-b", "-c", "-a", "-b"]), vec!["-b", "-c", "-a"]); //by first occurrence
+    ///     //set by a preset
+    ///     assert_eq!(flags_in_order(&["foo.exe", "-z", "-a"]), vec!["-z", "-a", "-b", "-c"]);
+    /// ```
+    /// 
+    /// options that take a list/data are ordered by their flag, not their list/data
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///     ];
+    ///     let args: Vec<String> = ["foo.exe", "--output", "out.txt", "-f", "rs,toml", "--recursive"].iter().map(|arg| arg.to_string()).collect();
+    ///     
+    ///     let parser = Parser::new(&args, &valid_options, &[]).unwrap();
+    ///     assert_eq!(parser.present_in_order().iter().map(|option| option.get_long_flag()).collect::<Vec<&str>>(), vec!["--output", "--filter", "--recursive"]);
+    ///     assert_eq!(parser.present_in_order()[0].get_data(), Some("out.txt"));
+    /// ```
+    pub fn present_in_order(&self) -> Vec<&option_args::ClOption> {
+        self.given_order.iter().map(|index| &self.option_arguments_found[*index]).collect()
+    }
+
     /// get a reference to `parameter_arguments_found`
     /// # Examples 
     /// ```
@@ -268,5 +1831,569 @@ impl Parser {
     ///     assert_eq!(parser.get_parameter_arguments_found().iter().map(|param| param.get_data()).collect::<Vec<&str>>(), vec!["path/to/search", "thing to search for"]);
     /// ```
     pub fn get_parameter_arguments_found(&self) -> &Vec<parameter_args::ClParameter> {&self.parameter_arguments_found}
+
+    /// get a reference to the args this Parser parsed
+    /// # None
+    /// - returns none if this Parser wasn't created with `ParserSettings::keep_raw_args(true)`
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{parser_settings::ParserSettings, Parser};
+    /// //... 
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/")];
+    ///     
+    ///     let parser = Parser::new_with_settings(&args, &[], &[], &ParserSettings::new().keep_raw_args(true)).unwrap();
+    ///     assert_eq!(parser.raw_args(), Some(args.as_slice()));
+    ///     
+    ///     //args aren't kept by default
+    ///     let parser = Parser::new(&args, &[], &[]).unwrap();
+    ///     assert_eq!(parser.raw_args(), None);
+    /// ```
+    pub fn raw_args(&self) -> Option<&[String]> {self.raw_args.as_deref()}
+
+    /// get a reference to the positionals that no parameter was given, in the order they were given,
+    /// ei. `a` in `foo.exe a b` if only one parameter is expected
+    ///
+    /// # Notes:
+    /// - positionals left over are errors, even if no parameters are expected, unless `ParserSettings::allow_unmatched_args()` or `ParserSettings::allow_unknown_flags()` is set,
+    ///   so this is always empty otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, parse_error::{ParseErrorKind, ParseErrors}, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in"), ClParameter::new("QUERY", "String to search for")];
+    ///     let args: Vec<String> = ["foo.exe", "a", "b", "c", "d", "e"].iter().map(|arg| arg.to_string()).collect();
+    ///
+    ///     //strict, the extras are errors
+    ///     let error = Parser::new(&args, &[], &expected_parameters).err().unwrap();
+    ///     assert_eq!(
+    ///         error.to_string(),
+    ///         "User Error: unexpected argument 'c' (arg 3), every parameter was already given\n\
+    ///          User Error: unexpected argument 'd' (arg 4), every parameter was already given\n\
+    ///          User Error: unexpected argument 'e' (arg 5), every parameter was already given"
+    ///     );
+    ///
+    ///     //lenient, the parameters take the last ones
+    ///     let parser = Parser::new_with_settings(&args, &[], &expected_parameters, &ParserSettings::new().allow_unmatched_args(true)).unwrap();
+    ///     assert_eq!(parser.get_unmatched_arguments(), &vec![String::from("a"), String::from("b"), String::from("c")]);
+    ///     assert_eq!(parser.get_parameter_arguments_found()[0].get_data(), "d");
+    ///
+    ///     //no parameters expected, every positional is an error
+    ///     let error = Parser::new(&args, &[], &[]).err().unwrap();
+    ///     assert_eq!(error.downcast_ref::<ParseErrors>().unwrap().get_errors().len(), 5);
+    ///     assert!(error.downcast_ref::<ParseErrors>().unwrap().get_errors().iter().all(|error| error.get_kind() == ParseErrorKind::UnexpectedPositional));
+    ///     assert!(error.to_string().starts_with("User Error: unexpected argument 'a' (arg 1), no parameters are expected"));
+    /// ```
+    pub fn get_unmatched_arguments(&self) -> &Vec<String> {&self.unmatched_args}
+
+    /// get a reference to the names of the parameters, and flags of the required options, whose values the user was asked for, instead of giving them in the args, see `Parser::parse_or_prompt()`
+    /// 
+    /// names are in the order they were asked for, the parameters in the order they're expected, then the options
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in"), ClParameter::new("QUERY", "String to search for")];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("path/to/search")];
+    ///     
+    ///     let parser = Parser::parse_or_prompt_with(&args, &[], &expected_parameters, &ParserSettings::new(), &mut std::io::Cursor::new("needle\n"), &mut std::io::sink()).unwrap();
+    ///     assert_eq!(parser.get_prompted(), &vec![String::from("QUERY")]);
+    ///     assert!(Parser::new(&["foo.exe", "a", "b"].map(String::from), &[], &expected_parameters).unwrap().get_prompted().is_empty());
+    /// ```
+    pub fn get_prompted(&self) -> &Vec<String> {&self.prompted}
+
+    /// returns the parameter the program should read from stdin, the one marked with `ClParameter::from_stdin()`, if it wasn't given and stdin isn't a terminal
+    /// 
+    /// the parser doesn't read stdin itself, this just decides when the program should
+    /// 
+    /// # Notes:
+    /// - stdin is never a terminal if it's replaced with `ParserSettings::stdin_with()`, ei. for tests
+    /// 
+    /// # None
+    /// - no parameter is marked with `ClParameter::from_stdin()`
+    /// - the marked parameter was given
+    /// - stdin is a terminal, so there's nothing piped in to read
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let expected_parameters: Vec<ClParameter> = vec![
+    ///         ClParameter::new("PATTERN", "Pattern to search for"),
+    ///         ClParameter::new("FILE", "File to search, or stdin if not given").from_stdin(true),
+    ///     ];
+    ///     let settings = ParserSettings::new().stdin_with(|| String::from("haystack with a needle"));
+    ///     let parse = |args: &[&str]| Parser::new_with_settings(&args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>(), &[], &expected_parameters, &settings).unwrap();
+    ///     
+    ///     //the file is given, so stdin isn't wanted
+    ///     assert!(parse(&["grep.exe", "needle", "notes.txt"]).wants_stdin_for().is_none());
+    ///     
+    ///     //the file isn't given, so it should be read from stdin
+    ///     let parser = parse(&["grep.exe", "needle"]);
+    ///     assert_eq!(parser.wants_stdin_for().map(|parameter| parameter.get_name()), Some("FILE"));
+    ///     assert_eq!(parser.get_parameter_arguments_found()[1].get_data(), ""); //it isn't read for you
+    ///     
+    ///     //without a marked parameter, stdin is never wanted
+    ///     let parser = Parser::new_with_settings(&[String::from("grep.exe"), String::from("needle")], &[], &[ClParameter::new("PATTERN", "Pattern to search for")], &settings).unwrap();
+    ///     assert!(parser.wants_stdin_for().is_none());
+    /// ```
+    pub fn wants_stdin_for(&self) -> Option<&parameter_args::ClParameter> {
+        use std::io::IsTerminal;
+
+        self.parameter_arguments_found.iter().find(|parameter| parameter.get_from_stdin())
+        .filter(|parameter| parameter.get_raw_data().is_empty() && parameter.get_values().is_empty())
+        .filter(|_| self.settings.get_stdin().is_some() || !std::io::stdin().is_terminal())
+    }
+
+    /// get a reference to `warnings`, diagnostics about args that were accepted, but might not mean what the user intended
+    /// 
+    /// warnings are grouped by the check that found them, in the order the checks run, and within each group are in the order of the args:
+    /// 1. flags with the wrong number of dashes, see `ParserSettings::correct_dash_mistakes()`
+    /// 2. parameters that look like flags without their dashes, see `parameter_parser::find_flag_like_parameters()`
+    /// 3. a misspelled subcommand, see `Parser::subcommand()`
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     
+    ///     //the user forgot the dashes
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("recursive")];
+    ///     let parser = Parser::new(&args, &valid_options, &expected_parameters).unwrap();
+    ///     assert_eq!(parser.get_warnings(), &vec![String::from("'recursive' was used as the PATH parameter, did you mean '--recursive'?")]);
+    ///     
+    ///     //genuine parameters don't cause warnings
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("path/to/search")];
+    ///     let parser = Parser::new(&args, &valid_options, &expected_parameters).unwrap();
+    ///     assert!(parser.get_warnings().is_empty());
+    /// ```
+    /// 
+    /// warnings are in the order of the checks, then the args
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-v", "--verbose", "Print more").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let args: Vec<String> = ["foo.exe", "-verbose", "--r", "recursive"].iter().map(|arg| arg.to_string()).collect();
+    ///     let parser = Parser::new_with_settings(&args, &valid_options, &expected_parameters, &ParserSettings::new().correct_dash_mistakes(true)).unwrap();
+    ///     
+    ///     let warnings = parser.get_warnings();
+    ///     assert_eq!(warnings.len(), 3);
+    ///     assert!(warnings[0].contains("'-verbose'"));
+    ///     assert!(warnings[1].contains("'--r'"));
+    ///     assert!(warnings[2].starts_with("'recursive' was used as the PATH parameter"));
+    /// ```
+    pub fn get_warnings(&self) -> &Vec<String> {&self.warnings}
+
+    /// returns the flags of every option that was given more than once, in the order they were first given,
+    /// see `option_parser::find_duplicated_flags()`
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE")];
+    ///     
+    ///     //given once
+    ///     let args: Vec<String> = ["foo.exe", "-o", "a.txt"].iter().map(|arg| arg.to_string()).collect();
+    ///     assert!(Parser::new(&args, &valid_options, &[]).unwrap().duplicated_flags().is_empty());
+    ///     
+    ///     //given twice, the first one wins, but the repeat is tracked
+    ///     let args: Vec<String> = ["foo.exe", "-o", "a.txt", "-o", "b.txt"].iter().map(|arg| arg.to_string()).collect();
+    ///     let parser = Parser::new(&args, &valid_options, &[]).unwrap();
+    ///     assert_eq!(parser.get_option_arguments_found()[0].get_data(), Some("a.txt"));
+    ///     assert_eq!(parser.duplicated_flags(), vec![String::from("-o")]);
+    ///     
+    ///     //in the order they were first given, not the order they were defined
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///     ];
+    ///     let args: Vec<String> = ["foo.exe", "--recursive", "-o", "a.txt", "-r", "-o", "b.txt"].iter().map(|arg| arg.to_string()).collect();
+    ///     assert_eq!(Parser::new(&args, &valid_options, &[]).unwrap().duplicated_flags(), vec![String::from("--recursive"), String::from("-o")]);
+    /// ```
+    pub fn duplicated_flags(&self) -> Vec<String> {self.duplicated_flags.clone()}
+
+    /// get a reference to the report of what happened while parsing, ei. which presets were applied and which values were checked
+    /// # None
+    /// - returns none if this Parser wasn't created with `ParserSettings::with_report(true)`
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{constraints::{Constraint, Constraints}, option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, parse_report::ReportEntry, parser_settings::ParserSettings, value_parser::ValueParser, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_preset(&ClOptionInfo::new("-a", "--archive", "Same as -r --format BULLET").unwrap(), &["-r", "--format", "BULLET"]),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"]).ignore_case(true),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE").with_allowed_values(&["out.txt"]),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-j", "--jobs", "How many jobs to run at once").unwrap(), "JOBS").with_value_parser(ValueParser::IntParser),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-l", "--level", "How much to log").unwrap(), "LEVEL").default_with(|| String::from("info")),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let args: Vec<String> = ["foo.exe", "-a", "--output", "out.txt", "-j", "4", "recursive"].iter().map(|arg| arg.to_string()).collect();
+    ///     let settings = ParserSettings::new()
+    ///         .with_report(true)
+    ///         .with_constraints(Constraints::new().with(Constraint::Requires(String::from("archive"), String::from("output"))));
+    ///     
+    ///     let parser = Parser::new_with_settings(&args, &valid_options, &expected_parameters, &settings).unwrap();
+    ///     assert_eq!(parser.report().unwrap().get_entries(), &vec![
+    ///         ReportEntry::ValuesChecked { flag: String::from("--output"), values: vec![String::from("out.txt")] },
+    ///         ReportEntry::PresetApplied { flag: String::from("--archive"), applied_to: vec![String::from("--recursive"), String::from("--format")] },
+    ///         ReportEntry::ValidatorPassed { flag: String::from("--jobs"), validator: ValueParser::IntParser },
+    ///         ReportEntry::DefaultApplied { flag: String::from("--level"), value: String::from("info") },
+    ///         ReportEntry::ConstraintPassed(Constraint::Requires(String::from("archive"), String::from("output"))),
+    ///         ReportEntry::Warning(String::from("'recursive' was used as the PATH parameter, did you mean '--recursive'?")),
+    ///     ]);
+    ///     
+    ///     //reports aren't collected by default
+    ///     assert!(Parser::new(&args, &valid_options, &expected_parameters).unwrap().report().is_none());
+    /// ```
+    pub fn report(&self) -> Option<&parse_report::ParseReport> {self.report.as_ref()}
+
+    /// get a reference to the counters and durations measured while parsing, see `parse_metrics::ParseMetrics`
+    /// # None
+    /// - returns none if this Parser wasn't created with `ParserSettings::metrics(true)`
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parser_settings::ParserSettings, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-r")];
+    ///     
+    ///     let parser = Parser::new_with_settings(&args, &valid_options, &[], &ParserSettings::new().metrics(true)).unwrap();
+    ///     assert_eq!(parser.metrics().unwrap().get_flag_count(), 1);
+    ///     
+    ///     //metrics aren't collected by default
+    ///     assert!(Parser::new(&args, &valid_options, &[]).unwrap().metrics().is_none());
+    /// ```
+    pub fn metrics(&self) -> Option<&parse_metrics::ParseMetrics> {self.metrics.as_ref()}
+
+    /// get the subcommand the args start with (right after the program name)
+    /// # None
+    /// - returns none if the first arg isn't one of the subcommands set with `ParserSettings::subcommands()`
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parser_settings::ParserSettings, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--release", "Build with optimizations").unwrap())];
+    ///     let settings = ParserSettings::new().subcommands(&["build", "test"]).allow_unmatched_args(true); //a first arg that isn't a subcommand is a positional
+    ///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    ///     
+    ///     //a recognized subcommand
+    ///     let parser = Parser::new_with_settings(&to_args(&["foo.exe", "build", "-r"]), &valid_options, &[], &settings).unwrap();
+    ///     assert_eq!(parser.subcommand(), Some("build"));
+    ///     assert!(parser.get_option_arguments_found()[0].get_present()); //the rest is parsed as usual
+    ///     
+    ///     //an unrecognized first arg
+    ///     let parser = Parser::new_with_settings(&to_args(&["foo.exe", "deploy", "-r"]), &valid_options, &[], &settings).unwrap();
+    ///     assert_eq!(parser.subcommand(), None);
+    ///     assert!(parser.get_warnings().is_empty()); //it isn't close to any subcommand
+    ///     
+    ///     //a misspelled subcommand is warned about
+    ///     let parser = Parser::new_with_settings(&to_args(&["foo.exe", "biuld", "-r"]), &valid_options, &[], &settings).unwrap();
+    ///     assert_eq!(parser.subcommand(), None);
+    ///     assert_eq!(parser.get_warnings(), &vec![String::from("'biuld' isn't a subcommand, did you mean 'build'?")]);
+    ///     
+    ///     //a flag first
+    ///     let parser = Parser::new_with_settings(&to_args(&["foo.exe", "-r", "build"]), &valid_options, &[], &settings).unwrap();
+    ///     assert_eq!(parser.subcommand(), None);
+    /// ```
+    /// 
+    /// subcommands can have their own options, which are only valid after them
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parser_settings::ParserSettings, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-v", "--verbose", "Print more").unwrap())];
+    ///     let add_options: Vec<ClOption> = vec![ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Files to add").unwrap(), "FILES")];
+    ///     let settings = ParserSettings::new().subcommand_options("add", &add_options).allow_unmatched_args(true);
+    ///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    ///     
+    ///     //after the subcommand, its options are found after the usual ones
+    ///     let parser = Parser::new_with_settings(&to_args(&["foo.exe", "add", "--filter", "a.txt", "-v"]), &valid_options, &[], &settings).unwrap();
+    ///     assert_eq!(parser.subcommand(), Some("add"));
+    ///     assert_eq!(parser.get_option_arguments_found()[1].get_list(), Some(&vec![String::from("a.txt")]));
+    ///     
+    ///     //before the subcommand, they get a targeted error
+    ///     let error = Parser::new_with_settings(&to_args(&["foo.exe", "--filter", "a.txt", "add"]), &valid_options, &[], &settings).err().unwrap();
+    ///     assert_eq!(error.to_string(), "User Error: '--filter' is an option of the 'add' subcommand; place it after 'add'");
+    ///     
+    ///     //without the subcommand, or for flags no subcommand has, the error is the usual one
+    ///     let error = Parser::new_with_settings(&to_args(&["foo.exe", "--filter", "a.txt"]), &valid_options, &[], &settings).err().unwrap();
+    ///     assert_eq!(error.to_string(), "User Error: unknown options: '--filter' (arg 1)");
+    ///     let error = Parser::new_with_settings(&to_args(&["foo.exe", "--force", "add"]), &valid_options, &[], &settings).err().unwrap();
+    ///     assert_eq!(error.to_string(), "User Error: unknown options: '--force' (arg 1)");
+    /// ```
+    pub fn subcommand(&self) -> Option<&str> {self.subcommand.as_deref()}
+
+    /// returns a human friendly summary of the args that were given, one per line, ei. for confirmation prompts
+    /// 
+    /// only present options are listed (with their list/data, or toggle), followed by every parameter that was filled,
+    /// in the order they were defined
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///         ClOption::new_flag_toggle(&ClOptionInfo::new("-c", "--color", "Toggle colored output").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-v", "", "Print more").unwrap()),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let args: Vec<String> = ["foo.exe", "-v", "--format", "NUMERIC", "-c", "-r", "path/to/search"].iter().map(|arg| arg.to_string()).collect();
+    ///     let parser = Parser::new(&args, &valid_options, &expected_parameters).unwrap();
+    ///     
+    ///     assert_eq!(parser.summary(), "--recursive\n--format: NUMERIC\n--color: on\n-v\nPATH: path/to/search");
+    /// ```
+    pub fn summary(&self) -> String {
+        //DATA
+        let mut lines: Vec<String> = Vec::new();
+
+        for option in self.option_arguments_found.iter().filter(|option| option.get_present()) {
+            let flag: &str = if option.get_long_flag().is_empty() {option.get_short_flag()} else {option.get_long_flag()};
+            lines.push(match option {
+                option_args::ClOption::FlagToggle { toggle, .. } => format!("{}: {}", flag, if *toggle {"on"} else {"off"}),
+                option_args::ClOption::FlagCount { count, .. } => format!("{}: {}", flag, count),
+                option_args::ClOption::FlagList { list, .. } => format!("{}: {}", flag, list.join(", ")),
+                option_args::ClOption::FlagData { data, .. } => format!("{}: {}", flag, data),
+                option_args::ClOption::Flag { .. } | option_args::ClOption::FlagPreset { .. } => flag.to_string(),
+            });
+        }
+        for parameter in self.parameter_arguments_found.iter().filter(|parameter| !parameter.get_data().is_empty()) {
+            lines.push(format!("{}: {}", parameter.get_name(), parameter.get_data()));
+        }
+
+        lines.join("\n")
+    }
+
+    /// returns the options and parameters whose resolved values differ from those of `baseline`, ei. to log only the settings that aren't the defaults
+    /// 
+    /// options are named by their long flag (or short flag if they don't have one), and listed before parameters, in the order they were defined
+    /// 
+    /// # Notes:
+    /// - the resolved value of a Flag or FlagPreset is whether it's present, of a FlagToggle its toggle, of a FlagCount its count, of a FlagList its list, and of a FlagData its data, including its default
+    /// - the resolved value of a parameter is its data, or its values if it's variadic
+    /// - options and parameters `baseline` doesn't have differ if they're present (or filled)
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-j", "--jobs", "How many jobs to run at once").unwrap(), "JOBS").default_with(|| String::from("8")),
+    ///         ClOption::new_flag_toggle(&ClOptionInfo::new("-c", "--color", "Toggle colored output").unwrap()),
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let parse = |args: &[&str]| Parser::new(&args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>(), &valid_options, &expected_parameters).unwrap();
+    ///     let baseline = parse(&["foo.exe", "."]);
+    ///     
+    ///     //the same values, even if given explicitly or toggled back
+    ///     assert!(parse(&["foo.exe", "--jobs", "8", "-c", "-c", "."]).changed_from(&baseline).is_empty());
+    ///     
+    ///     //some differ
+    ///     let parser = parse(&["foo.exe", "-r", "-j", "8", "-f", "rs", "src"]);
+    ///     assert_eq!(parser.changed_from(&baseline), vec!["--recursive", "--filter", "PATH"]);
+    ///     assert_eq!(baseline.changed_from(&parser), vec!["--recursive", "--filter", "PATH"]);
+    ///     
+    ///     //options the baseline doesn't have
+    ///     let other = Parser::new(&[String::from("foo.exe"), String::from(".")], &[], &expected_parameters).unwrap();
+    ///     assert_eq!(parse(&["foo.exe", "-c", "."]).changed_from(&other), vec!["--color"]);
+    /// ```
+    pub fn changed_from(&self, baseline: &Parser) -> Vec<String> {
+        //returns the resolved value of an option
+        let resolve = |option: &option_args::ClOption| -> Vec<String> {
+            match option {
+                option_args::ClOption::Flag { present, .. } | option_args::ClOption::FlagPreset { present, .. } => vec![present.to_string()],
+                option_args::ClOption::FlagToggle { toggle, .. } => vec![toggle.to_string()],
+                option_args::ClOption::FlagCount { count, .. } => vec![count.to_string()],
+                option_args::ClOption::FlagList { list, .. } => list.clone(),
+                option_args::ClOption::FlagData { data, .. } => vec![data.clone()],
+            }
+        };
+        let changed_options = self.option_arguments_found.iter()
+        .filter(|option| match baseline.option_arguments_found.iter().find(|other| other.get_short_flag().eq(option.get_short_flag()) && other.get_long_flag().eq(option.get_long_flag())) {
+            Some(other) => resolve(option) != resolve(other),
+            None => option.get_present(),
+        })
+        .map(|option| option_parser::get_display_flag(option).to_string());
+
+        //and of a parameter
+        let resolve = |parameter: &parameter_args::ClParameter| -> Vec<String> {
+            if parameter.get_variadic() {parameter.get_values().clone()} else {vec![parameter.get_data().to_string()]}
+        };
+        let changed_parameters = self.parameter_arguments_found.iter()
+        .filter(|parameter| match baseline.parameter_arguments_found.iter().find(|other| other.get_name().eq(parameter.get_name())) {
+            Some(other) => resolve(parameter) != resolve(other),
+            None => !parameter.get_data().is_empty(),
+        })
+        .map(|parameter| parameter.get_name().to_string());
+
+        changed_options.chain(changed_parameters).collect()
+    }
+
+    /// returns the data of the option with the flag `flag` as a variant of `E`, see `ClOption::new_flag_data_enum()`
+    /// 
+    /// # None
+    /// - the option wasn't present
+    /// 
+    /// # Errors
+    /// - no option has the flag `flag`, or it isn't of type ClOption::FlagData
+    /// - the data isn't the name of a variant of `E`, ei. the option wasn't defined with the variants of `E` as its allowed values
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+    /// //... 
+    ///     clia::value_enum! {
+    ///         Format { Default, Bullet }
+    ///     }
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data_enum::<Format>(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").ignore_case(true)];
+    ///     
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-F"), String::from("bullet")];
+    ///     let parser = Parser::new(&args, &valid_options, &[]).unwrap();
+    ///     assert_eq!(parser.option_enum::<Format>("--format").unwrap(), Some(Format::Bullet));
+    ///     assert!(parser.option_enum::<Format>("--unknown").is_err());
+    ///     
+    ///     //not present
+    ///     let parser = Parser::new(&[String::from("foo.exe")], &valid_options, &[]).unwrap();
+    ///     assert_eq!(parser.option_enum::<Format>("-F").unwrap(), None);
+    /// ```
+    pub fn option_enum<E: value_enum::ValueEnum>(&self, flag: &str) -> Result<Option<E>, parse_error::ParseError> {
+        //DATA
+        let option: &option_args::ClOption = match self.option_arguments_found.iter().find(|option| option.has_flag(flag)) {
+            Some(option) => option,
+            None => return Err(parse_error::ParseError::new(parse_error::ParseErrorKind::Other, None, &format!("BUG: no option has the flag({})", flag))),
+        };
+        let data: &str = match option.get_data() {
+            Some(data) => data,
+            None => return Err(parse_error::ParseError::new(parse_error::ParseErrorKind::Other, None, &format!("BUG: the option with flag({}) doesn't take data", flag))),
+        };
+
+        if !option.get_present() {
+            return Ok(None);
+        }
+        match E::from_name(data) {
+            Some(value) => Ok(Some(value)),
+            None => Err(parse_error::ParseError::new(parse_error::ParseErrorKind::InvalidValue, None, &format!("User Error: invalid value '{}' for flag({}), possible values are: {}", data, flag, E::variants().join(", "))).with_flag(flag)),
+        }
+    }
+
+    //clap style getters, to ease migrating call sites from clap
+    /// returns whether the option identified by `id` was set, where `id` is the options long flag without the `--`
+    /// (or its short flag without the `-`, if no long flag matches)
+    /// 
+    /// for options of type ClOption::FlagToggle this is the toggle, otherwise it's whether the option was present
+    /// 
+    /// # Notes:
+    /// - unknown `id`s return false
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-v", "", "Print more").unwrap()),
+    ///     ];
+    ///     let args: Vec<String> = ["foo.exe", "-r", "-v"].iter().map(|arg| arg.to_string()).collect();
+    ///     let parser = Parser::new(&args, &valid_options, &[]).unwrap();
+    ///     
+    ///     assert!(parser.get_flag("recursive"));
+    ///     assert!(parser.get_flag("v"));
+    ///     assert!(!parser.get_flag("unknown"));
+    /// ```
+    pub fn get_flag(&self, id: &str) -> bool {
+        self.find_option_by_id(id).is_some_and(|option| option.get_toggle().unwrap_or(option.get_present()))
+    }
+
+    /// returns the data of the option identified by `id` (see `Parser::get_flag()`), or, if no option matches, the data of the parameter named `id`
+    /// 
+    /// # None
+    /// - the option identified by `id` isn't of type ClOption::FlagData, or wasn't present
+    /// - nothing is identified by `id`
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let args: Vec<String> = ["foo.exe", "--format", "BULLET", "path/to/search"].iter().map(|arg| arg.to_string()).collect();
+    ///     let parser = Parser::new(&args, &valid_options, &expected_parameters).unwrap();
+    ///     
+    ///     assert_eq!(parser.get_one("format"), Some("BULLET"));
+    ///     assert_eq!(parser.get_one("PATH"), Some("path/to/search"));
+    ///     assert_eq!(parser.get_one("output"), None); //not present
+    ///     assert_eq!(parser.get_one("unknown"), None);
+    /// ```
+    pub fn get_one(&self, id: &str) -> Option<&str> {
+        match self.find_option_by_id(id) {
+            Some(option) => if option.get_present() {option.get_data()} else {None},
+            None => self.parameter_arguments_found.iter().find(|parameter| parameter.get_name().eq(id)).map(|parameter| parameter.get_data()),
+        }
+    }
+
+    /// returns the list of the option identified by `id` (see `Parser::get_flag()`), or the values of the variadic parameter named `id`
+    /// 
+    /// # None
+    /// - the option identified by `id` isn't of type ClOption::FlagList, or wasn't present
+    /// - the parameter named `id` isn't variadic
+    /// - nothing is identified by `id`
+    /// 
+    /// # Examples 
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+    /// //... 
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS")];
+    ///     let args: Vec<String> = ["foo.exe", "--filter", "rs,toml"].iter().map(|arg| arg.to_string()).collect();
+    ///     let parser = Parser::new(&args, &valid_options, &[]).unwrap();
+    ///     
+    ///     assert_eq!(parser.get_many("filter"), Some([String::from("rs"), String::from("toml")].as_slice()));
+    ///     assert_eq!(parser.get_many("unknown"), None);
+    /// ```
+    /// 
+    /// variadic parameters
+    /// ```
+    /// use clia::{parameter_args::ClParameter, Parser};
+    /// //... 
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("SRC", "Files to copy").variadic(true), ClParameter::new("DEST", "Where to copy them")];
+    ///     let args: Vec<String> = ["cp", "a.txt", "b.txt", "dir"].iter().map(|arg| arg.to_string()).collect();
+    ///     let parser = Parser::new(&args, &[], &expected_parameters).unwrap();
+    ///     
+    ///     assert_eq!(parser.get_many("SRC"), Some([String::from("a.txt"), String::from("b.txt")].as_slice()));
+    ///     assert_eq!(parser.get_many("DEST"), None);
+    ///     assert!(Parser::help("cp", "", "", &[], &expected_parameters).contains("<SRC>... <DEST>"));
+    /// ```
+    pub fn get_many(&self, id: &str) -> Option<&[String]> {
+        match self.find_option_by_id(id) {
+            Some(option) => if option.get_present() {option.get_list().map(|list| list.as_slice())} else {None},
+            None => self.parameter_arguments_found.iter().find(|parameter| parameter.get_name().eq(id) && parameter.get_variadic()).map(|parameter| parameter.get_values().as_slice()),
+        }
+    }
+
+    /// returns the found option whose long flag (without the `--`) is `id`, or failing that, whose short flag (without the `-`) is `id`
+    pub(crate) fn find_option_by_id(&self, id: &str) -> Option<&option_args::ClOption> {
+        if id.is_empty() {
+            return None;
+        }
+        self.option_arguments_found.iter().find(|option| option.get_long_flag().strip_prefix("--") == Some(id))
+        .or_else(|| self.option_arguments_found.iter().find(|option| option.get_short_flag().strip_prefix('-') == Some(id)))
+    }
     
 }
\ No newline at end of file
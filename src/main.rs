@@ -63,11 +63,10 @@ fn main() {
     let args: Vec<String> = env::args().collect(); //read the argument values from env, and collect them into a string vector
 
     //call parser
-    let arg_parser;
-    match Parser::new(&args, &valid_options, &expected_parameters) {
-        Ok(arg_par) => arg_parser = arg_par,
+    let arg_parser = match Parser::new(&args, &valid_options, &expected_parameters) {
+        Ok(arg_par) => arg_par,
         Err(e) => {println!("{}", Parser::help("foo.exe", "by Anthony Rubick", "Just here as an example of things you can do", &valid_options, &expected_parameters)); panic!("{}", e);},
-    }
+    };
 
     /*
     third step is to access the "found" fields from the parser
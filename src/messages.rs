@@ -0,0 +1,107 @@
+//! # Messages
+//! 'messages' is a module containing utilities for
+//! rewording the error messages of the parser, ei. to translate them
+//!
+//! messages are set with `ParserSettings::with_messages()`
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use crate::parse_error::{ParseError, ParseErrorKind};
+
+/// stores a template for the message of each kind of error, errors of kinds without a template keep their built-in english message
+///
+/// templates can contain these placeholders:
+/// - `{message}`, the built-in message
+/// - `{flag}`, the flag the error is about, or nothing
+/// - `{arg_index}`, the index of the arg the error is about, or nothing
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Messages {
+    templates: Vec<(ParseErrorKind, String)>,
+}
+impl Messages {
+    /// creates a new Messages, with no templates, so every error keeps its built-in english message
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{messages::Messages, parse_error::ParseErrorKind};
+    /// //...
+    ///     assert!(Messages::new().get_template(ParseErrorKind::UnknownFlag).is_none());
+    /// ```
+    pub fn new() -> Messages {
+        Messages::default()
+    }
+
+    /// sets the template for the message of errors of `kind`, replacing any it had
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{messages::Messages, option_args::{ClOption, ClOptionInfo}, parse_error::ParseErrorKind, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ///     ];
+    ///     let messages = Messages::new().with_template(ParseErrorKind::UnknownFlag, "Benutzerfehler: unbekannte Option '{flag}' (Argument {arg_index})");
+    ///     let settings = ParserSettings::new().with_messages(messages);
+    ///
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-r"), String::from("--frmat")];
+    ///     assert_eq!(
+    ///         Parser::new_with_settings(&args, &valid_options, &[], &settings).err().unwrap().to_string(),
+    ///         "Benutzerfehler: unbekannte Option '--frmat' (Argument 2)"
+    ///     );
+    ///     //other errors are unchanged
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-r"), String::from("-o")];
+    ///     assert_eq!(
+    ///         Parser::new_with_settings(&args, &valid_options, &[], &settings).err().unwrap().to_string(),
+    ///         Parser::new(&args, &valid_options, &[]).err().unwrap().to_string()
+    ///     );
+    /// ```
+    pub fn with_template(mut self, kind: ParseErrorKind, template: &str) -> Messages {
+        self.templates.retain(|(existing, _)| existing.ne(&kind));
+        self.templates.push((kind, template.to_string()));
+        self
+    }
+
+    /// returns `error`, with its message rewritten by the template for its kind, if there is one
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{messages::Messages, parse_error::{ParseError, ParseErrorKind}};
+    /// //...
+    ///     let messages = Messages::new().with_template(ParseErrorKind::MissingValue, "{flag}: {message}!");
+    ///
+    ///     let error = ParseError::new(ParseErrorKind::MissingValue, Some(1), "User Error: no data").with_flag("--format");
+    ///     assert_eq!(messages.localize(&error).get_message(), "--format: User Error: no data!");
+    ///     assert_eq!(messages.localize(&error).get_flag(), Some("--format"));
+    ///
+    ///     let error = ParseError::new(ParseErrorKind::UnknownFlag, Some(1), "User Error: unknown options: '-z' (arg 1)");
+    ///     assert_eq!(messages.localize(&error), error);
+    /// ```
+    pub fn localize(&self, error: &ParseError) -> ParseError {
+        let Some(template) = self.get_template(error.get_kind()) else {return error.clone()};
+        let message: String = template
+            .replace("{message}", error.get_message())
+            .replace("{flag}", error.get_flag().unwrap_or_default())
+            .replace("{arg_index}", &error.get_arg_index().map_or(String::new(), |index| index.to_string()));
+        error.clone().with_message(&message)
+    }
+
+    //getter methods
+    /// get the template for the message of errors of `kind`
+    ///
+    /// # None
+    /// - errors of `kind` keep their built-in message
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{messages::Messages, parse_error::ParseErrorKind};
+    /// //...
+    ///     let messages = Messages::new().with_template(ParseErrorKind::UnknownFlag, "unknown: {flag}");
+    ///     assert_eq!(messages.get_template(ParseErrorKind::UnknownFlag), Some("unknown: {flag}"));
+    ///     assert_eq!(messages.get_template(ParseErrorKind::InvalidValue), None);
+    /// ```
+    pub fn get_template(&self, kind: ParseErrorKind) -> Option<&str> {
+        self.templates.iter().find(|(existing, _)| existing.eq(&kind)).map(|(_, template)| template.as_str())
+    }
+}
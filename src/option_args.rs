@@ -19,15 +19,44 @@
 #![warn(rustdoc::missing_doc_code_examples)]
 
 use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::help;
+use crate::value_enum::ValueEnum;
+use crate::value_parser::{self, Encoding, ValueParser};
 
 /// stores the short_flag, long_flag, and description of an option
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ClOptionInfo {
     short_flag: String,
     long_flag: String,
     description:String,
+    long_description: String,
+    meta: Vec<(String, String)>,
 }
 impl ClOptionInfo {
+    /// creates a new ClOptionInfoBuilder, for building a ClOptionInfo one field at a time
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     let info = ClOptionInfo::builder()
+    ///         .short("-r")
+    ///         .long("--recursive")
+    ///         .description("Search through subdirectories")
+    ///         .long_description("Search through subdirectories, and their subdirectories, and so on")
+    ///         .build()
+    ///         .unwrap();
+    ///     
+    ///     assert_eq!(info, ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap().with_long_description("Search through subdirectories, and their subdirectories, and so on"));
+    /// ```
+    pub fn builder() -> ClOptionInfoBuilder {
+        ClOptionInfoBuilder::default()
+    }
+
     /// creates a new ClOptionInfo with the given `short_flag`, `long_flag`, and `description`
     /// 
     /// # Notes: 
@@ -77,6 +106,7 @@ impl ClOptionInfo {
     ///     assert!(ClOptionInfo::new("-r", "recursive", "Search through subdirectories").is_err()); //doesn't start with '--'
     ///     assert!(ClOptionInfo::new("-r", "--recursive-7", "Search through subdirectories").is_err()); //7 isn't a valid character
     ///     assert!(ClOptionInfo::new("-r", "--recursive-%", "Search through subdirectories").is_err()); //% isn't a valid character
+    ///     assert!(ClOptionInfo::new("-r", "--recursive=yes", "Search through subdirectories").is_err()); //= isn't a valid character, it separates a flag from its value
     ///     assert!(ClOptionInfo::new("", "", "Search through subdirectories").is_err()); //both flags are empty
     ///     
     ///     // examples of properly formatted long_flags
@@ -85,34 +115,50 @@ impl ClOptionInfo {
     ///     assert!(ClOptionInfo::new("-r", "--Recurse-through-subfolders", "Search through subdirectories").is_ok()); //multiple words should be separated with '-'
     /// ```
     pub fn new(short_flag: &str, long_flag: &str, description: &str) -> Result<ClOptionInfo,Box<dyn Error>> {
-        let info = ClOptionInfo {
-            short_flag: short_flag.to_string(),
-            long_flag: long_flag.to_string(),
-            description: description.to_string(),
-        };
+        ClOptionInfo::builder().short(short_flag).long(long_flag).description(description).build()
+    }
 
-        if info.are_flags_formatted_properly() {
-            return Ok(info);
-        } else {
-            return Err(format!("BUG: short_flag (\"{}\") and/or long_flag (\"{}\") improperly formated!", short_flag, long_flag).into());
-        }
+    /// sets the `long_description` of this info, a more detailed description shown when getting help for just this option, see `ClOption::gen_long_help()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     let info = ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap().with_long_description("Search through subdirectories, and their subdirectories");
+    ///     assert_eq!(info.get_long_description(), "Search through subdirectories, and their subdirectories");
+    /// ```
+    pub fn with_long_description(mut self, long_description: &str) -> ClOptionInfo {
+        self.long_description = long_description.to_string();
+        self
     }
 
-    /// returns `true` is both flags are formatted properly, `false` parameterwise
-    fn are_flags_formatted_properly(&self) -> bool {
-        //if both flags are empty, return false
-        if self.short_flag.is_empty() && self.long_flag.is_empty() {
-            return false;
+    /// stores `value` under `key` in the metadata of this info, replacing any value already stored under `key`, see `ClOption::with_meta()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     let info = ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap().with_meta("plugin", "search");
+    ///     assert_eq!(info.get_meta("plugin"), Some("search"));
+    /// ```
+    pub fn with_meta(mut self, key: &str, value: &str) -> ClOptionInfo {
+        match self.meta.iter_mut().find(|(stored_key, _)| stored_key.eq(key)) {
+            Some((_, stored_value)) => *stored_value = value.to_string(),
+            None => self.meta.push((key.to_string(), value.to_string())),
         }
+        self
+    }
 
-        //return error if flags aren't valid
-        if ( self.short_flag.chars().any( |c| !(c.is_ascii_alphabetic() || c.eq(&'-')) ) ||   ( !self.short_flag.is_empty() &&(!self.short_flag.starts_with("-") || self.short_flag.len()!=2)) ) //if short flag: contains invalid characters OR (isn't empty AND (doesn't start with '-' OR isn't 2 characters long))
-        || ( self.long_flag.chars().any(  |c| !(c.is_ascii_alphabetic() || c.eq(&'-')) ) ||   ( !self.long_flag.is_empty()  && !self.long_flag.starts_with("--")) )//if long flag: contain invalid characters OR (isn't empty AND deosn't start with "--")
-        { //either flags are invalid:
-            return false;
-        } else {
-            return true;
-        }
+    /// returns `true` if `short_flag` is empty, or a `-` followed by an alphabetic ascii character
+    fn is_short_flag_formatted_properly(short_flag: &str) -> bool {
+        //if short flag: contains invalid characters OR (isn't empty AND (doesn't start with '-' OR isn't 2 characters long))
+        !( short_flag.chars().any( |c| !(c.is_ascii_alphabetic() || c.eq(&'-')) ) || ( !short_flag.is_empty() && (!short_flag.starts_with('-') || short_flag.len()!=2)) )
+    }
+
+    /// returns `true` if `long_flag` is empty, or `--` followed by a word (or words separated by additional `-`'s)
+    fn is_long_flag_formatted_properly(long_flag: &str) -> bool {
+        //if long flag: contain invalid characters OR (isn't empty AND deosn't start with "--")
+        !( long_flag.chars().any( |c| !(c.is_ascii_alphabetic() || c.eq(&'-')) ) || ( !long_flag.is_empty() && !long_flag.starts_with("--")) )
     }
 
     /// get a reference to  `short_flag`
@@ -142,11 +188,229 @@ impl ClOptionInfo {
     ///     assert_eq!(example_info.get_description(), "Search through subdirectories");
     /// ```
     pub fn get_description(&self) -> &str {&self.description}
+    /// get a reference to  `long_description`, which is empty if it wasn't set
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     let example_info: ClOptionInfo = ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap();
+    ///     assert_eq!(example_info.get_long_description(), "");
+    /// ```
+    pub fn get_long_description(&self) -> &str {&self.long_description}
+
+    /// get the metadata value stored under `key`
+    /// # None
+    /// - nothing is stored under `key`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     let example_info: ClOptionInfo = ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap();
+    ///     assert_eq!(example_info.get_meta("plugin"), None);
+    /// ```
+    pub fn get_meta(&self, key: &str) -> Option<&str> {
+        self.meta.iter().find(|(stored_key, _)| stored_key.eq(key)).map(|(_, value)| value.as_str())
+    }
+
+    /// returns `true` if `flag` is the short or long flag of this info
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     let example_info: ClOptionInfo = ClOptionInfo::new("", "--recursive", "Search through subdirectories").unwrap();
+    ///     assert!(example_info.has_flag("--recursive"));
+    ///     assert!(!example_info.has_flag("-r"));
+    ///     assert!(!example_info.has_flag("")); //an empty flag never matches
+    /// ```
+    pub fn has_flag(&self, flag: &str) -> bool {
+        !flag.is_empty() && (self.short_flag.eq(flag) || self.long_flag.eq(flag))
+    }
+
+    /// returns `description` split into lines no wider than `width`, breaking on spaces (see `help::wrap()`),
+    /// ei. for displaying it in a tooltip
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     let example_info: ClOptionInfo = ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap();
+    ///     assert_eq!(example_info.wrapped_description(80), vec!["Search through subdirectories"]);
+    ///     assert_eq!(example_info.wrapped_description(20), vec!["Search through", "subdirectories"]);
+    /// ```
+    pub fn wrapped_description(&self, width: usize) -> Vec<String> {
+        help::wrap(&self.description, width)
+    }
+}
+
+/// builds a ClOptionInfo one field at a time, see `ClOptionInfo::builder()`
+/// 
+/// flags are checked as they're set, the first improperly formatted flag is the error returned by `ClOptionInfoBuilder::build()`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClOptionInfoBuilder {
+    info: ClOptionInfo,
+    error: Option<String>,
+}
+impl ClOptionInfoBuilder {
+    //builder methods
+    /// sets the `short_flag`, which must be a `-` followed by any alphabetic ascii character, or empty if there is a long flag
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     assert!(ClOptionInfo::builder().short("-r").long("--recursive").build().is_ok());
+    ///     assert_eq!(
+    ///         ClOptionInfo::builder().short("-recursive").long("--recursive").build().unwrap_err().to_string(),
+    ///         "BUG: short_flag (\"-recursive\") improperly formated, it must be a '-' followed by an alphabetic ascii character"
+    ///     );
+    /// ```
+    pub fn short(mut self, short_flag: &str) -> ClOptionInfoBuilder {
+        if self.error.is_none() && !ClOptionInfo::is_short_flag_formatted_properly(short_flag) {
+            self.error = Some(format!("BUG: short_flag (\"{}\") improperly formated, it must be a '-' followed by an alphabetic ascii character", short_flag));
+        }
+        self.info.short_flag = short_flag.to_string();
+        self
+    }
+
+    /// sets the `long_flag`, which must be `--` followed by a word (or words separated by additional `-`'s), or empty if there is a short flag
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     assert!(ClOptionInfo::builder().long("--recurse-through-subfolders").build().is_ok());
+    ///     assert_eq!(
+    ///         ClOptionInfo::builder().long("-recursive").build().unwrap_err().to_string(),
+    ///         "BUG: long_flag (\"-recursive\") improperly formated, it must be '--' followed by a word (or words separated by '-')"
+    ///     );
+    /// ```
+    pub fn long(mut self, long_flag: &str) -> ClOptionInfoBuilder {
+        if self.error.is_none() && !ClOptionInfo::is_long_flag_formatted_properly(long_flag) {
+            self.error = Some(format!("BUG: long_flag (\"{}\") improperly formated, it must be '--' followed by a word (or words separated by '-')", long_flag));
+        }
+        self.info.long_flag = long_flag.to_string();
+        self
+    }
+
+    /// sets the `description`, shown in help messages
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     let info = ClOptionInfo::builder().long("--recursive").description("Search through subdirectories").build().unwrap();
+    ///     assert_eq!(info.get_description(), "Search through subdirectories");
+    /// ```
+    pub fn description(mut self, description: &str) -> ClOptionInfoBuilder {
+        self.info.description = description.to_string();
+        self
+    }
+
+    /// sets the `long_description`, see `ClOptionInfo::with_long_description()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     let info = ClOptionInfo::builder().long("--recursive").long_description("Search through subdirectories, and theirs").build().unwrap();
+    ///     assert_eq!(info.get_long_description(), "Search through subdirectories, and theirs");
+    /// ```
+    pub fn long_description(mut self, long_description: &str) -> ClOptionInfoBuilder {
+        self.info.long_description = long_description.to_string();
+        self
+    }
+
+    /// builds the ClOptionInfo
+    /// 
+    /// # Errors
+    /// - a flag that was set is formatted improperly
+    /// - neither flag was set
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::ClOptionInfo;
+    /// //...
+    ///     assert_eq!(ClOptionInfo::builder().description("Search through subdirectories").build().unwrap_err().to_string(), "BUG: options need a short_flag and/or a long_flag");
+    /// ```
+    pub fn build(self) -> Result<ClOptionInfo, Box<dyn Error>> {
+        if let Some(error) = self.error {
+            return Err(error.into());
+        }
+        if self.info.short_flag.is_empty() && self.info.long_flag.is_empty() {
+            return Err("BUG: options need a short_flag and/or a long_flag".into());
+        }
+
+        Ok(self.info)
+    }
+}
+
+/// produces the data of a ClOption::FlagData that wasn't given, only when it's needed, see `ClOption::default_with()`
+/// 
+/// LazyDefaults are equal if they share the same closure
+#[derive(Clone)]
+pub struct LazyDefault(Arc<dyn Fn() -> String + Send + Sync>);
+impl LazyDefault {
+    /// creates a new LazyDefault that produces data with `default`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::LazyDefault;
+    /// //...
+    ///     let default = LazyDefault::new(|| String::from("8"));
+    ///     assert_eq!(default.produce(), "8");
+    ///     assert_eq!(default, default.clone());
+    ///     assert_ne!(default, LazyDefault::new(|| String::from("8")));
+    /// ```
+    pub fn new(default: impl Fn() -> String + Send + Sync + 'static) -> LazyDefault {
+        LazyDefault(Arc::new(default))
+    }
+
+    /// runs the closure, returning the data it produces
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::LazyDefault;
+    /// //...
+    ///     assert_eq!(LazyDefault::new(|| String::from("BULLET")).produce(), "BULLET");
+    /// ```
+    pub fn produce(&self) -> String {
+        (self.0)()
+    }
+}
+impl fmt::Debug for LazyDefault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LazyDefault(..)")
+    }
+}
+impl PartialEq for LazyDefault {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
 
+/// what a ClOption::FlagData does when its flag is given more than once, see `ClOption::on_repeat()`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnRepeat {
+    /// keep the value given first, after either flag
+    #[default]
+    FirstWins,
+    /// keep the value given last, after either flag
+    LastWins,
+    /// giving the flag more than once is an error, of kind `ParseErrorKind::RepeatedFlag`
+    Error,
+    /// keep every value given, in the order they were given, see `ClOption::get_values()`, the data is the first of them
+    Accumulate,
 }
 
 /// consolidates the data of, and utilities for, the different types of options a command line program may use
 /// the types of options a program may want to get from command line arguments
+/// 
+/// options are created not present, with no list/data, and the parser returns copies with those set, see `Parser::get_option_arguments_found()`
+/// 
+/// # Notes:
+/// - `==` compares every field, including what parsing sets (ei. `present` and the list/data), so a defined option doesn't equal what's found for it once its flag is given
+/// - `ClOption::same_option()` only compares what identifies an option: its type, its info, and the name of its list/data
+/// - an option can be compared with a `ClOptionInfo` with `==`, which compares its info
 #[derive(Clone, Debug, PartialEq)]
 pub enum ClOption {
     /// for options like '-r' or '--recursive'
@@ -156,6 +420,31 @@ pub enum ClOption {
         /// the options info
         info: ClOptionInfo,
     },
+    /// for options like '-x' or '--toggle-something', that toggle a boolean every time they're given
+    FlagToggle {
+        /// is the flag present
+        present:bool,
+        /// the state of the toggle, `true` if the flag was given an odd number of times
+        toggle:bool,
+        /// the options info
+        info: ClOptionInfo,
+    },
+    /// for options like '-v' or '--verbose', that count how many times they're given, ei. `-vvv` is 3
+    FlagCount {
+        /// how many times the flag was given
+        count: u32,
+        /// the options info
+        info: ClOptionInfo,
+    },
+    /// for options like '--fast', that stand for a preset of other options (ei. `--threads 8 --cache`)
+    FlagPreset {
+        /// is the flag present
+        present:bool,
+        /// the args this flag stands for
+        preset: Vec<String>,
+        /// the options info
+        info: ClOptionInfo,
+    },
     /// for options like '-f <EXTENSIONS>...' or '--filter <EXTENSIONS>...'
     FlagList {
         /// is the flag present
@@ -164,6 +453,14 @@ pub enum ClOption {
         list_name: String,
         /// the list associated with this flag
         list: Vec<String>,
+        /// the values allowed in the list, if empty, anything is allowed
+        allowed_values: Vec<String>,
+        /// should items be matched against `allowed_values` ignoring case
+        ignore_case: bool,
+        /// are items `KEY: VALUE` pairs, see `ClOption::get_header_pairs()`
+        key_value_pairs: bool,
+        /// can lists be given in brackets, space separated, ei. `[a b c]`, see `ClOption::bracketed_lists()`
+        bracketed_lists: bool,
         /// the options info
         info: ClOptionInfo,
     },
@@ -175,6 +472,24 @@ pub enum ClOption {
         data_name: String,
         /// the data associated with this flag
         data: String,
+        /// the data associated with this flag, exactly as it was typed
+        raw_data: String,
+        /// the values allowed as data, if empty, anything is allowed
+        allowed_values: Vec<String>,
+        /// should data be matched against `allowed_values` ignoring case
+        ignore_case: bool,
+        /// checks that the data is well-formed, if there is one
+        value_parser: Option<ValueParser>,
+        /// the encoding of the data, if it's decoded into bytes, see `ClOption::get_bytes()`
+        encoding: Option<Encoding>,
+        /// is explicitly empty data (ei. `--format ""`) an error
+        reject_empty_value: bool,
+        /// produces the data if the flag isn't given, if there is one
+        default_with: Option<LazyDefault>,
+        /// what to do when the flag is given more than once
+        on_repeat: OnRepeat,
+        /// the data given after every occurrence of the flag that was kept, see `OnRepeat`
+        values: Vec<String>,
         /// the options info
         info: ClOptionInfo,
     },
@@ -201,7 +516,7 @@ impl ClOption {
 
         //build output
         match self {
-            ClOption::Flag {present:_,info} => {
+            ClOption::Flag { info, .. } | ClOption::FlagToggle { info, .. } | ClOption::FlagCount { info, .. } | ClOption::FlagPreset { info, .. } => {
                 //add short_flag
                 output += format!("    {}{}", info.short_flag, {if info.short_flag.is_empty() {' '} else {','}}).as_str();
 
@@ -228,16 +543,16 @@ impl ClOption {
                             (0..(38-output.len())).map(|_| " ").collect::<String>()
                         }
                     },
-                    info.description
+                    self.gen_help_description()
                 ).as_str();
             },
-            ClOption::FlagList { present:_, list_name, list:_, info } => {
+            ClOption::FlagList { info, .. } => {
                 //add short_flag
                 output += format!("    {}{}", info.short_flag, {if info.short_flag.is_empty() {' '} else {','}}).as_str();
 
                 //add long flag
                 output += format!(
-                    "{}{} <{}>...",
+                    "{}{}{}",
                     {
                         if output.len() > 8 {
                             String::from("\n        ")
@@ -246,7 +561,7 @@ impl ClOption {
                         }
                     },
                     info.long_flag,
-                    list_name
+                    self.gen_placeholder()
                 ).as_str();
 
                 //add description
@@ -259,16 +574,16 @@ impl ClOption {
                             (0..(38-output.len())).map(|_| " ").collect::<String>()
                         }
                     },
-                    info.description
+                    self.gen_help_description()
                 ).as_str();
             },
-            ClOption::FlagData { present:_, data_name, data:_, info } => {
+            ClOption::FlagData { info, .. } => {
                 //add short_flag
                 output += format!("    {}{}", info.short_flag, {if info.short_flag.is_empty() {' '} else {','}}).as_str();
 
                 //add long flag
                 output += format!(
-                    "{}{} <{}>",
+                    "{}{}{}",
                     {
                         if output.len() > 8 {
                             String::from("\n        ")
@@ -277,7 +592,7 @@ impl ClOption {
                         }
                     },
                     info.long_flag,
-                    data_name
+                    self.gen_placeholder()
                 ).as_str();
 
                 //add description
@@ -290,15 +605,230 @@ impl ClOption {
                             (0..(38-output.len())).map(|_| " ").collect::<String>()
                         }
                     },
-                    info.description
+                    self.gen_help_description()
                 ).as_str();
             },
         }
 
+        //an empty description would leave the padding at the end of the line
+        help::trim_line_ends(&output)
+    }
+
+    /// Creates the usage fragment of this option, its flag followed by the placeholder of what it takes, like in `ClOption::gen_help_line()`,
+    /// but without the description or padding, used for things like building a usage line
+    /// 
+    /// the short flag is used if the option has one, otherwise the long flag
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap());
+    ///     let flag_toggle_option = ClOption::new_flag_toggle(&ClOptionInfo::new("", "--color", "Color the output").unwrap());
+    ///     let flag_count_option = ClOption::new_flag_count(&ClOptionInfo::new("-v", "--verbose", "Print more").unwrap());
+    ///     let flag_preset_option = ClOption::new_flag_preset(&ClOptionInfo::new("", "--numbered", "Shorthand for numeric output").unwrap(), &["--format", "NUMERIC"]);
+    ///     let flag_list_option = ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXT");
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("", "--format", "Format to print output in").unwrap(), "FORMAT");
+    ///     
+    ///     assert_eq!(flag_option.gen_usage_fragment(),        "-r");
+    ///     assert_eq!(flag_toggle_option.gen_usage_fragment(), "--color");
+    ///     assert_eq!(flag_count_option.gen_usage_fragment(),  "-v");
+    ///     assert_eq!(flag_preset_option.gen_usage_fragment(), "--numbered");
+    ///     assert_eq!(flag_list_option.gen_usage_fragment(),   "-f <EXT>...");
+    ///     assert_eq!(flag_data_option.gen_usage_fragment(),   "--format <FORMAT>");
+    /// ```
+    pub fn gen_usage_fragment(&self) -> String {
+        let info = self.get_info();
+        let flag: &str = if info.short_flag.is_empty() {&info.long_flag} else {&info.short_flag};
+        format!("{}{}", flag, self.gen_placeholder())
+    }
+
+    /// returns the placeholder shown after the flags of this option, ei. ` <FORMAT>` or ` <LIST>...`, empty if it doesn't take a list/data
+    fn gen_placeholder(&self) -> String {
+        match self {
+            ClOption::Flag { .. } | ClOption::FlagToggle { .. } | ClOption::FlagCount { .. } | ClOption::FlagPreset { .. } => String::new(),
+            ClOption::FlagList { list_name, .. } => format!(" <{}>...", list_name),
+            ClOption::FlagData { data_name, .. } => format!(" <{}>", data_name),
+        }
+    }
+
+    /// Creates the description shown in help messages for this option, this is the description of the option
+    /// followed by any annotations, like the values it allows
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_option = ClOption::new_flag(& ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in").unwrap(), "FORMAT")
+    ///         .with_allowed_values(&["DEFAULT", "BULLET", "NUMERIC"]);
+    ///     
+    ///     let flag_preset_option = ClOption::new_flag_preset(&ClOptionInfo::new("", "--numbered", "Shorthand for numeric output").unwrap(), &["--format", "NUMERIC"]);
+    ///     
+    ///     assert_eq!(flag_option.gen_help_description(),        "Search through subdirectories recursively");
+    ///     assert_eq!(flag_data_option.gen_help_description(),   "Format to print output in [possible values: DEFAULT, BULLET, NUMERIC]");
+    ///     assert_eq!(flag_preset_option.gen_help_description(), "Shorthand for numeric output [same as: --format NUMERIC]");
+    /// ```
+    pub fn gen_help_description(&self) -> String {
+        self.annotate_description(self.get_description())
+    }
+
+    /// returns `description` followed by the annotations of this option, see `ClOption::gen_help_description()`
+    fn annotate_description(&self, description: &str) -> String {
+        match self {
+            ClOption::FlagPreset { preset, .. } => format!("{} [same as: {}]", description, preset.join(" ")),
+            ClOption::FlagCount { .. } => format!("{} [can be repeated]", description),
+            _ => match self.get_allowed_values() {
+                Some(allowed_values) if !allowed_values.is_empty() => format!("{} [possible values: {}]", description, allowed_values.join(", ")),
+                _ => description.to_string(),
+            },
+        }
+    }
+
+    /// Creates an instruction line for this option, like `ClOption::gen_help_line()`, but with the description wrapped
+    /// so that no line is wider than `width` (unless a single word doesn't fit)
+    /// 
+    /// wrapped lines of the description are aligned with the first
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_option = ClOption::new_flag(& ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
+    ///     
+    ///     assert_eq!(flag_option.gen_help_line_wrapped(80), flag_option.gen_help_line());
+    ///     assert_eq!(flag_option.gen_help_line_wrapped(60), String::from("    -r, --recursive                   Search through\n                                      subdirectories\n                                      recursively"));
+    /// ```
+    pub fn gen_help_line_wrapped(&self, width: usize) -> String {
+        let help_line: String = self.gen_help_line();
+        let description: String = self.gen_help_description();
+
+        //the description is always at the end of the help line, everything before it are the flags and their padding
+        help::trim_line_ends(&format!(
+            "{}{}",
+            help_line.strip_suffix(description.trim_end()).unwrap_or(&help_line),
+            help::join_indented(&help::wrap(&description, width.saturating_sub(38)), 38)
+        ))
+    }
+
+    /// Creates a multi-line help block for this option, with the flags (and placeholder) on the first line and the description on the next,
+    /// used for things like showing detailed help for a single option
+    /// 
+    /// the long description is used instead of the description, if there is one (see `ClOptionInfo::with_long_description()`)
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
+    ///     let flag_list_option = ClOption::new_flag_list(&ClOptionInfo::new("", "--look-for", "Comma separated list of strings to look for").unwrap(), "LIST");
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in").unwrap(), "FORMAT");
+    ///
+    ///     assert_eq!(flag_option.gen_long_help(),      String::from("    -r, --recursive\n        Search through subdirectories recursively"));
+    ///     assert_eq!(flag_list_option.gen_long_help(), String::from("    --look-for <LIST>...\n        Comma separated list of strings to look for"));
+    ///     assert_eq!(flag_data_option.gen_long_help(), String::from("    -f, --format <FORMAT>\n        Format to print output in"));
+    ///     
+    ///     let long_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap().with_long_description("Search through subdirectories, and theirs"));
+    ///     assert_eq!(long_option.gen_long_help(), String::from("    -r, --recursive\n        Search through subdirectories, and theirs"));
+    ///     assert!(long_option.gen_help_line().ends_with("Search through subdirectories"));
+    /// ```
+    pub fn gen_long_help(&self) -> String {
+        let info = self.get_info();
+
+        //add flags
+        let mut flags: Vec<&str> = Vec::new();
+        if !info.short_flag.is_empty() {flags.push(&info.short_flag);}
+        if !info.long_flag.is_empty() {flags.push(&info.long_flag);}
+        let mut output: String = format!("    {}", flags.join(", "));
+
+        //add placeholder
+        output += &self.gen_placeholder();
+
+        //add description, the long one if there is one
+        let description: &str = if info.long_description.is_empty() {&info.description} else {&info.long_description};
+        output += format!("\n        {}", self.annotate_description(description)).as_str();
+
         output
     }
 
-    
+    /// returns `true` if `flag` is this options short or long flag
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let option = ClOption::new_flag(&ClOptionInfo::new("", "--recursive", "Search through subdirectories").unwrap());
+    ///     assert!(option.has_flag("--recursive"));
+    ///     assert!(!option.has_flag("-r"));
+    ///     assert!(!option.has_flag("")); //an empty flag never matches
+    /// ```
+    pub fn has_flag(&self, flag: &str) -> bool {self.get_info().has_flag(flag)}
+
+    /// returns `true` if `other` is the same option as this one, ei. one of them was found for the other while parsing,
+    /// only their types, infos, and the names of their lists/data are compared, not what parsing sets or how they're checked
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT")];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-F"), String::from("BULLET")];
+    ///     let parser = Parser::new(&args, &valid_options, &[]).unwrap();
+    ///     let found: &ClOption = &parser.get_option_arguments_found()[0];
+    ///     
+    ///     //the same option, in a different state
+    ///     assert_ne!(found, &valid_options[0]);
+    ///     assert!(found.same_option(&valid_options[0]));
+    ///     
+    ///     //different options
+    ///     assert!(!found.same_option(&ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "STYLE")));
+    ///     assert!(!found.same_option(&ClOption::new_flag_list(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT")));
+    ///     assert!(!found.same_option(&ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format the output").unwrap(), "FORMAT")));
+    /// ```
+    pub fn same_option(&self, other: &ClOption) -> bool {
+        match (self, other) {
+            (Self::Flag { info, .. }, Self::Flag { info: other_info, .. })
+            | (Self::FlagToggle { info, .. }, Self::FlagToggle { info: other_info, .. })
+            | (Self::FlagCount { info, .. }, Self::FlagCount { info: other_info, .. })
+            | (Self::FlagPreset { info, .. }, Self::FlagPreset { info: other_info, .. }) => info.eq(other_info),
+            (Self::FlagList { list_name: name, info, .. }, Self::FlagList { list_name: other_name, info: other_info, .. })
+            | (Self::FlagData { data_name: name, info, .. }, Self::FlagData { data_name: other_name, info: other_info, .. }) => info.eq(other_info) && name.eq(other_name),
+            _ => false,
+        }
+    }
+
+    /// returns the candidates in `all` that are *not* in the list of this FlagList, in the order of `all`,
+    /// useful for filter style options like `--except <ITEMS>...`
+    ///
+    /// # Notes:
+    /// - options that aren't of type ClOption::FlagList exclude nothing, so every candidate is returned
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser};
+    /// //...
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--except"), String::from("lint,docs")];
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_list(&ClOptionInfo::new("-e", "--except", "Steps to skip").unwrap(), "STEPS")];
+    ///     let steps = ["build", "lint", "test", "docs"];
+    ///
+    ///     //nothing is excluded before parsing
+    ///     assert_eq!(valid_options[0].excluded_from(&steps), vec!["build", "lint", "test", "docs"]);
+    ///
+    ///     let found_options = option_parser::parse_for_options(&args, &valid_options).unwrap();
+    ///     assert_eq!(found_options[0].excluded_from(&steps), vec!["build", "test"]);
+    ///
+    ///     //other kinds of options exclude nothing
+    ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap());
+    ///     assert_eq!(flag_option.excluded_from(&steps), steps.to_vec());
+    /// ```
+    pub fn excluded_from<'a>(&self, all: &'a [&str]) -> Vec<&'a str> {
+        match self.get_list() {
+            Some(list) => all.iter().filter(|candidate| !list.iter().any(|item| item.eq(*candidate))).copied().collect(),
+            None => all.to_vec(),
+        }
+    }
+
+
     //get methods
 
     /// get a reference to `info`
@@ -311,9 +841,12 @@ impl ClOption {
     /// ```
     pub fn get_info(&self) -> &ClOptionInfo {
         match self {
-            Self::Flag { present:_, info } => &info,
-            Self::FlagList { present:_, list_name:_, list:_, info } => &info,
-            Self::FlagData { present:_, data_name:_, data:_, info } => &info,
+            Self::Flag { info, .. } => info,
+            Self::FlagToggle { info, .. } => info,
+            Self::FlagCount { info, .. } => info,
+            Self::FlagPreset { info, .. } => info,
+            Self::FlagList { info, .. } => info,
+            Self::FlagData { info, .. } => info,
         }
     }
     /// get a reference to  `short_flag`
@@ -346,14 +879,29 @@ impl ClOption {
     /// ```
     pub fn get_description(&self) -> &str {self.get_info().get_description()}
 
+    /// get the metadata value stored under `key`, see `ClOption::with_meta()`
+    /// # None
+    /// - nothing is stored under `key`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let example_option: ClOption = ClOption::new_flag( &ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap() );
+    ///     assert_eq!(example_option.get_meta("plugin"), None);
+    /// ```
+    pub fn get_meta(&self, key: &str) -> Option<&str> {self.get_info().get_meta(key)}
+
 
     /// gets a reference to `present`
     /// 
     /// # Examples
     /// ```
     /// use std::env;
-    /// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, Parser};
     /// //...
+    ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
+    /// 
     ///     //collect cli arguments
     ///     let args: Vec<String> = env::args().collect();
     ///     # let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("-r")];
@@ -365,7 +913,6 @@ impl ClOption {
     ///     let expected_parameters: Vec<ClParameter> = Vec::new();
     ///     //...
     /// 
-    ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
     ///     //default is false
     ///     assert_eq!(flag_option.get_present(), false );
     ///     
@@ -376,9 +923,12 @@ impl ClOption {
     /// ```
     pub fn get_present(&self) -> bool {
         match self {
-            ClOption::Flag { present, info:_ } => *present,
-            ClOption::FlagList { present, list_name:_, list:_, info:_ } => *present,
-            ClOption::FlagData { present, data_name:_, data:_, info:_ } => *present,
+            ClOption::Flag { present, .. } => *present,
+            ClOption::FlagToggle { present, .. } => *present,
+            ClOption::FlagCount { count, .. } => *count > 0,
+            ClOption::FlagPreset { present, .. } => *present,
+            ClOption::FlagList { present, .. } => *present,
+            ClOption::FlagData { present, .. } => *present,
         }
     }
 
@@ -389,8 +939,10 @@ impl ClOption {
     /// # Examples
     /// ```
     /// use std::env;
-    /// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, Parser};
     /// //...
+    ///     let flag_list_option = ClOption::new_flag_list(&ClOptionInfo::new("-l", "--look-for", "Comma separated list of strings to look for").unwrap(), "LIST");
+    /// 
     ///     //collect cli arguments
     ///     let args: Vec<String> = env::args().collect();
     ///     # let args: Vec<String> = vec!["path/to/executable/".to_string(), "-l".to_string(), "a,list,of,stuff".to_string()];
@@ -402,7 +954,6 @@ impl ClOption {
     ///     let expected_parameters: Vec<ClParameter> = Vec::new();
     ///     //...
     /// 
-    ///     let flag_list_option = ClOption::new_flag_list(&ClOptionInfo::new("-l", "--look-for", "Comma separated list of strings to look for").unwrap(), "LIST");
     ///     //default is empty
     ///     assert!( flag_list_option.get_list().unwrap().is_empty());
     ///     
@@ -417,9 +968,12 @@ impl ClOption {
     /// ```
     pub fn get_list(&self) ->  Option<&Vec<String>> {
         match self {
-            ClOption::Flag { present:_, info:_ } => None,
-            ClOption::FlagList { present:_, list_name:_, list, info:_ } => Some(list),
-            ClOption::FlagData { present:_, data_name:_, data:_, info:_ } => None,
+            ClOption::Flag { .. } => None,
+            ClOption::FlagToggle { .. } => None,
+            ClOption::FlagCount { .. } => None,
+            ClOption::FlagPreset { .. } => None,
+            ClOption::FlagList { list, .. } => Some(list),
+            ClOption::FlagData { .. } => None,
         }
     }
 
@@ -430,8 +984,10 @@ impl ClOption {
     /// # Examples
     /// ```
     /// use std::env;
-    /// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, Parser};
     /// //...    
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in, valid formats are: DEFAULT, BULLET, and NUMERIC").unwrap(), "FORMAT");
+    /// 
     ///     //collect cli arguments
     ///     let args: Vec<String> = env::args().collect();
     ///     # let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("-f"), String::from("DEFAULT")];
@@ -443,14 +999,13 @@ impl ClOption {
     ///     let expected_parameters: Vec<ClParameter> = Vec::new();
     ///     //...
     ///     
-    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in, valid formats are: DEFAULT, BULLET, and NUMERIC").unwrap(), "FORMAT");
     ///     //default is an empty String
-    ///     assert_eq!( flag_data_option.get_data().unwrap(), &String::new());
+    ///     assert_eq!( flag_data_option.get_data().unwrap(), "");
     ///     
     ///     //will return a poulated string if Parser found one
     ///     let parser: Parser = Parser::new(&args, &valid_options, &expected_parameters).unwrap();
     ///     let found_flag = parser.get_option_arguments_found().get(0).unwrap();
-    ///     assert_eq!(found_flag.get_data(), Some(&String::from("DEFAULT")) );
+    ///     assert_eq!(found_flag.get_data(), Some("DEFAULT") );
     ///     
     ///     //returns none if ClOption is not of type FlagData 
     ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
@@ -458,9 +1013,498 @@ impl ClOption {
     /// ```
     pub fn get_data(&self) ->  Option<&str> {
         match self {
-            ClOption::Flag { present:_, info:_ } => None,
-            ClOption::FlagList { present:_, list_name:_, list:_, info:_ } => None,
-            ClOption::FlagData { present:_, data_name:_, data, info:_ } => Some(data),
+            ClOption::Flag { .. } => None,
+            ClOption::FlagToggle { .. } => None,
+            ClOption::FlagCount { .. } => None,
+            ClOption::FlagPreset { .. } => None,
+            ClOption::FlagList { .. } => None,
+            ClOption::FlagData { data, .. } => Some(data),
+        }
+    }
+
+    /// gets a reference to `data`, distinguishing an option that wasn't given from one given explicitly empty data (ei. `--format ""`),
+    /// which `ClOption::get_data()` can't, since data is empty by default
+    /// # None
+    /// - returns none if self is not of type ClOption::FlagData, or isn't present
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// use clia::option_parser;
+    /// //...
+    ///     let valid_options = vec![ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in").unwrap(), "FORMAT")];
+    ///     
+    ///     //absent
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/")];
+    ///     let found_flag = option_parser::parse_for_options(&args, &valid_options).unwrap().remove(0);
+    ///     assert_eq!(found_flag.get_data_opt(), None);
+    ///     assert_eq!(found_flag.get_data(), Some(""));
+    ///     
+    ///     //explicitly empty
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("--format"), String::new()];
+    ///     let found_flag = option_parser::parse_for_options(&args, &valid_options).unwrap().remove(0);
+    ///     assert_eq!(found_flag.get_data_opt(), Some(""));
+    /// ```
+    pub fn get_data_opt(&self) -> Option<&str> {
+        match self {
+            ClOption::FlagData { present: true, data, .. } => Some(data),
+            _ => None,
+        }
+    }
+
+    /// gets a reference to the values of a ClOption::FlagData, the data given after each occurrence of its flag that was kept, in the order they were given,
+    /// so every value if it's made with `ClOption::on_repeat(OnRepeat::Accumulate)`, and at most one otherwise
+    /// # None
+    /// - returns none if self is not of type ClOption::FlagData
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption, OnRepeat};
+    /// use clia::option_parser;
+    /// //...
+    ///     let valid_options = vec![ClOption::new_flag_data(&ClOptionInfo::new("-i", "--include", "Directory to include").unwrap(), "DIR").on_repeat(OnRepeat::Accumulate)];
+    ///     let parse = |args: &[&str]| option_parser::parse_for_options(&args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>(), &valid_options).unwrap().remove(0);
+    ///     
+    ///     assert_eq!(parse(&["foo.exe"]).get_values(), Some(&Vec::new()));
+    ///     assert_eq!(parse(&["foo.exe", "--include", "a"]).get_values(), Some(&vec![String::from("a")]));
+    ///     
+    ///     //two and three repetitions, mixing short and long flags
+    ///     let found_flag = parse(&["foo.exe", "--include", "a", "-i", "b"]);
+    ///     assert_eq!(found_flag.get_values(), Some(&vec![String::from("a"), String::from("b")]));
+    ///     assert_eq!(found_flag.get_data(), Some("a"));
+    ///     let found_flag = parse(&["foo.exe", "-i", "a", "path", "--include=b", "-i", "c"]);
+    ///     assert_eq!(found_flag.get_values(), Some(&vec![String::from("a"), String::from("b"), String::from("c")]));
+    ///     
+    ///     //returns none if ClOption is not of type FlagData 
+    ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
+    ///     assert_eq!(flag_option.get_values(), None);
+    /// ```
+    pub fn get_values(&self) -> Option<&Vec<String>> {
+        match self {
+            ClOption::FlagData { values, .. } => Some(values),
+            _ => None,
+        }
+    }
+
+    /// gets what a ClOption::FlagData does when its flag is given more than once, see `ClOption::on_repeat()`
+    /// # None
+    /// - returns none if self is not of type ClOption::FlagData
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption, OnRepeat};
+    /// //...
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE");
+    ///     assert_eq!(flag_data_option.get_on_repeat(), Some(OnRepeat::FirstWins));
+    ///     assert_eq!(flag_data_option.on_repeat(OnRepeat::LastWins).get_on_repeat(), Some(OnRepeat::LastWins));
+    /// ```
+    pub fn get_on_repeat(&self) -> Option<OnRepeat> {
+        match self {
+            ClOption::FlagData { on_repeat, .. } => Some(*on_repeat),
+            _ => None,
+        }
+    }
+
+    /// gets the choice of an option made with `ClOption::new_choice()`, which is the default choice if its flag wasn't given
+    /// 
+    /// # Notes:
+    /// - the choice is never empty, unless the option was made with no choices
+    /// - for other options, this is the same as `ClOption::get_data()`, or empty if self is not of type ClOption::FlagData
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let choice_option = ClOption::new_choice(&ClOptionInfo::new("-m", "--mode", "How to compare files").unwrap(), "MODE", &["fast", "exact"], 0);
+    ///     assert_eq!(choice_option.get_choice(), "fast");
+    ///     //the default is the last choice if the index is too big
+    ///     let choice_option = ClOption::new_choice(&ClOptionInfo::new("-m", "--mode", "How to compare files").unwrap(), "MODE", &["fast", "exact"], 5);
+    ///     assert_eq!(choice_option.get_choice(), "exact");
+    ///     
+    ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
+    ///     assert_eq!(flag_option.get_choice(), "");
+    /// ```
+    pub fn get_choice(&self) -> &str {
+        self.get_data().unwrap_or_default()
+    }
+
+    /// gets a reference to `default_with`, which produces the data of a FlagData when its flag isn't given
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagData, or doesn't have a lazy default
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-j", "--jobs", "How many jobs to run at once").unwrap(), "JOBS");
+    ///     //default is none
+    ///     assert!(flag_data_option.get_default_with().is_none());
+    ///     assert_eq!(flag_data_option.default_with(|| String::from("8")).get_default_with().unwrap().produce(), "8");
+    /// ```
+    pub fn get_default_with(&self) -> Option<&LazyDefault> {
+        match self {
+            ClOption::FlagData { default_with, .. } => default_with.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// gets the value of `reject_empty_value`, which is whether explicitly empty data is an error
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagData
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in").unwrap(), "FORMAT");
+    ///     //default is false
+    ///     assert_eq!(flag_data_option.get_reject_empty_value(), Some(false));
+    ///     assert_eq!(flag_data_option.reject_empty_value(true).get_reject_empty_value(), Some(true));
+    /// ```
+    pub fn get_reject_empty_value(&self) -> Option<bool> {
+        match self {
+            ClOption::FlagData { reject_empty_value, .. } => Some(*reject_empty_value),
+            _ => None,
+        }
+    }
+
+    /// gets the encoding the data of a FlagData is decoded from, see `ClOption::encoding()`
+    /// # None
+    /// - returns none if self is not of type ClOption::FlagData, or its data isn't decoded
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, value_parser::Encoding};
+    /// //...
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-k", "--key", "Key to sign with").unwrap(), "KEY");
+    ///     assert_eq!(flag_data_option.get_encoding(), None);
+    ///     assert_eq!(flag_data_option.encoding(Encoding::Hex).get_encoding(), Some(Encoding::Hex));
+    /// ```
+    pub fn get_encoding(&self) -> Option<Encoding> {
+        match self {
+            ClOption::FlagData { encoding, .. } => *encoding,
+            _ => None,
+        }
+    }
+
+    /// gets the data of a FlagData, decoded from its encoding, see `ClOption::encoding()`
+    /// # None
+    /// - returns none if self is not of type ClOption::FlagData, its data isn't decoded, or it has no data (ei. it wasn't given)
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser, value_parser::Encoding};
+    /// //...
+    ///     let valid_options = vec![ClOption::new_flag_data(&ClOptionInfo::new("-k", "--key", "Key to sign with").unwrap(), "KEY").encoding(Encoding::Hex)];
+    ///     
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-k"), String::from("0x0102")];
+    ///     assert_eq!(option_parser::parse_for_options(&args, &valid_options).unwrap()[0].get_bytes(), Some(vec![1, 2]));
+    ///     
+    ///     let args: Vec<String> = vec![String::from("foo.exe")];
+    ///     assert_eq!(option_parser::parse_for_options(&args, &valid_options).unwrap()[0].get_bytes(), None);
+    /// ```
+    pub fn get_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            ClOption::FlagData { encoding: Some(encoding), data, .. } if !data.is_empty() => encoding.decode(data).ok(),
+            _ => None,
+        }
+    }
+
+    /// gets the data of a FlagData as a duration, ei. `30s` for `--timeout 30s`, see `value_parser::parse_duration()`
+    ///
+    /// a number without a unit is in seconds
+    ///
+    /// # Errors
+    /// - the data isn't a duration, the error describes what was expected, attach `ValueParser::DurationParser` to report it while parsing instead
+    ///
+    /// # None
+    /// - returns none if self is not of type ClOption::FlagData, or it has no data (ei. it wasn't given)
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     let valid_options = vec![ClOption::new_flag_data(&ClOptionInfo::new("-t", "--timeout", "How long to wait").unwrap(), "DURATION")];
+    ///     let timeout = |value: &str| {
+    ///         let args: Vec<String> = vec![String::from("foo.exe"), String::from("--timeout"), String::from(value)];
+    ///         option_parser::parse_for_options(&args, &valid_options).unwrap()[0].get_duration()
+    ///     };
+    ///
+    ///     assert_eq!(timeout("30s"), Some(Ok(Duration::from_secs(30))));
+    ///     assert_eq!(timeout("500ms"), Some(Ok(Duration::from_millis(500))));
+    ///     assert_eq!(timeout("2m"), Some(Ok(Duration::from_secs(120))));
+    ///     assert_eq!(timeout("45"), Some(Ok(Duration::from_secs(45))));
+    ///     assert_eq!(timeout("30x"), Some(Err(String::from("unknown unit 'x' in '30x', expected one of: ms, s, m, h"))));
+    ///
+    ///     let args: Vec<String> = vec![String::from("foo.exe")];
+    ///     assert_eq!(option_parser::parse_for_options(&args, &valid_options).unwrap()[0].get_duration(), None);
+    /// ```
+    pub fn get_duration(&self) -> Option<Result<Duration, String>> {
+        match self {
+            ClOption::FlagData { data, .. } if !data.is_empty() => Some(value_parser::parse_duration(data)),
+            _ => None,
+        }
+    }
+
+    /// gets a reference to `raw_data`, the data exactly as it was typed,
+    /// this differs from `data` when `ignore_case` is set and the data was matched to one of the `allowed_values` with a different case
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagData
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in").unwrap(), "FORMAT")
+    ///         .with_allowed_values(&["DEFAULT", "BULLET", "NUMERIC"])
+    ///         .ignore_case(true);
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("-f"), String::from("numeric")];
+    ///     
+    ///     let found_flag = option_parser::parse_for_options(&args, &[flag_data_option]).unwrap().remove(0);
+    ///     assert_eq!(found_flag.get_data(), Some("NUMERIC"));
+    ///     assert_eq!(found_flag.get_data_raw(), Some("numeric"));
+    /// ```
+    pub fn get_data_raw(&self) ->  Option<&str> {
+        match self {
+            ClOption::FlagData { raw_data, .. } => Some(raw_data),
+            _ => None,
+        }
+    }
+
+    /// gets a reference to `allowed_values`
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagList or ClOption::FlagData
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in").unwrap(), "FORMAT");
+    ///     //default is empty, meaning anything is allowed
+    ///     assert!(flag_data_option.get_allowed_values().unwrap().is_empty());
+    ///     
+    ///     let flag_data_option = flag_data_option.with_allowed_values(&["DEFAULT", "BULLET", "NUMERIC"]);
+    ///     assert_eq!(flag_data_option.get_allowed_values(), Some(&vec![String::from("DEFAULT"), String::from("BULLET"), String::from("NUMERIC")]));
+    ///     
+    ///     //returns none if ClOption doesn't take data
+    ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
+    ///     assert_eq!(flag_option.get_allowed_values(), None);
+    /// ```
+    pub fn get_allowed_values(&self) -> Option<&Vec<String>> {
+        match self {
+            ClOption::FlagList { allowed_values, .. } | ClOption::FlagData { allowed_values, .. } => Some(allowed_values),
+            _ => None,
+        }
+    }
+
+    /// gets the value of `ignore_case`
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagList or ClOption::FlagData
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in").unwrap(), "FORMAT");
+    ///     //default is false
+    ///     assert_eq!(flag_data_option.get_ignore_case(), Some(false));
+    ///     assert_eq!(flag_data_option.ignore_case(true).get_ignore_case(), Some(true));
+    /// ```
+    pub fn get_ignore_case(&self) -> Option<bool> {
+        match self {
+            ClOption::FlagList { ignore_case, .. } | ClOption::FlagData { ignore_case, .. } => Some(*ignore_case),
+            _ => None,
+        }
+    }
+
+    /// gets the value of `key_value_pairs`, which is whether the items of a FlagList are `KEY: VALUE` pairs
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagList
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_list_option = ClOption::new_flag_list(&ClOptionInfo::new("-H", "--headers", "Headers to send").unwrap(), "HEADERS");
+    ///     //default is false
+    ///     assert_eq!(flag_list_option.get_key_value_pairs(), Some(false));
+    ///     assert_eq!(flag_list_option.key_value_pairs(true).get_key_value_pairs(), Some(true));
+    /// ```
+    pub fn get_key_value_pairs(&self) -> Option<bool> {
+        match self {
+            ClOption::FlagList { key_value_pairs, .. } => Some(*key_value_pairs),
+            _ => None,
+        }
+    }
+
+    /// gets the value of `bracketed_lists`, which is whether the list of a FlagList can be given in brackets, see `ClOption::bracketed_lists()`
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagList
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_list_option = ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to count").unwrap(), "EXTENSIONS");
+    ///     //default is false
+    ///     assert_eq!(flag_list_option.get_bracketed_lists(), Some(false));
+    ///     assert_eq!(flag_list_option.bracketed_lists(true).get_bracketed_lists(), Some(true));
+    /// ```
+    pub fn get_bracketed_lists(&self) -> Option<bool> {
+        match self {
+            ClOption::FlagList { bracketed_lists, .. } => Some(*bracketed_lists),
+            _ => None,
+        }
+    }
+
+    /// returns the items of a FlagList whose items are `KEY: VALUE` pairs (see `ClOption::key_value_pairs()`), split at their first `:` into ordered (key, value) pairs,
+    /// with the whitespace around keys and values trimmed
+    /// 
+    /// returns nothing if self isn't a FlagList of pairs, or isn't present
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     let valid_options = vec![ClOption::new_flag_list(&ClOptionInfo::new("-H", "--headers", "Headers to send").unwrap(), "HEADERS").key_value_pairs(true)];
+    ///     
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--headers"), String::from("Accept: json, Content-Type: text, Referer:http://example.com")];
+    ///     let headers = option_parser::parse_for_options(&args, &valid_options).unwrap().remove(0);
+    ///     assert_eq!(
+    ///         headers.get_header_pairs(),
+    ///         vec![
+    ///             (String::from("Accept"), String::from("json")),
+    ///             (String::from("Content-Type"), String::from("text")),
+    ///             (String::from("Referer"), String::from("http://example.com")), //split at the first `:` only
+    ///         ]
+    ///     );
+    ///     
+    ///     //items that aren't pairs are an error
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--headers"), String::from("Accept: json, gzip")];
+    ///     assert_eq!(
+    ///         option_parser::parse_for_options(&args, &valid_options).unwrap_err().to_string(),
+    ///         "User Error: invalid item 'gzip' for flag(--headers), expected 'KEY: VALUE'"
+    ///     );
+    /// ```
+    pub fn get_header_pairs(&self) -> Vec<(String, String)> {
+        match self {
+            ClOption::FlagList { list, key_value_pairs: true, .. } => list.iter()
+                .filter_map(|item| item.split_once(':'))
+                .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// gets a reference to `value_parser`, which checks that the data of a FlagData is well-formed
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagData, or doesn't have a value parser
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, value_parser::ValueParser};
+    /// //...
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-n", "--count", "How many results to show").unwrap(), "COUNT");
+    ///     //default is none
+    ///     assert_eq!(flag_data_option.get_value_parser(), None);
+    ///     assert_eq!(flag_data_option.with_value_parser(ValueParser::IntParser).get_value_parser(), Some(&ValueParser::IntParser));
+    /// ```
+    pub fn get_value_parser(&self) -> Option<&ValueParser> {
+        match self {
+            ClOption::FlagData { value_parser, .. } => value_parser.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// gets a reference to `preset`, the args a ClOption::FlagPreset stands for
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagPreset
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_preset_option = ClOption::new_flag_preset(&ClOptionInfo::new("", "--fast", "Go fast").unwrap(), &["--threads", "8", "--cache"]);
+    ///     assert_eq!(flag_preset_option.get_preset(), Some(&vec![String::from("--threads"), String::from("8"), String::from("--cache")]));
+    ///     
+    ///     //returns none if ClOption is not of type FlagPreset
+    ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
+    ///     assert_eq!(flag_option.get_preset(), None);
+    /// ```
+    pub fn get_preset(&self) -> Option<&Vec<String>> {
+        match self {
+            ClOption::FlagPreset { preset, .. } => Some(preset),
+            _ => None,
+        }
+    }
+
+    /// gets the state of the toggle, `true` if the flag was given an odd number of times, `false` otherwise
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagToggle
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     let flag_toggle_option = ClOption::new_flag_toggle(&ClOptionInfo::new("-x", "--toggle", "Toggle something, can be given multiple times").unwrap());
+    ///     let valid_options = vec![flag_toggle_option.clone()];
+    ///     //default is false
+    ///     assert_eq!(flag_toggle_option.get_toggle(), Some(false));
+    ///     
+    ///     //every occurrence of the flag flips the toggle
+    ///     for (occurrences, expected) in [(0, false), (1, true), (2, false), (3, true)] {
+    ///         let mut args: Vec<String> = vec![String::from("path/to/executable/")];
+    ///         args.extend((0..occurrences).map(|i| String::from(if i % 2 == 0 {"-x"} else {"--toggle"})));
+    ///         
+    ///         let found_flag = option_parser::parse_for_options(&args, &valid_options).unwrap().remove(0);
+    ///         assert_eq!(found_flag.get_toggle(), Some(expected));
+    ///         assert_eq!(found_flag.get_present(), occurrences > 0);
+    ///     }
+    ///     
+    ///     //returns none if ClOption is not of type FlagToggle
+    ///     let flag_option = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories recursively").unwrap());
+    ///     assert_eq!(flag_option.get_toggle(), None);
+    /// ```
+    pub fn get_toggle(&self) -> Option<bool> {
+        match self {
+            ClOption::FlagToggle { toggle, .. } => Some(*toggle),
+            _ => None,
+        }
+    }
+
+    /// gets how many times the flag was given, counting both flags and every letter of clusters of short flags, ei. 3 for `-vv --verbose` or `-vvv`
+    /// # None
+    /// - returns none is self is not of type ClOption::FlagCount
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+    /// //...
+    ///     let valid_options = vec![
+    ///         ClOption::new_flag_count(&ClOptionInfo::new("-v", "--verbose", "Print more").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///     ];
+    ///     let count = |args: &[&str]| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         let found = Parser::new(&args, &valid_options, &[]).unwrap().get_option_arguments_found()[0].clone();
+    ///         assert_eq!(found.get_present(), found.get_count() > Some(0));
+    ///         found.get_count()
+    ///     };
+    ///     
+    ///     assert_eq!(count(&["foo.exe"]), Some(0));
+    ///     assert_eq!(count(&["foo.exe", "-v"]), Some(1));
+    ///     assert_eq!(count(&["foo.exe", "-vv"]), Some(2));
+    ///     assert_eq!(count(&["foo.exe", "-v", "-v", "-v"]), Some(3));
+    ///     assert_eq!(count(&["foo.exe", "-vrv", "--verbose"]), Some(3));
+    ///     
+    ///     //returns none if ClOption is not of type FlagCount
+    ///     assert_eq!(valid_options[1].get_count(), None);
+    /// ```
+    pub fn get_count(&self) -> Option<u32> {
+        match self {
+            ClOption::FlagCount { count, .. } => Some(*count),
+            _ => None,
         }
     }
 
@@ -482,9 +1526,74 @@ impl ClOption {
     ///     let example_option: ClOption = ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap() ); 
     /// ```
     pub fn new_flag(info: &ClOptionInfo) -> ClOption {
-        return ClOption::Flag { present: false, info: info.clone()};
+        ClOption::Flag { present: false, info: info.clone()}
+    }
+    /// Creates and returns new ClOption::FlagToggle with the given info
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let example_option: ClOption = ClOption::new_flag_toggle(&ClOptionInfo::new("-x", "--toggle", "Toggle something, can be given multiple times").unwrap() ); 
+    /// ```
+    pub fn new_flag_toggle(info: &ClOptionInfo) -> ClOption {
+        ClOption::FlagToggle { present: false, toggle: false, info: info.clone()}
+    }
+    /// Creates and returns new ClOption::FlagCount with the given info
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let example_option: ClOption = ClOption::new_flag_count(&ClOptionInfo::new("-v", "--verbose", "Print more").unwrap());
+    ///     assert_eq!(example_option.gen_help_line(), "    -v, --verbose                     Print more [can be repeated]");
+    /// ```
+    pub fn new_flag_count(info: &ClOptionInfo) -> ClOption {
+        ClOption::FlagCount { count: 0, info: info.clone()}
+    }
+    /// Creates and returns new ClOption::FlagPreset with the given info, that stands for the args in `preset`
+    /// 
+    /// when the flag is given, every option set by `preset` that the user didn't set themselves is set as if `preset` had been typed,
+    /// so options given explicitly always win over presets
+    /// 
+    /// # Notes:
+    /// - every flag in `preset` must be defined by the valid options it's parsed with
+    /// - presets inside of `preset` are not expanded
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_preset(&ClOptionInfo::new("", "--fast", "Go fast").unwrap(), &["--threads", "8", "--cache", "--no-verify"]),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-t", "--threads", "How many threads to use").unwrap(), "THREADS"),
+    ///         ClOption::new_flag(&ClOptionInfo::new("", "--cache", "Cache results").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("", "--no-verify", "Skip verification").unwrap()),
+    ///     ];
+    ///     
+    ///     //the preset sets values
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("--fast")];
+    ///     let parsed_options = option_parser::parse_for_options(&args, &valid_options).unwrap();
+    ///     assert!(parsed_options.iter().all(|option| option.get_present()));
+    ///     assert_eq!(parsed_options[1].get_data(), Some("8"));
+    ///     
+    ///     //but options the user set explicitly win
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("--fast"), String::from("--threads"), String::from("2")];
+    ///     let parsed_options = option_parser::parse_for_options(&args, &valid_options).unwrap();
+    ///     assert!(parsed_options.iter().all(|option| option.get_present()));
+    ///     assert_eq!(parsed_options[1].get_data(), Some("2"));
+    ///     
+    ///     //and nothing is set without the preset
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/")];
+    ///     let parsed_options = option_parser::parse_for_options(&args, &valid_options).unwrap();
+    ///     assert!(parsed_options.iter().all(|option| !option.get_present()));
+    /// ```
+    pub fn new_flag_preset(info: &ClOptionInfo, preset: &[&str]) -> ClOption {
+        ClOption::FlagPreset { present: false, preset: preset.iter().map(|arg| arg.to_string()).collect(), info: info.clone()}
     }
     /// Creates and returns new ClOption::FlagList with the given info
+    /// 
+    /// # Notes:
+    /// - empty items are dropped, so `--filter "rs,,md,"` is `rs` and `md`, and `--filter ""` is an empty list
+    /// 
     /// # Examples
     /// ```
     /// use clia::option_args::{ClOptionInfo, ClOption};
@@ -492,7 +1601,7 @@ impl ClOption {
     ///     let example_option: ClOption = ClOption::new_flag_list( &ClOptionInfo::new("-f", "--filter", "Comma separated list of extensions, will only count lines of files with these extensions").unwrap(), "EXTENSIONS"); 
     /// ```
     pub fn new_flag_list(info: &ClOptionInfo, list_name: &str) -> ClOption {
-        return ClOption::FlagList { present: false, list_name: list_name.to_ascii_uppercase(), list: Vec::new(), info: info.clone()};
+        ClOption::FlagList { present: false, list_name: list_name.to_ascii_uppercase(), list: Vec::new(), allowed_values: Vec::new(), ignore_case: false, key_value_pairs: false, bracketed_lists: false, info: info.clone()}
     }
     /// Creates and returns new ClOption::FlagData with the given info
     /// # Examples
@@ -502,6 +1611,452 @@ impl ClOption {
     ///     let example_option: ClOption = ClOption::new_flag_list( &ClOptionInfo::new("-F", "--format", "Format the output in a list, valid formats are: DEFAULT, BULLET, MARKDOWN, and NUMERIC").unwrap(), "FORMAT"); 
     /// ```
     pub fn new_flag_data(info: &ClOptionInfo, data_name: &str) -> ClOption {
-        return ClOption::FlagData { present: false, data_name: data_name.to_ascii_uppercase(), data: String::new(), info: info.clone()};
+        ClOption::FlagData { present: false, data_name: data_name.to_ascii_uppercase(), data: String::new(), raw_data: String::new(), allowed_values: Vec::new(), ignore_case: false, value_parser: None, encoding: None, reject_empty_value: false, default_with: None, on_repeat: OnRepeat::FirstWins, values: Vec::new(), info: info.clone()}
+    }
+    /// Creates and returns new ClOption::FlagData with the given info, whose allowed values are the variants of `E`
+    /// 
+    /// use `Parser::option_enum()` to get the data as an `E`
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+    /// //...
+    ///     clia::value_enum! {
+    ///         Format { Default, Bullet, Markdown, Numeric }
+    ///     }
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data_enum::<Format>(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT")];
+    ///     
+    ///     //valid values
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--format"), String::from("Numeric")];
+    ///     let parser = Parser::new(&args, &valid_options, &[]).unwrap();
+    ///     assert_eq!(parser.option_enum::<Format>("--format").unwrap(), Some(Format::Numeric));
+    ///     
+    ///     //invalid values
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--format"), String::from("Fancy")];
+    ///     assert_eq!(
+    ///         Parser::new(&args, &valid_options, &[]).err().unwrap().to_string(),
+    ///         "User Error: invalid value 'Fancy' for flag(--format), possible values are: Default, Bullet, Markdown, Numeric"
+    ///     );
+    ///     
+    ///     //the help shows the variants
+    ///     assert!(valid_options[0].gen_help_line().ends_with("[possible values: Default, Bullet, Markdown, Numeric]"));
+    /// ```
+    pub fn new_flag_data_enum<E: ValueEnum>(info: &ClOptionInfo, data_name: &str) -> ClOption {
+        ClOption::new_flag_data(info, data_name).with_allowed_values(E::variants())
+    }
+
+    /// Creates and returns new ClOption::FlagData with the given info, whose data must be one of `choices`, the same as `ClOption::with_allowed_values()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_data_choices(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT", &["DEFAULT", "BULLET", "MARKDOWN", "NUMERIC"]),
+    ///     ];
+    ///     let parse = |args: &[&str]| option_parser::parse_for_options(&args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>(), &valid_options);
+    ///
+    ///     //an accepted value
+    ///     assert_eq!(parse(&["foo.exe", "--format", "MARKDOWN"]).unwrap()[0].get_data(), Some("MARKDOWN"));
+    ///
+    ///     //a rejected value, the error lists the choices
+    ///     assert_eq!(
+    ///         parse(&["foo.exe", "--format", "HTML"]).unwrap_err().to_string(),
+    ///         "User Error: invalid value 'HTML' for flag(--format), possible values are: DEFAULT, BULLET, MARKDOWN, NUMERIC"
+    ///     );
+    ///
+    ///     //and so does the help line
+    ///     assert!(valid_options[0].gen_help_line().ends_with("Format the output [possible values: DEFAULT, BULLET, MARKDOWN, NUMERIC]"));
+    /// ```
+    pub fn new_flag_data_choices(info: &ClOptionInfo, data_name: &str, choices: &[&str]) -> ClOption {
+        ClOption::new_flag_data(info, data_name).with_allowed_values(choices)
+    }
+
+    /// Creates and returns new ClOption::FlagData with the given info, whose data must be one of `choices`, and is `choices[default_index]` if the flag isn't given
+    /// 
+    /// use `ClOption::get_choice()` to get the choice
+    /// 
+    /// # Notes:
+    /// - if `default_index` is past the end of `choices`, the default is the last choice
+    /// - explicitly empty data (ei. `--mode ""`) is an error, see `ClOption::reject_empty_value()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_choice(&ClOptionInfo::new("-m", "--mode", "How to compare files").unwrap(), "MODE", &["fast", "exact", "fuzzy"], 1)];
+    ///     let parse = |args: &[&str]| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         option_parser::parse_for_options(&args, &valid_options).map(|mut found| found.remove(0))
+    ///     };
+    ///     
+    ///     //absent, so the default
+    ///     let found_flag = parse(&["foo.exe"]).unwrap();
+    ///     assert_eq!(found_flag.get_choice(), "exact");
+    ///     assert!(!found_flag.get_present());
+    ///     
+    ///     //one of the choices
+    ///     assert_eq!(parse(&["foo.exe", "--mode", "fuzzy"]).unwrap().get_choice(), "fuzzy");
+    ///     
+    ///     //anything else
+    ///     assert_eq!(
+    ///         parse(&["foo.exe", "-m", "slow"]).unwrap_err().to_string(),
+    ///         "User Error: invalid value 'slow' for flag(-m), possible values are: fast, exact, fuzzy"
+    ///     );
+    ///     assert!(parse(&["foo.exe", "-m", ""]).is_err());
+    /// ```
+    pub fn new_choice(info: &ClOptionInfo, data_name: &str, choices: &[&str], default_index: usize) -> ClOption {
+        let mut option: ClOption = ClOption::new_flag_data(info, data_name).with_allowed_values(choices).reject_empty_value(true);
+        if let (ClOption::FlagData { data, raw_data, .. }, Some(default)) = (&mut option, choices.get(default_index).or(choices.last())) {
+            *data = default.to_string();
+            *raw_data = default.to_string();
+        }
+        option
+    }
+
+    //builder methods
+    /// restrict the data of a ClOption::FlagData, or the items in the list of a ClOption::FlagList, to `values`,
+    /// the allowed values are also listed in help messages
+    /// 
+    /// has no effect on options that don't take data
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in").unwrap(), "FORMAT")
+    ///         .with_allowed_values(&["DEFAULT", "BULLET", "NUMERIC"]);
+    ///     let valid_options = vec![flag_data_option];
+    ///     
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("-f"), String::from("BULLET")];
+    ///     assert_eq!(option_parser::parse_for_options(&args, &valid_options).unwrap()[0].get_data(), Some("BULLET"));
+    ///     
+    ///     //data that isn't allowed is an error
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("-f"), String::from("MARKDOWN")];
+    ///     assert_eq!(
+    ///         option_parser::parse_for_options(&args, &valid_options).unwrap_err().to_string(),
+    ///         "User Error: invalid value 'MARKDOWN' for flag(-f), possible values are: DEFAULT, BULLET, NUMERIC"
+    ///     );
+    ///     
+    ///     //and the closest allowed value is suggested, if there's one close enough
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("-f"), String::from("bulet")];
+    ///     assert_eq!(
+    ///         option_parser::parse_for_options(&args, &valid_options).unwrap_err().to_string(),
+    ///         "User Error: invalid value 'bulet' for flag(-f), did you mean 'BULLET'? possible values are: DEFAULT, BULLET, NUMERIC"
+    ///     );
+    /// ```
+    pub fn with_allowed_values(mut self, values: &[&str]) -> ClOption {
+        if let ClOption::FlagList { allowed_values, .. } | ClOption::FlagData { allowed_values, .. } = &mut self {
+            *allowed_values = values.iter().map(|value| value.to_string()).collect();
+        }
+        self
+    }
+
+    /// if `ignore` is true, data (or list items) are matched against the allowed values ignoring case,
+    /// and stored with the casing of the allowed value they matched, so you can `match` on them
+    /// 
+    /// has no effect on options that don't take data
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     let flag_data_option = ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in").unwrap(), "FORMAT")
+    ///         .with_allowed_values(&["DEFAULT", "BULLET", "NUMERIC"])
+    ///         .ignore_case(true);
+    ///     let flag_list_option = ClOption::new_flag_list(&ClOptionInfo::new("-l", "--languages", "Languages to count").unwrap(), "LANGUAGES")
+    ///         .with_allowed_values(&["Rust", "Python", "C"])
+    ///         .ignore_case(true);
+    ///     let valid_options = vec![flag_data_option, flag_list_option];
+    ///     
+    ///     for typed in ["numeric", "NUMERIC", "NuMeRiC"] {
+    ///         let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("-f"), String::from(typed)];
+    ///         let found_flag = option_parser::parse_for_options(&args, &valid_options).unwrap().remove(0);
+    ///         assert_eq!(found_flag.get_data(), Some("NUMERIC"));
+    ///         assert_eq!(found_flag.get_data_raw(), Some(typed));
+    ///     }
+    ///     
+    ///     //list items are stored with the casing of the allowed values they match too
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("-l"), String::from("rust,PYTHON,c")];
+    ///     let found_flag = option_parser::parse_for_options(&args, &valid_options).unwrap().remove(1);
+    ///     assert_eq!(found_flag.get_list(), Some(&vec![String::from("Rust"), String::from("Python"), String::from("C")]));
+    ///     
+    ///     //the help message shows the canonical forms
+    ///     assert!(valid_options[1].gen_help_line().ends_with("[possible values: Rust, Python, C]"));
+    /// ```
+    pub fn ignore_case(mut self, ignore: bool) -> ClOption {
+        if let ClOption::FlagList { ignore_case, .. } | ClOption::FlagData { ignore_case, .. } = &mut self {
+            *ignore_case = ignore;
+        }
+        self
+    }
+
+    /// if `pairs` is true, every item of a FlagList must be a `KEY: VALUE` pair, items without a `:` are an error, get the pairs with `ClOption::get_header_pairs()`
+    /// 
+    /// has no effect on options that aren't of type ClOption::FlagList
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::option_args::{ClOptionInfo, ClOption};
+    /// //...
+    ///     let flag_list_option = ClOption::new_flag_list(&ClOptionInfo::new("-H", "--headers", "Headers to send").unwrap(), "HEADERS").key_value_pairs(true);
+    ///     assert_eq!(flag_list_option.get_key_value_pairs(), Some(true));
+    /// ```
+    pub fn key_value_pairs(mut self, pairs: bool) -> ClOption {
+        if let ClOption::FlagList { key_value_pairs, .. } = &mut self {
+            *key_value_pairs = pairs;
+        }
+        self
+    }
+
+    /// if `bracketed` is true, the list of a FlagList can also be given in brackets, space separated, ei. `-f [a b c]`, as one arg or spread across args (`-f`, `[a`, `b`, `c]`),
+    /// lists that don't start with `[` are still comma separated
+    /// 
+    /// has no effect on options that aren't of type ClOption::FlagList
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options = vec![ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to count").unwrap(), "EXTENSIONS").bracketed_lists(true)];
+    ///     let expected_parameters = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let parse = |args: &[&str]| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         Parser::new(&args, &valid_options, &expected_parameters)
+    ///     };
+    ///     
+    ///     //as one arg
+    ///     let parser = parse(&["foo.exe", "-f", "[rs py  c]", "path"]).unwrap();
+    ///     assert_eq!(parser.get_many("filter"), Some(&[String::from("rs"), String::from("py"), String::from("c")][..]));
+    ///     assert_eq!(parser.get_one("PATH"), Some("path"));
+    ///     
+    ///     //spread across args, which aren't parameters
+    ///     let parser = parse(&["foo.exe", "-f", "[rs", "py", "c]", "path"]).unwrap();
+    ///     assert_eq!(parser.get_many("filter"), Some(&[String::from("rs"), String::from("py"), String::from("c")][..]));
+    ///     assert_eq!(parser.get_one("PATH"), Some("path"));
+    ///     
+    ///     //comma separated lists still work
+    ///     assert_eq!(parse(&["foo.exe", "-f", "rs,py", "path"]).unwrap().get_many("filter"), Some(&[String::from("rs"), String::from("py")][..]));
+    ///     
+    ///     //but brackets have to be closed
+    ///     assert!(parse(&["foo.exe", "-f", "[rs", "py", "path"]).is_err());
+    /// ```
+    pub fn bracketed_lists(mut self, bracketed: bool) -> ClOption {
+        if let ClOption::FlagList { bracketed_lists, .. } = &mut self {
+            *bracketed_lists = bracketed;
+        }
+        self
+    }
+
+    /// checks the data of a FlagData with `parser` while parsing, data that isn't well-formed is an error
+    /// 
+    /// has no effect on options that aren't of type ClOption::FlagData
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser, value_parser::ValueParser};
+    /// //...
+    ///     let valid_options = vec![
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-n", "--count", "How many results to show").unwrap(), "COUNT").with_value_parser(ValueParser::IntParser),
+    ///     ];
+    ///     
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("-n"), String::from("10")];
+    ///     assert_eq!(option_parser::parse_for_options(&args, &valid_options).unwrap()[0].get_data(), Some("10"));
+    ///     
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("-n"), String::from("ten")];
+    ///     assert_eq!(
+    ///         option_parser::parse_for_options(&args, &valid_options).unwrap_err().to_string(),
+    ///         "User Error: invalid value 'ten' for flag(-n): expected an integer"
+    ///     );
+    /// ```
+    pub fn with_value_parser(mut self, parser: ValueParser) -> ClOption {
+        if let ClOption::FlagData { value_parser, .. } = &mut self {
+            *value_parser = Some(parser);
+        }
+        self
+    }
+
+    /// decodes the data of a FlagData from `encoding` while parsing, so data that isn't encoded properly is an error naming the flag,
+    /// the decoded data can be accessed with `ClOption::get_bytes()`, and the data itself is unchanged
+    /// 
+    /// has no effect on options that aren't of type ClOption::FlagData
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser, value_parser::Encoding};
+    /// //...
+    ///     let valid_options = vec![
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-k", "--key", "Key to sign with, in hex").unwrap(), "KEY").encoding(Encoding::Hex),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-p", "--payload", "Payload to sign, in base64").unwrap(), "PAYLOAD").encoding(Encoding::Base64),
+    ///     ];
+    ///     let parse = |args: &[&str]| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         option_parser::parse_for_options(&args, &valid_options)
+    ///     };
+    ///     
+    ///     let found = parse(&["foo.exe", "--key", "DEADbeef", "--payload", "aGVsbG8="]).unwrap();
+    ///     assert_eq!(found[0].get_bytes(), Some(vec![0xde, 0xad, 0xbe, 0xef]));
+    ///     assert_eq!(found[0].get_data(), Some("DEADbeef"));
+    ///     assert_eq!(found[1].get_bytes(), Some(b"hello".to_vec()));
+    ///     
+    ///     //malformed input
+    ///     assert_eq!(
+    ///         parse(&["foo.exe", "--key", "DEADbee"]).unwrap_err().to_string(),
+    ///         "User Error: invalid value 'DEADbee' for flag(--key): expected hex, ei. 0a1b"
+    ///     );
+    ///     assert_eq!(
+    ///         parse(&["foo.exe", "-p", "aGVs#G8="]).unwrap_err().to_string(),
+    ///         "User Error: invalid value 'aGVs#G8=' for flag(-p): expected base64, ei. aGVsbG8="
+    ///     );
+    /// ```
+    pub fn encoding(mut self, encoding: Encoding) -> ClOption {
+        if let ClOption::FlagData { encoding: option_encoding, .. } = &mut self {
+            *option_encoding = Some(encoding);
+        }
+        self
+    }
+
+    /// sets a closure that produces the data of a FlagData when its flag isn't given, ei. a default that is expensive to compute or depends on the environment,
+    /// it only runs when it's needed, after presets are applied
+    /// 
+    /// the option is still not present, so `ClOption::get_data()` returns the default but `ClOption::get_data_opt()` doesn't
+    /// 
+    /// has no effect on options that aren't of type ClOption::FlagData
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     static CALLS: AtomicUsize = AtomicUsize::new(0);
+    ///     let valid_options = vec![
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-j", "--jobs", "How many jobs to run at once").unwrap(), "JOBS")
+    ///             .default_with(|| {CALLS.fetch_add(1, Ordering::SeqCst); String::from("8")}),
+    ///     ];
+    ///     
+    ///     //the closure doesn't run when the flag is given
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("--jobs"), String::from("2")];
+    ///     let found_flag = option_parser::parse_for_options(&args, &valid_options).unwrap().remove(0);
+    ///     assert_eq!(found_flag.get_data(), Some("2"));
+    ///     assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    ///     
+    ///     //but does when it isn't
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/")];
+    ///     let found_flag = option_parser::parse_for_options(&args, &valid_options).unwrap().remove(0);
+    ///     assert_eq!(found_flag.get_data(), Some("8"));
+    ///     assert_eq!(found_flag.get_data_opt(), None);
+    ///     assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    /// ```
+    pub fn default_with(mut self, default: impl Fn() -> String + Send + Sync + 'static) -> ClOption {
+        if let ClOption::FlagData { default_with, .. } = &mut self {
+            *default_with = Some(LazyDefault::new(default));
+        }
+        self
+    }
+
+    /// if `reject` is true, explicitly empty data (ei. `--format ""`) is an error, instead of being stored as empty data
+    /// 
+    /// has no effect on options that aren't of type ClOption::FlagData, the empty items of a FlagList are always dropped
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, option_parser};
+    /// //...
+    ///     let valid_options = vec![ClOption::new_flag_data(&ClOptionInfo::new("-f", "--format", "Format to print output in").unwrap(), "FORMAT").reject_empty_value(true)];
+    ///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("--format"), String::new()];
+    ///     
+    ///     assert_eq!(option_parser::parse_for_options(&args, &valid_options).unwrap_err().to_string(), "User Error: flag(--format) was given an empty value");
+    /// ```
+    pub fn reject_empty_value(mut self, reject: bool) -> ClOption {
+        if let ClOption::FlagData { reject_empty_value, .. } = &mut self {
+            *reject_empty_value = reject;
+        }
+        self
+    }
+
+    /// sets what a ClOption::FlagData does when its flag is given more than once, by default the first value wins, see `OnRepeat`
+    /// 
+    /// has no effect on options that aren't of type ClOption::FlagData
+    /// 
+    /// # Notes:
+    /// - options that don't keep the first value are meant to be repeated, so they're never listed by `Parser::duplicated_flags()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption, OnRepeat}, option_parser, parse_error::{ParseErrorKind, ParseErrors}};
+    /// //...
+    ///     let info = ClOptionInfo::new("-o", "--output", "File to write to").unwrap();
+    ///     let args: Vec<String> = ["foo.exe", "--output", "a.txt", "-o", "b.txt", "--output", "c.txt"].iter().map(|arg| arg.to_string()).collect();
+    ///     let parse = |on_repeat: OnRepeat| option_parser::parse_for_options(&args, &[ClOption::new_flag_data(&info, "FILE").on_repeat(on_repeat)]);
+    ///     
+    ///     assert_eq!(parse(OnRepeat::FirstWins).unwrap()[0].get_data(), Some("a.txt"));
+    ///     assert_eq!(parse(OnRepeat::LastWins).unwrap()[0].get_data(), Some("c.txt"));
+    ///     assert_eq!(parse(OnRepeat::LastWins).unwrap()[0].get_values(), Some(&vec![String::from("c.txt")]));
+    ///     assert_eq!(parse(OnRepeat::Accumulate).unwrap()[0].get_values(), Some(&vec![String::from("a.txt"), String::from("b.txt"), String::from("c.txt")]));
+    ///     
+    ///     let error = parse(OnRepeat::Error).unwrap_err();
+    ///     assert_eq!(error.to_string(), "User Error: flag(--output) was given 3 times, but can only be given once");
+    ///     assert_eq!(error.downcast_ref::<ParseErrors>().unwrap().get_errors()[0].get_kind(), ParseErrorKind::RepeatedFlag);
+    ///     
+    ///     //only the values that are kept are checked
+    ///     let args: Vec<String> = ["foo.exe", "-o", "", "-o", "b.txt"].iter().map(|arg| arg.to_string()).collect();
+    ///     let valid_options = vec![ClOption::new_flag_data(&info, "FILE").reject_empty_value(true).on_repeat(OnRepeat::LastWins)];
+    ///     assert_eq!(option_parser::parse_for_options(&args, &valid_options).unwrap()[0].get_data(), Some("b.txt"));
+    /// ```
+    pub fn on_repeat(mut self, repeat: OnRepeat) -> ClOption {
+        if let ClOption::FlagData { on_repeat, .. } = &mut self {
+            *on_repeat = repeat;
+        }
+        self
+    }
+
+    /// stores `value` under `key` in the metadata of this option, ei. which plugin owns it, replacing any value already stored under `key`
+    /// 
+    /// metadata is only stored, nothing in this crate reads it, so it's a place for your own information about an option,
+    /// and it stays with the option through parsing
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+    /// //...
+    ///     let valid_options = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()).with_meta("plugin", "search").with_meta("category", "filtering"),
+    ///     ];
+    ///     assert_eq!(valid_options[0].get_meta("plugin"), Some("search"));
+    ///     assert_eq!(valid_options[0].get_meta("category"), Some("filtering"));
+    ///     assert_eq!(valid_options[0].get_meta("owner"), None);
+    ///     
+    ///     //replacing a value
+    ///     assert_eq!(valid_options[0].clone().with_meta("plugin", "walk").get_meta("plugin"), Some("walk"));
+    ///     
+    ///     //the found options keep their metadata
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-r")];
+    ///     let parser = Parser::new(&args, &valid_options, &[]).unwrap();
+    ///     assert_eq!(parser.get_option_arguments_found()[0].get_meta("plugin"), Some("search"));
+    /// ```
+    pub fn with_meta(mut self, key: &str, value: &str) -> ClOption {
+        match &mut self {
+            Self::Flag { info, .. } | Self::FlagToggle { info, .. } | Self::FlagCount { info, .. } | Self::FlagPreset { info, .. } | Self::FlagList { info, .. } | Self::FlagData { info, .. } => {
+                *info = std::mem::take(info).with_meta(key, value);
+            },
+        }
+        self
+    }
+}
+/// compares the info of the option with `other`
+/// 
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOptionInfo, ClOption}, Parser};
+/// //...
+///     let info = ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap();
+///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-r")];
+///     let parser = Parser::new(&args, &[ClOption::new_flag(&info)], &[]).unwrap();
+///     
+///     assert!(parser.get_option_arguments_found()[0] == info);
+///     assert!(parser.get_option_arguments_found()[0] != ClOptionInfo::new("-r", "--recursive", "").unwrap());
+/// ```
+impl PartialEq<ClOptionInfo> for ClOption {
+    fn eq(&self, other: &ClOptionInfo) -> bool {
+        self.get_info().eq(other)
     }
 }
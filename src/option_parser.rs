@@ -5,17 +5,39 @@
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_doc_code_examples)]
 
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::error::Error;
 
+use crate::classifier::{Classifier, TokenClass};
+use crate::expansion::{Expander, Indirection};
 use crate::option_args;
+use crate::parameter_parser;
+use crate::parse_error::{ParseError, ParseErrorKind, ParseErrors};
+use crate::parse_report::{ParseReport, ReportEntry};
+use crate::parser_settings::ParserSettings;
+use crate::suggest;
 
 /// parse args for Options 
 /// valid flags are given by valid_options
 /// returns a vector containing all of the ClOptions in valid_options, with their associated data updated
 /// 
+/// # Notes:
+/// - the returned vector is always in the same order as `valid_options`, regardless of the order of `args`
+/// - empty `args` contain no flags, so every option is returned as not present
+/// - empty `valid_options` make any flag in `args` an unknown flag
+/// - short flags can be clustered, ei. `-rf` is `-r -f`, if every letter is a short flag, only the last can take a list/data
+/// - long flags can be given their list/data after an `=`, ei. `--format=NUMERIC`
+/// - the first arg is the program name, so it's never a flag, even if it starts with a `-`
+/// 
 /// # Errors
-/// - `args` contains a flag (string starting with `-`) not in `valid_options` 
+/// - `args` contains flags (strings starting with `-`) not in `valid_options`, the error lists every one of them (and their index in `args`) in the order they were given
+/// - `args` contains a flag of `valid_options` with the wrong number of dashes (ei. `-recursive`), these get their own error suggesting the right flag
+/// - `args` contains a flag typed with a dash that isn't ASCII (ei. `–recursive`), these get their own error too, see `ParserSettings::normalize_unicode_dashes()`
 /// - the `args` passed would result in an error from `option_parser::get_list_after_flag()` or `option_parser::get_data_after_flag()`
+/// - a list item or data isn't one of the allowed values of its option
+/// - a flag that takes a list/data isn't the last flag of a cluster
+/// 
+/// every error found is reported, as a `parse_error::ParseErrors`
 /// 
 /// # Examples
 /// ```
@@ -31,94 +53,1121 @@ use crate::option_args;
 ///     //call option_parser::parse_for_options() to get a vector that's a copy of valid_options but with it's data updated
 ///     let parsed_options: Vec<ClOption> = option_parser::parse_for_options(&args, &valid_options).unwrap();
 /// ```
+/// every invalid flag is reported
+/// ```
+/// # use clia::{option_args::{ClOption, ClOptionInfo},option_parser};
+///     //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output in a list").unwrap(), "FORMAT"),
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///     ];
+///     let args: Vec<String> = ["path/to/executable/", "-r", "--formt", "NUMERIC", "path", "-z"].iter().map(|arg| arg.to_string()).collect();
+///     
+///     assert_eq!(
+///         option_parser::parse_for_options(&args, &valid_options).unwrap_err().to_string(),
+///         "User Error: unknown options: '--formt' (arg 2), '-z' (arg 5)"
+///     );
+/// ```
+/// 
+/// 
+/// clustered short flags
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag(&ClOptionInfo::new("-i", "--ignore-case", "Ignore case when searching").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+///     ];
+///     let parse = |args: &[&str]| option_parser::parse_for_options(&args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>(), &valid_options);
+///     
+///     let found = parse(&["foo.exe", "-ri"]).unwrap();
+///     assert!(found[0].get_present() && found[1].get_present() && !found[2].get_present());
+///     
+///     //the last flag can take a value from the next arg
+///     let found = parse(&["foo.exe", "-rio", "out.txt"]).unwrap();
+///     assert_eq!(found[2].get_data(), Some("out.txt"));
+///     
+///     //but no other flag can
+///     assert_eq!(
+///         parse(&["foo.exe", "-ori", "out.txt"]).unwrap_err().to_string(),
+///         "User Error: flag(-o) in '-ori' (arg 1) takes a value, so it can only be the last flag of a cluster"
+///     );
+///     
+///     //and every letter has to be a short flag, the ones that aren't are named
+///     assert_eq!(parse(&["foo.exe", "-rz"]).unwrap_err().to_string(), "User Error: unknown options: '-rz' (arg 1, '-z' isn't a short flag)");
+///     assert_eq!(parse(&["foo.exe", "-rizq"]).unwrap_err().to_string(), "User Error: unknown options: '-rizq' (arg 1, '-z', '-q' aren't short flags)");
+///     assert_eq!(parse(&["foo.exe", "-rzq"]).unwrap_err().to_string(), "User Error: unknown options: '-rzq' (arg 1, '-z', '-q' aren't short flags)");
+///     assert_eq!(parse(&["foo.exe", "-verbose"]).unwrap_err().to_string(), "User Error: unknown options: '-verbose' (arg 1, '-v', '-e', '-b', '-s' aren't short flags)");
+///     //unless none are, then it's just an unknown flag
+///     assert_eq!(parse(&["foo.exe", "-zq"]).unwrap_err().to_string(), "User Error: unknown options: '-zq' (arg 1)");
+///     assert_eq!(parse(&["foo.exe", "-quest"]).unwrap_err().to_string(), "User Error: unknown options: '-quest' (arg 1)");
+/// ```
+/// 
+/// values given after an `=` are the list/data of their flag, even if they look like flags, and errors show the args as they were given
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parameter_args::ClParameter, Parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+///         ClOption::new_flag_data(&ClOptionInfo::new("", "--offset", "Lines to skip").unwrap(), "OFFSET"),
+///     ];
+///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+///     
+///     let found = option_parser::parse_for_options(&to_args(&["foo.exe", "--offset=-5", "--format==x"]), &valid_options).unwrap();
+///     assert_eq!(found[2].get_data(), Some("-5"));
+///     assert_eq!(found[1].get_data(), Some("=x"));
+///     let parser = Parser::new(&to_args(&["foo.exe", "--offset=-5", "path"]), &valid_options, &[ClParameter::new("PATH", "Path to search in")]).unwrap();
+///     assert_eq!(parser.get_option_arguments_found()[2].get_data(), Some("-5"));
+///     assert_eq!(parser.get_one("PATH"), Some("path"));
+///     
+///     assert_eq!(
+///         option_parser::parse_for_options(&to_args(&["foo.exe", "-rF"]), &valid_options).unwrap_err().to_string(),
+///         "No arguments after flag(-F) in args([\"-rF\"])"
+///     );
+/// ```
 /// 
+/// flags typed with unicode dashes
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parameter_args::ClParameter, parse_error::ParseErrors, Parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-t", "--title", "Title of the output").unwrap(), "TITLE"),
+///     ];
+///     let parse = |args: &[&str]| option_parser::parse_for_options(&args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>(), &valid_options);
+///     
+///     //an en dash, and an em dash
+///     assert_eq!(
+///         parse(&["foo.exe", "\u{2013}recursive"]).unwrap_err().to_string(),
+///         "User Error: '\u{2013}recursive' begins with a non-ASCII dash (U+2013); did you mean '--recursive'?"
+///     );
+///     assert_eq!(
+///         parse(&["foo.exe", "\u{2014}format", "NUMERIC"]).unwrap_err().to_string(),
+///         "User Error: '\u{2014}format' begins with a non-ASCII dash (U+2014); did you mean '--format'?"
+///     );
+///     assert_eq!(
+///         parse(&["foo.exe", "\u{2013}r", "\u{2013}\u{2013}verbose"]).unwrap_err().to_string(),
+///         "User Error: '\u{2013}r' begins with a non-ASCII dash (U+2013); did you mean '-r'?\n\
+///         User Error: '\u{2013}\u{2013}verbose' begins with a non-ASCII dash (U+2013); flags start with '-'"
+///     );
+///     
+///     //but the data of a flag, and dashes in the middle of an arg, are left alone
+///     assert_eq!(parse(&["foo.exe", "--title", "\u{2014}draft"]).unwrap()[2].get_data(), Some("\u{2014}draft"));
+///     assert!(parse(&["foo.exe", "-r", "2013\u{2013}2014", "\u{2014}"]).is_ok());
+///     
+///     //and they aren't parameters either, so the Parser reports them once, wherever they are
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+///     for args in [["foo.exe", "\u{2014}recursive", "path"], ["foo.exe", "path", "\u{2014}recursive"]] {
+///         let error = Parser::new(&args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>(), &valid_options, &expected_parameters).err().unwrap();
+///         let errors = error.downcast_ref::<ParseErrors>().unwrap().get_errors();
+///         assert_eq!(errors.len(), 1, "{:?}", args);
+///         assert_eq!(errors[0].to_string(), "User Error: '\u{2014}recursive' begins with a non-ASCII dash (U+2014); did you mean '--recursive'?");
+///     }
+/// ```
+/// 
+/// the program name is ignored, even if it looks like a flag
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parameter_args::ClParameter, parameter_parser, Parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+///     ];
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+///     
+///     for program in ["-r", "-ro", "--output", "--output=a.txt", "-z"] {
+///         let found = option_parser::parse_for_options(&to_args(&[program, "src"]), &valid_options).unwrap();
+///         assert!(found.iter().all(|option| !option.get_present()));
+///         
+///         let found = parameter_parser::parse_for_parameters(&to_args(&[program, "src"]), &expected_parameters).unwrap();
+///         assert_eq!(found[0].get_data(), "src");
+///     }
+///     
+///     let parser = Parser::new(&to_args(&["-r", "-o", "a.txt", "src"]), &valid_options, &expected_parameters).unwrap();
+///     assert!(!parser.get_option_arguments_found()[0].get_present());
+///     assert_eq!(parser.get_option_arguments_found()[1].get_data(), Some("a.txt"));
+///     assert_eq!(parser.get_parameter_arguments_found()[0].get_data(), "src");
+/// ```
+/// 
+/// args without the program name, or with nothing else, never panic, whatever the options and settings
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parser_settings::ParserSettings, Parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_toggle(&ClOptionInfo::new("-c", "--color", "Color the output").unwrap()),
+///         ClOption::new_flag_preset(&ClOptionInfo::new("", "--numbered", "Shorthand for numeric output").unwrap(), &["--format", "NUMERIC"]),
+///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS").bracketed_lists(true),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").default_with(|| String::from("DEFAULT")),
+///     ];
+///     let settings = ParserSettings::new()
+///         .subcommands(&["add"])
+///         .expand_response_files(true)
+///         .normalize_unicode_dashes(true)
+///         .correct_dash_mistakes(true)
+///         .with_report(true)
+///         .metrics(true);
+///
+///     for args in [vec![], vec![String::from("prog")]] {
+///         let found = option_parser::parse_for_options(&args, &valid_options).unwrap();
+///         assert!(found.iter().all(|option| !option.get_present()));
+///         assert!(Parser::new(&args, &valid_options, &[]).is_ok());
+///         assert!(Parser::new_with_settings(&args, &valid_options, &[], &settings).is_ok());
+///     }
+/// ```
 pub fn parse_for_options(args: &[String], valid_options: &[option_args::ClOption]) -> Result<Vec<option_args::ClOption>,Box<dyn Error>> {
-    //DATA
-    let mut valid_flags: Vec<String> = Vec::new();
-    let flags_in_args:Vec<String>;
-    let mut results: Vec<option_args::ClOption>;
-
-    //fill valid_flags with the long and short flags of the ClOptions in valid_options
-    for option in valid_options.into_iter() {
-        match option {
-            option_args::ClOption::Flag { present:_, info } => {
-                //add flags
-                valid_flags.push(info.get_short_flag().to_string());
-                valid_flags.push(info.get_long_flag().to_string());
-            },
-            option_args::ClOption::FlagList { present:_, list_name:_, list:_, info } => {
-                //add flags
-                valid_flags.push(info.get_short_flag().to_string());
-                valid_flags.push(info.get_long_flag().to_string());
-            },
-            option_args::ClOption::FlagData { present:_, data_name:_, data:_, info } => {
-                //add flags
-                valid_flags.push(info.get_short_flag().to_string());
-                valid_flags.push(info.get_long_flag().to_string());
+    parse_for_options_with_settings(args, valid_options, &ParserSettings::default())
+}
+
+/// parse args for Options, like `option_parser::parse_for_options()`, but customized by `settings`
+/// 
+/// # Errors
+/// - same as `option_parser::parse_for_options()`
+/// 
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parser_settings::ParserSettings};
+/// //...
+///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("--recursive ")]; //notice the trailing space
+///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+///     
+///     //by default, flags must match exactly
+///     assert!(option_parser::parse_for_options_with_settings(&args, &valid_options, &ParserSettings::new()).is_err());
+///     
+///     //but trailing whitespace can be tolerated
+///     let parsed_options = option_parser::parse_for_options_with_settings(&args, &valid_options, &ParserSettings::new().trim_flag_whitespace(true)).unwrap();
+///     assert!(parsed_options[0].get_present());
+/// ```
+pub fn parse_for_options_with_settings(args: &[String], valid_options: &[option_args::ClOption], settings: &ParserSettings) -> Result<Vec<option_args::ClOption>,Box<dyn Error>> {
+    let (results, errors) = collect_options(args, valid_options, settings, None);
+
+    if errors.is_empty() {
+        Ok(results)
+    } else {
+        Err(Box::new(ParseErrors::new(errors)))
+    }
+}
+
+/// parses args for options, returning every error found instead of stopping at the first, and adding what happened to `report` if there is one
+pub(crate) fn collect_options(args: &[String], valid_options: &[option_args::ClOption], settings: &ParserSettings, mut report: Option<&mut ParseReport>) -> (Vec<option_args::ClOption>, Vec<ParseError>) {
+    //expand response files if needed, expanding again does nothing so this is safe to recurse with
+    let expanded_args: Vec<String>;
+    let args: &[String] = if settings.get_expand_response_files() {
+        match Expander::new(settings.get_max_expansion_depth()).expand_response_files(args) {
+            Ok(expanded) => {
+                expanded_args = expanded;
+                &expanded_args
             },
+            Err(e) => return (valid_options.to_vec(), vec![e]),
         }
+    } else {
+        args
+    };
+
+    //nothing after a terminator is an option, see `find_terminator()`
+    let args: &[String] = before_terminator(args);
+
+    //replace the unicode dashes flags were typed with if needed, replacing again does nothing
+    let normalized_args: Vec<String>;
+    let args: &[String] = if settings.get_normalize_unicode_dashes() {
+        normalized_args = normalize_unicode_dashes(args, valid_options);
+        &normalized_args
+    } else {
+        args
+    };
+
+    //trim flags and correct flags with the wrong number of dashes if needed, and parse the tidied args instead
+    if settings.get_trim_flag_whitespace() || settings.get_correct_dash_mistakes() {
+        let valid_flags: HashSet<&str> = get_valid_flags(valid_options);
+        let tidied_args: Vec<String> = args.iter().enumerate().map(|(index, arg)| if index == 0 {arg.clone()} else {tidy_arg(arg, &valid_flags, settings)}).collect();
+        return collect_options(&tidied_args, valid_options, &settings.clone().trim_flag_whitespace(false).correct_dash_mistakes(false), report);
+    }
+
+    //join bracketed lists spread across args if needed, joining again does nothing, and errors point at the args the lists were given in
+    let (joined_args, joined_origins): (Vec<String>, Vec<usize>);
+    let (args, origins): (&[String], &[usize]) = if valid_options.iter().any(|option| option.get_bracketed_lists() == Some(true)) {
+        (joined_args, joined_origins) = join_bracketed_lists(args, valid_options, settings);
+        (&joined_args, &joined_origins)
+    } else {
+        (args, &[])
     };
 
-    //parse args for flags
-    flags_in_args = (&args[0..]).iter() //iterator of arguments, ignoring the first one
-    .filter(|arg| arg.starts_with("-")) //that start with a hyphen
-    .map(|arg| arg.clone()) //clone them
-    .collect(); //collect into vector
+    //parse args for options
+    let (mut results, mut errors) = scan_for_options(args, origins, valid_options, settings);
+
+    //report the values that passed their allowed values check
+    if let Some(report) = report.as_deref_mut() {
+        if errors.is_empty() {
+            for option in results.iter().filter(|option| option.get_present() && option.get_allowed_values().is_some_and(|allowed| !allowed.is_empty())) {
+                let values: Vec<String> = option.get_list().cloned().or_else(|| option.get_data().map(|data| vec![data.to_string()])).unwrap_or_default();
+                report.push(ReportEntry::ValuesChecked { flag: get_display_flag(option).to_string(), values });
+            }
+        }
+    }
+
+    //apply the presets of the FlagPresets that are present, to the options the user didn't set themselves
+    let presets: Vec<(String, Vec<String>)> = results.iter()
+    .filter(|option| option.get_present())
+    .filter_map(|option| option.get_preset().map(|preset| (get_display_flag(option).to_string(), preset.clone())))
+    .collect();
+    for (flag, preset) in presets {
+        let mut expander: Expander = Expander::new(settings.get_max_expansion_depth());
+        if let Err(e) = apply_preset(&flag, &preset, &mut results, valid_options, settings, &mut expander, &mut report) {
+            errors.push(e);
+        }
+    }
+
+    //ask the value providers for the list/data of the options that still aren't present, parsing what they provide as if it was given in the args
+    for option in results.iter_mut().filter(|option| !option.get_present() && matches!(option, option_args::ClOption::FlagList { .. } | option_args::ClOption::FlagData { .. })) {
+        let Some((provider, value)) = settings.get_value_providers().iter().find_map(|provider| provider.provide(option.get_info()).map(|value| (provider, value))) else {continue};
+        let flag: String = get_display_flag(option).to_string();
+        let (provided, provided_errors) = scan_for_options(&[String::new(), flag.clone(), value], &[], std::slice::from_ref(option), settings);
+
+        if provided_errors.is_empty() {
+            *option = provided.into_iter().next().unwrap_or_else(|| option.clone());
+            if let Some(report) = report.as_deref_mut() {
+                report.push(ReportEntry::ValueProvided { flag, provider: provider.get_name().to_string() });
+            }
+        } else {
+            errors.extend(provided_errors.iter().map(|e| ParseError::new(e.get_kind(), None, &format!("{} (provided by {})", e.get_message(), provider.get_name())).with_flag(&flag)));
+        }
+    }
+
+    //report the values that passed their value parser, wherever they came from
+    if let Some(report) = report.as_deref_mut() {
+        if errors.is_empty() {
+            for option in results.iter().filter(|option| option.get_present()) {
+                let Some(validator) = option.get_value_parser() else {continue};
+                report.push(ReportEntry::ValidatorPassed { flag: get_display_flag(option).to_string(), validator: *validator });
+            }
+        }
+    }
+
+    //produce the lazy defaults of FlagData that still aren't present
+    for option in results.iter_mut() {
+        let flag: String = get_display_flag(option).to_string();
+        if let option_args::ClOption::FlagData { present: false, data, default_with: Some(default), .. } = option {
+            *data = default.produce();
+            if let Some(report) = report.as_deref_mut() {
+                report.push(ReportEntry::DefaultApplied { flag, value: data.clone() });
+            }
+        }
+    }
+
+    (results, errors)
+}
+
+/// applies the `preset` of the FlagPreset with `flag` to the options in `results` the user didn't set themselves,
+/// then the presets of any FlagPresets in it, every preset goes through `expander`
+fn apply_preset(flag: &str, preset: &[String], results: &mut [option_args::ClOption], valid_options: &[option_args::ClOption], settings: &ParserSettings, expander: &mut Expander, report: &mut Option<&mut ParseReport>) -> Result<(), ParseError> {
+    expander.enter(Indirection::Preset(flag.to_string()))?;
+
+    //presets can include response files
+    let preset: Vec<String> = if settings.get_expand_response_files() {expander.expand_response_files(preset)?} else {preset.to_vec()};
+
+    //scanned after an empty program name, so the first flag of the preset isn't skipped
+    let preset: Vec<String> = std::iter::once(String::new()).chain(preset).collect();
+    let (preset_options, preset_errors) = scan_for_options(&preset, &[], valid_options, &settings.clone().allow_unknown_flags(false));
+    if !preset_errors.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::InvalidPreset, None, &format!("BUG: invalid preset for flag({}): {}", flag, ParseErrors::new(preset_errors))).with_flag(flag));
+    }
+
+    let mut applied_to: Vec<String> = Vec::new();
+    let mut included: Vec<(String, Vec<String>)> = Vec::new();
+    for (result, preset_option) in results.iter_mut().zip(preset_options) {
+        if !preset_option.get_present() {
+            continue;
+        }
+        if let Some(included_preset) = preset_option.get_preset() {
+            included.push((get_display_flag(&preset_option).to_string(), included_preset.clone()));
+        }
+        if !result.get_present() {
+            applied_to.push(get_display_flag(&preset_option).to_string());
+            *result = preset_option;
+        }
+    }
+    if let Some(report) = report.as_deref_mut() {
+        report.push(ReportEntry::PresetApplied { flag: flag.to_string(), applied_to });
+    }
+
+    for (included_flag, included_preset) in included {
+        apply_preset(&included_flag, &included_preset, results, valid_options, settings, expander, report)?;
+    }
+
+    expander.leave();
+    Ok(())
+}
+
+/// parses args for options, without expanding presets, errors point at the arg in the args they were joined from that each arg at an index in `origins` came from (see `join_bracketed_lists()`),
+/// or its own index if it isn't in `origins`
+fn scan_for_options(args: &[String], origins: &[usize], valid_options: &[option_args::ClOption], settings: &ParserSettings) -> (Vec<option_args::ClOption>, Vec<ParseError>) {
+    //DATA
+    let valid_flags: HashSet<&str> = get_valid_flags(valid_options);
+    let tokenizer: Tokenizer = Tokenizer::new(valid_options);
+    let mut results: Vec<option_args::ClOption> = valid_options.to_vec();
+    let mut errors: Vec<ParseError> = Vec::new();
+    let mut suggestions_left: usize = settings.get_max_suggestions();
+    let origin = |index: usize| origins.get(index).copied().unwrap_or(index);
+
+    //the program name in args[0] is never a flag, or the list/data of one
+    let scanned_args: &[String] = args.get(1..).unwrap_or_default();
+
+    //the items of each list are split into the same buffer, so they're only allocated once they're matched
+    let mut list_items: Vec<&str> = Vec::new();
+
+    //every occurrence of a flag of each option: the index of its arg, the flag as it was typed, and its list/data if it was given after an '='
+    let mut occurrences: Vec<Vec<(usize, &str, Option<&str>)>> = vec![Vec::new(); valid_options.len()];
+    for (index, arg) in args.iter().enumerate().skip(1) {
+        let value: Option<&str> = match tokenizer.split(arg) {
+            Token::Equals(flag, value) => {
+                if value.is_empty() {
+                    errors.push(ParseError::new(ParseErrorKind::MissingValue, Some(origin(index)), &format!("User Error: no value given after '=' for flag({})", flag)).with_flag(flag));
+                }
+                Some(value)
+            },
+            Token::Cluster(_) => {
+                //only the last flag of a cluster can take a list/data, the arg after it is still taken by the first one that does, so it isn't reported too
+                if let Some(option) = tokenizer.find_misplaced(arg) {
+                    let flag: &str = tokenizer.get_short_name(option);
+                    errors.push(ParseError::new(
+                        ParseErrorKind::MisplacedFlag,
+                        Some(origin(index)),
+                        &format!("User Error: flag({}) in '{}' (arg {}) takes a value, so it can only be the last flag of a cluster", flag, arg, origin(index)),
+                    ).with_flag(flag));
+                }
+                None
+            },
+            Token::Positional | Token::Flag(_) => None,
+        };
+        tokenizer.for_each_flag(arg, |option, flag| occurrences[option].push((index, flag, value)));
+    }
+
+    //invalid flags in args (flags not in valid_flags) that are valid flags with the wrong number of dashes, or options of a subcommand given after them, get their own errors
+    let invalid_flags: Vec<(usize, &String)> = args.iter().enumerate().skip(1)
+    .filter(|(_, arg)| tokenizer.is_unknown_flag(arg))
+    .filter(|(index, arg)| match find_dash_mistake(arg, &valid_flags).map(|(_, message)| message).or_else(|| find_misplaced_subcommand_option(args, *index, settings)) {
+        Some(message) => {
+            if !settings.get_allow_unknown_flags() {
+                errors.push(ParseError::new(ParseErrorKind::MisplacedFlag, Some(origin(*index)), &format!("User Error: {}", message)).with_flag(arg));
+            }
+            false
+        },
+        None => true,
+    })
+    .collect();
+
+    //flags typed with unicode dashes aren't flags at all, so they get their own errors too, but the list/data of a flag is left alone
+    if !settings.get_allow_unknown_flags() {
+        for index in find_unicode_dash_flags(args, valid_options) {
+            if let Some((_, message)) = find_unicode_dash(&args[index], &valid_flags) {
+                errors.push(ParseError::new(ParseErrorKind::MisplacedFlag, Some(origin(index)), &format!("User Error: {}", message)).with_flag(&args[index]));
+            }
+        }
+    }
 
-    //if there are invalid flags in args (flags not in valid_flags), throw an error
-    if flags_in_args.iter().any(|arg| !valid_flags.contains(arg)) {
-        return Err("User Error: One or more invalid flags given.".into());
+    //if there are any other invalid flags, report all of them in one error, in the order they were given
+    if !invalid_flags.is_empty() && !settings.get_allow_unknown_flags() {
+        errors.push(unknown_flags_error(&invalid_flags.iter().map(|(index, arg)| (origin(*index), arg.as_str())).collect::<Vec<(usize, &str)>>(), &valid_flags));
     }
 
     //construct a list of options, with their associated data
-    results = valid_options.to_vec();
-    for cl_option in results.iter_mut() {
+    for (cl_option, occurrences) in results.iter_mut().zip(occurrences.iter()) {
         match cl_option {
-            option_args::ClOption::Flag { present, info } => {
+            option_args::ClOption::Flag { present, .. } | option_args::ClOption::FlagPreset { present, .. } => {
                 //update data
-                *present = flags_in_args.contains(&info.get_short_flag().to_string()) || flags_in_args.contains(&info.get_long_flag().to_string());
+                *present = !occurrences.is_empty();
             },
-            option_args::ClOption::FlagList { present, list_name:_, list, info } => {
-                //update data
-                if flags_in_args.contains(&info.get_short_flag().to_string()) {
-                    *present = true;
-                    match get_list_after_flag(args, info.get_short_flag()) {
-                        Ok(list_from_args) => *list = list_from_args,
-                        Err(e) => return Err(e),
-                    }
-                } else if flags_in_args.contains(&info.get_long_flag().to_string()) {
-                    *present = true;
-                    match get_list_after_flag(args, info.get_long_flag()) {
-                        Ok(list_from_args) => *list = list_from_args,
-                        Err(e) => return Err(e),
-                    }
-                } 
-                else {
-                    *present = false;
+            option_args::ClOption::FlagToggle { present, toggle, .. } => {
+                //update data, every occurrence of either flag flips it
+                *present = !occurrences.is_empty();
+                *toggle = occurrences.len() % 2 == 1;
+            },
+            option_args::ClOption::FlagCount { count, .. } => {
+                //count every occurrence of either flag
+                *count = occurrences.len().try_into().unwrap_or(u32::MAX);
+            },
+            option_args::ClOption::FlagList { present, list, allowed_values, ignore_case, key_value_pairs, bracketed_lists, .. } => {
+                //update data, the list after the first occurrence of either flag
+                *present = !occurrences.is_empty();
+                let Some((index, flag, value)) = occurrences.first().copied() else {continue};
+                //given after an '=', or the next arg
+                let (found_list, value_index) = match value {
+                    Some(value) => (Ok(value), origin(index)),
+                    None => (find_value_at(scanned_args, flag, index - 1), origin(index + 1)),
+                };
+                match found_list.and_then(|found_list| split_list(scanned_args, flag, found_list, *bracketed_lists, &mut list_items)) {
+                    Ok(()) => {
+                        list.clear();
+                        list.reserve(list_items.len());
+                        for item in list_items.iter() {
+                            if *key_value_pairs && !item.contains(':') {
+                                errors.push(ParseError::new(ParseErrorKind::InvalidValue, Some(value_index), &format!("User Error: invalid item '{}' for flag({}), expected 'KEY: VALUE'", item.trim(), flag)).with_flag(flag));
+                                continue;
+                            }
+                            match match_allowed_value(item, flag, allowed_values, *ignore_case, &mut suggestions_left) {
+                                Ok(item) => list.push(item),
+                                Err(e) => errors.push(ParseError::new(ParseErrorKind::InvalidValue, Some(value_index), &e.to_string()).with_flag(flag)),
+                            }
+                        }
+                    },
+                    Err(e) => errors.push(ParseError::new(ParseErrorKind::MissingValue, Some(origin(index)), &e.to_string()).with_flag(flag)),
                 }
             },
-            option_args::ClOption::FlagData { present, data_name:_, data, info } => {
+            option_args::ClOption::FlagData { present, data, raw_data, allowed_values, ignore_case, value_parser, encoding, reject_empty_value, on_repeat, values, info, .. } => {
+                //every occurrence of either flag
+                if *on_repeat == option_args::OnRepeat::Error && occurrences.len() > 1 {
+                    let flag: &str = if info.get_long_flag().is_empty() {info.get_short_flag()} else {info.get_long_flag()};
+                    errors.push(ParseError::new(ParseErrorKind::RepeatedFlag, Some(origin(occurrences[1].0)), &format!("User Error: flag({}) was given {} times, but can only be given once", flag, occurrences.len())).with_flag(flag));
+                }
+
+                //the occurrences whose data is kept
+                let kept: &[(usize, &str, Option<&str>)] = match on_repeat {
+                    option_args::OnRepeat::FirstWins | option_args::OnRepeat::Error => &occurrences[..occurrences.len().min(1)],
+                    option_args::OnRepeat::LastWins => &occurrences[occurrences.len().saturating_sub(1)..],
+                    option_args::OnRepeat::Accumulate => occurrences,
+                };
+
                 //update data
-                if flags_in_args.contains(&info.get_short_flag().to_string()) {
-                    *present = true;
-                    match get_data_after_flag(args, info.get_short_flag()) {
-                        Ok(data_from_args) => *data = data_from_args,
-                        Err(e) => return Err(e),
-                    }
-                } else if flags_in_args.contains(&info.get_long_flag().to_string()){
-                    *present = true;
-                    match get_data_after_flag(args, info.get_long_flag()) {
-                        Ok(data_from_args) => *data = data_from_args,
-                        Err(e) => return Err(e),
+                *present = !kept.is_empty();
+                values.clear();
+                for (nth, (index, flag, value)) in kept.iter().copied().enumerate() {
+                    let (found_data, value_index) = match value {
+                        Some(value) => (Ok(value), origin(index)),
+                        None => (find_value_at(scanned_args, flag, index - 1), origin(index + 1)),
+                    };
+                    match found_data {
+                        Ok(found_data) if found_data.is_empty() && *reject_empty_value => {
+                            errors.push(ParseError::new(ParseErrorKind::InvalidValue, Some(value_index), &format!("User Error: flag({}) was given an empty value", flag)).with_flag(flag));
+                        },
+                        Ok(found_data) => {
+                            match match_allowed_value(found_data, flag, allowed_values, *ignore_case, &mut suggestions_left) {
+                                Ok(matched_data) => {
+                                    //check the data is well-formed
+                                    let checked: Option<Result<(), String>> = value_parser.map(|parser| parser.check(&matched_data))
+                                        .filter(|checked| checked.is_err())
+                                        .or_else(|| encoding.map(|encoding| encoding.decode(&matched_data).map(|_| ())));
+                                    if let Some(Err(e)) = checked {
+                                        errors.push(ParseError::new(ParseErrorKind::InvalidValue, Some(value_index), &format!("User Error: invalid value '{}' for flag({}): {}", matched_data, flag, e)).with_flag(flag));
+                                    }
+                                    if nth == 0 {
+                                        *data = matched_data.clone();
+                                    }
+                                    values.push(matched_data);
+                                },
+                                Err(e) => errors.push(ParseError::new(ParseErrorKind::InvalidValue, Some(value_index), &e.to_string()).with_flag(flag)),
+                            }
+                            if nth == 0 {
+                                *raw_data = found_data.to_string();
+                            }
+                        },
+                        Err(e) => errors.push(ParseError::new(ParseErrorKind::MissingValue, Some(origin(index)), &e.to_string()).with_flag(flag)),
                     }
-                } 
-                else {
-                    *present = false;
                 }
             },
         }
     }
-    return Ok(results);
+
+    (results, errors)
+}
+
+/// returns the flag `option` is shown as in messages, its long flag, or its short flag if it doesn't have one
+pub(crate) fn get_display_flag(option: &option_args::ClOption) -> &str {
+    if option.get_long_flag().is_empty() {option.get_short_flag()} else {option.get_long_flag()}
+}
+
+/// returns the value in `allowed_values` that `value` matches (ignoring case if `ignore_case` is set), or `value` itself if anything is allowed,
+/// the error suggests the closest allowed value if there are `suggestions_left`, using one up
+fn match_allowed_value(value: &str, flag: &str, allowed_values: &[String], ignore_case: bool, suggestions_left: &mut usize) -> Result<String,Box<dyn Error>> {
+    if allowed_values.is_empty() {
+        return Ok(value.to_string());
+    }
+
+    match allowed_values.iter().find(|allowed| if ignore_case {allowed.eq_ignore_ascii_case(value)} else {allowed.as_str().eq(value)}) {
+        Some(allowed) => Ok(allowed.clone()),
+        None => {
+            let suggestion: String = if *suggestions_left > 0 {suggest::did_you_mean(value, allowed_values.iter().map(|allowed| allowed.as_str()))} else {String::new()};
+            *suggestions_left = suggestions_left.saturating_sub(1);
+            Err(format!("User Error: invalid value '{}' for flag({}),{} possible values are: {}", value, flag, suggestion, allowed_values.join(", ")).into())
+        },
+    }
+}
+
+/// returns `true` if `arg` is a flag (starts with a `-`), `false` otherwise
+/// 
+/// this is the one definition of "looks like a flag" used both when checking args for invalid flags,
+/// and when checking whether a flag is followed by its list/data, so the two can never disagree
+/// 
+/// as a consequence, a token starting with a `-` is never used as the list/data of the flag before it:
+/// if it's a valid flag, that's a missing list/data error, and if isn't, it's reported as an invalid flag
+/// 
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser};
+/// //...
+///     assert!(option_parser::is_flag("-r"));
+///     assert!(option_parser::is_flag("--recursive"));
+///     assert!(!option_parser::is_flag("path/to/search"));
+///     assert!(!option_parser::is_flag("-")); //a lone dash is data, conventionally meaning stdin or stdout
+///     
+///     //an undefined flag following a data flag is reported as an invalid flag (and the data as missing), rather than used as the data
+///     let valid_options = vec![
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output in a list").unwrap(), "FORMAT"),
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///     ];
+///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("--format"), String::from("-z")];
+///     let error = option_parser::parse_for_options(&args, &valid_options).unwrap_err().to_string();
+///     assert_eq!(error.lines().next(), Some("User Error: unknown options: '-z' (arg 2)"));
+///     assert!(error.lines().nth(1).unwrap().starts_with("No list found after flag(--format)"));
+///     
+///     //and a defined flag following a data flag means the data is missing
+///     let args: Vec<String> = vec![String::from("path/to/executable/"), String::from("--format"), String::from("-r")];
+///     assert!(option_parser::parse_for_options(&args, &valid_options).unwrap_err().to_string().starts_with("No list found after flag(--format)"));
+/// ```
+pub fn is_flag(arg: &str) -> bool {
+    arg.starts_with('-') && arg.ne("-")
+}
+
+/// returns the index of the first `--` in `args` (after the program name), which ends the options, every arg after it is a positional, even if it looks like a flag,
+/// ei. `foo.exe -r -- -file-starting-with-a-dash`
+/// 
+/// # None
+/// - there is no `--` in `args`
+/// 
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parameter_args::ClParameter, Parser};
+/// //...
+///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+///     assert_eq!(option_parser::find_terminator(&to_args(&["foo.exe", "-r", "--", "-z", "--"])), Some(2));
+///     assert_eq!(option_parser::find_terminator(&to_args(&["foo.exe", "-r", "path"])), None);
+///     
+///     //how it's handled by the Parser
+///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("QUERY", "String to search for"), ClParameter::new("PATH", "Path to search in")];
+///     let parser = Parser::new(&to_args(&["foo.exe", "-r", "fn", "--", "-r"]), &valid_options, &expected_parameters).unwrap();
+///     assert!(parser.get_option_arguments_found()[0].get_present());
+///     assert_eq!(parser.get_parameter_arguments_found()[0].get_data(), "fn");
+///     assert_eq!(parser.get_parameter_arguments_found()[1].get_data(), "-r");
+/// ```
+pub fn find_terminator(args: &[String]) -> Option<usize> {
+    args.iter().skip(1).position(|arg| arg.eq("--")).map(|index| index + 1)
+}
+
+/// returns the args in `args` before the first `--`, the ones that can be flags, see `find_terminator()`
+pub(crate) fn before_terminator(args: &[String]) -> &[String] {
+    &args[..find_terminator(args).unwrap_or(args.len())]
+}
+
+/// returns the flags of every option in `valid_options` that occurs more than once in `args` (counting both its short and long flag),
+/// in the order of their first occurrence, as they were first typed
+/// 
+/// parsing accepts repeats (the first occurrence wins), so this is useful for warning the user about accidental ones
+/// 
+/// # Notes:
+/// - options of type ClOption::FlagToggle are never listed, since they're meant to be repeated
+/// - neither are options of type ClOption::FlagData that don't keep the first value, see `ClOption::on_repeat()`
+/// - args after a `--` aren't flags, so they're never counted, see `option_parser::find_terminator()`
+/// 
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///     ];
+///     
+///     let args: Vec<String> = ["foo.exe", "-r", "-o", "a.txt", "path"].iter().map(|arg| arg.to_string()).collect();
+///     assert!(option_parser::find_duplicated_flags(&args, &valid_options).is_empty());
+///     
+///     let args: Vec<String> = ["foo.exe", "--output", "a.txt", "-r", "-o", "b.txt", "path"].iter().map(|arg| arg.to_string()).collect();
+///     assert_eq!(option_parser::find_duplicated_flags(&args, &valid_options), vec![String::from("--output")]);
+/// ```
+pub fn find_duplicated_flags(args: &[String], valid_options: &[option_args::ClOption]) -> Vec<String> {
+    //DATA
+    let tokenizer: Tokenizer = Tokenizer::new(valid_options);
+    let mut occurrences: Vec<Vec<(usize, &str)>> = vec![Vec::new(); valid_options.len()];
+
+    //every occurrence of a flag of each option, the index of its arg and the flag as it was typed
+    for (index, arg) in before_terminator(args).iter().enumerate().skip(1) {
+        tokenizer.for_each_flag(arg, |option, flag| occurrences[option].push((index, flag)));
+    }
+
+    let mut duplicated: Vec<(usize, String)> = valid_options.iter().zip(occurrences.iter())
+    .filter(|(option, _)| option.get_toggle().is_none() && option.get_count().is_none())
+    .filter(|(option, _)| option.get_on_repeat().unwrap_or_default() == option_args::OnRepeat::FirstWins)
+    .filter(|(_, occurrences)| occurrences.len() > 1)
+    .map(|(_, occurrences)| (occurrences[0].0, occurrences[0].1.to_string()))
+    .collect();
+
+    //order by first occurrence
+    duplicated.sort_by_key(|(index, _)| *index);
+    duplicated.into_iter().map(|(_, flag)| flag).collect()
+}
+
+/// returns every flag in `args` that isn't a flag of any option in `valid_options`, in the order they were given, without failing,
+/// ei. for a `--check` mode that reports everything wrong with a command line at once
+/// 
+/// flags with the wrong number of dashes (ei. `-recursive`) aren't recognized either, so they're included,
+/// but args after a `--` aren't flags (see `option_parser::find_terminator()`), so they aren't
+/// 
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///     ];
+///     
+///     let args: Vec<String> = ["foo.exe", "-z", "-r", "--verbose", "-o", "a.txt", "-recursive", "-z", "path"].iter().map(|arg| arg.to_string()).collect();
+///     assert_eq!(option_parser::find_unknown_flags(&args, &valid_options), vec!["-z", "--verbose", "-recursive", "-z"]);
+///     
+///     //only known flags
+///     let args: Vec<String> = ["foo.exe", "-r", "--output", "a.txt", "path"].iter().map(|arg| arg.to_string()).collect();
+///     assert!(option_parser::find_unknown_flags(&args, &valid_options).is_empty());
+/// ```
+/// 
+/// args are split like the parser splits them, so clusters of short flags, and values given after an `=`, aren't unknown,
+/// and the flags it finds are the ones `option_parser::parse_iter()` and the Parser report
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parameter_args::ClParameter, parse_error::ParseErrorKind, Parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag(&ClOptionInfo::new("-i", "--ignore-case", "Ignore case").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+///         ClOption::new_flag_data(&ClOptionInfo::new("", "--offset", "Lines to skip").unwrap(), "OFFSET"),
+///     ];
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+///     
+///     for args in [
+///         to_args(&["foo.exe", "-ri", "--offset=-5", "--format=-x", "path"]),
+///         to_args(&["foo.exe", "-rF", "json", "--offset=3", "-z", "path"]),
+///         to_args(&["foo.exe", "--format=json", "-rz", "--verbose", "-ir", "path"]),
+///     ] {
+///         let unknown: Vec<String> = option_parser::find_unknown_flags(&args, &valid_options);
+///         let from_iter: Vec<String> = option_parser::parse_iter(&args, &valid_options)
+///             .filter_map(Result::err)
+///             .filter(|e| e.get_kind() == ParseErrorKind::UnknownFlag)
+///             .map(|e| args[e.get_arg_index().unwrap()].clone())
+///             .collect();
+///         assert_eq!(unknown, from_iter, "{:?}", args);
+///         
+///         match Parser::new(&args, &valid_options, &expected_parameters) {
+///             Ok(parser) => {
+///                 assert!(unknown.is_empty(), "{:?}", args);
+///                 assert_eq!(parser.get_one("PATH"), Some("path"));
+///             },
+///             Err(e) => assert!(!unknown.is_empty() && unknown.iter().all(|flag| e.to_string().contains(&format!("'{}'", flag))), "{:?}", args),
+///         }
+///     }
+/// ```
+pub fn find_unknown_flags(args: &[String], valid_options: &[option_args::ClOption]) -> Vec<String> {
+    let tokenizer: Tokenizer = Tokenizer::new(valid_options);
+    before_terminator(args).iter().skip(1).filter(|arg| tokenizer.is_unknown_flag(arg)).cloned().collect()
+}
+
+/// returns the index of every stray arg in `args`, args before the parameters (after the last option and its list/data)
+/// that aren't flags, or the list/data of a flag, ei. `extra` in `foo.exe -f rs,toml extra -F NUMERIC path`,
+/// in the order they were given, see `ParserSettings::stray_args()`
+/// 
+/// # Notes:
+/// - flags are recognized with `settings`, like the parser does, see `classifier::Classifier`
+/// - a subcommand (see `ParserSettings::subcommands()`) isn't stray
+/// - the arg after a flag that isn't defined is stray, since there's no telling if it takes a value
+/// 
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser, parser_settings::ParserSettings};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+///     ];
+///     let stray_args = |args: &[&str]| {
+///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+///         option_parser::find_stray_args(&args, &valid_options, &ParserSettings::new())
+///     };
+///     
+///     assert_eq!(stray_args(&["foo.exe", "-f", "rs,toml", "extra", "-F", "NUMERIC", "path"]), vec![3]);
+///     assert_eq!(stray_args(&["foo.exe", "first", "-r", "second", "third", "-F", "NUMERIC", "path"]), vec![1, 3, 4]);
+///     //the values of options aren't stray, and neither are the parameters
+///     assert!(stray_args(&["foo.exe", "-f", "rs,toml", "-F", "NUMERIC", "-r", "path", "needle"]).is_empty());
+///     assert!(stray_args(&["foo.exe", "path"]).is_empty());
+/// ```
+pub fn find_stray_args(args: &[String], valid_options: &[option_args::ClOption], settings: &ParserSettings) -> Vec<usize> {
+    //DATA
+    let first: usize = if args.get(1).is_some_and(|arg| settings.get_subcommands().contains(arg)) {2} else {1};
+    let classifier: Classifier = Classifier::new(valid_options, settings);
+    let first_positional: usize = parameter_parser::find_positionals(args, &classifier, first).first().copied().unwrap_or(args.len());
+
+    (first..first_positional)
+    .filter(|index| classifier.classify(&args[*index], Some(&args[*index - 1])) == TokenClass::Positional)
+    .collect()
+}
+
+/// returns true if `short` or `long` is in `args` (after the program name, and before any `--`, see `option_parser::find_terminator()`), without needing any options defined,
+/// ei. for checking for `--help` or `--debug` early, before parsing properly
+///
+/// either flag can be empty, in which case only the other is looked for
+///
+/// # Examples
+/// ```
+/// use clia::option_parser;
+/// //...
+///     let args: Vec<String> = ["foo.exe", "-d", "path/to/search"].iter().map(|arg| arg.to_string()).collect();
+///     assert!(option_parser::flag_present(&args, "-d", "--debug"));
+///
+///     let args: Vec<String> = ["foo.exe", "--debug", "path/to/search"].iter().map(|arg| arg.to_string()).collect();
+///     assert!(option_parser::flag_present(&args, "-d", "--debug"));
+///
+///     let args: Vec<String> = ["foo.exe", "--help", "path/to/search"].iter().map(|arg| arg.to_string()).collect();
+///     assert!(!option_parser::flag_present(&args, "-d", "--debug"));
+///     assert!(!option_parser::flag_present(&args, "", "")); //nothing to look for
+/// ```
+pub fn flag_present(args: &[String], short: &str, long: &str) -> bool {
+    before_terminator(args).iter().skip(1).any(|arg| is_flag(arg) && ((!short.is_empty() && arg.eq(short)) || (!long.is_empty() && arg.eq(long))))
+}
+
+/// an arg recognized by `option_parser::parse_iter()`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsedItem {
+    /// a flag of a valid option, and its list/data if it takes one
+    Flag {
+        /// the index of the flag in the args
+        arg_index: usize,
+        /// the index of the option in the valid options
+        option_index: usize,
+        /// the flag, as it was typed
+        flag: String,
+        /// the list/data after the flag (with the allowed value it matched, if the option has allowed values), none if the option doesn't take one
+        value: Option<String>,
+    },
+    /// an arg that isn't a flag, or the list/data of one
+    Positional {
+        /// the index of the arg in the args
+        arg_index: usize,
+        /// the arg
+        value: String,
+    },
+}
+
+/// returns an iterator over the args after the program name, that recognizes them one at a time instead of parsing them all at once,
+/// so the caller decides whether to stop at the first error or keep going, ei. to process the recognized args of a command line with mistakes in it
+///
+/// # Notes:
+/// - args are split like the parser splits them, so a cluster of short flags is an item for each of its flags,
+///   and the list/data of an option is the arg right after its flag (or its cluster), or the text after the `=` of a long flag, and is part of the same item
+/// - the list/data of an option is checked against its allowed values and value parser (see `ClOption::with_allowed_values()` and `ClOption::with_value_parser()`)
+/// - nothing is checked across args, ei. presets aren't applied, and repeated flags are all returned
+/// - the first `--` isn't an item, and every arg after it is a positional, see `option_parser::find_terminator()`
+///
+/// # Errors
+/// every error is a `parse_error::ParseError`, and parsing carries on with the next arg after it:
+/// - a flag isn't a flag of any option in `valid_options`
+/// - a flag that needs a list/data isn't followed by one, the arg after it (if any) is parsed as the next item
+/// - a list item or data isn't one of the allowed values of its option, or isn't well-formed
+///
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser::{self, ParsedItem}, parse_error::{ParseError, ParseErrorKind}};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"]),
+///     ];
+///     let args: Vec<String> = ["foo.exe", "-r", "-z", "--format", "FANCY", "--format", "BULLET", "path"].iter().map(|arg| arg.to_string()).collect();
+///
+///     //collect every result
+///     let results: Vec<Result<ParsedItem, ParseError>> = option_parser::parse_iter(&args, &valid_options).collect();
+///     assert_eq!(results.len(), 5);
+///     assert_eq!(results[0], Ok(ParsedItem::Flag { arg_index: 1, option_index: 0, flag: String::from("-r"), value: None }));
+///     assert_eq!(results[1].as_ref().unwrap_err().get_kind(), ParseErrorKind::UnknownFlag);
+///     assert_eq!(results[2].as_ref().unwrap_err().get_kind(), ParseErrorKind::InvalidValue);
+///     assert_eq!(results[3], Ok(ParsedItem::Flag { arg_index: 5, option_index: 1, flag: String::from("--format"), value: Some(String::from("BULLET")) }));
+///     assert_eq!(results[4], Ok(ParsedItem::Positional { arg_index: 7, value: String::from("path") }));
+///
+///     //or just the recognized args
+///     assert_eq!(option_parser::parse_iter(&args, &valid_options).filter_map(Result::ok).count(), 3);
+///
+///     //or stop at the first error
+///     let first: Result<Vec<ParsedItem>, ParseError> = option_parser::parse_iter(&args, &valid_options).collect();
+///     assert_eq!(first.unwrap_err().to_string(), "User Error: unknown options: '-z' (arg 2)");
+///
+///     //nothing after a terminator is a flag
+///     let args: Vec<String> = ["foo.exe", "-r", "--", "-z"].iter().map(|arg| arg.to_string()).collect();
+///     let results: Vec<Result<ParsedItem, ParseError>> = option_parser::parse_iter(&args, &valid_options).collect();
+///     assert_eq!(results, vec![
+///         Ok(ParsedItem::Flag { arg_index: 1, option_index: 0, flag: String::from("-r"), value: None }),
+///         Ok(ParsedItem::Positional { arg_index: 3, value: String::from("-z") }),
+///     ]);
+/// ```
+pub fn parse_iter<'a>(args: &'a [String], valid_options: &'a [option_args::ClOption]) -> impl Iterator<Item = Result<ParsedItem, ParseError>> + 'a {
+    //DATA
+    let valid_flags: HashSet<&str> = get_valid_flags(valid_options);
+    let tokenizer: Tokenizer = Tokenizer::new(valid_options);
+    let mut index: usize = 1;
+    let terminator: usize = find_terminator(args).unwrap_or(args.len());
+    let mut pending: VecDeque<Result<ParsedItem, ParseError>> = VecDeque::new();
+
+    std::iter::from_fn(move || {
+        //an arg can be more than one item, ei. a cluster of short flags
+        while pending.is_empty() {
+            //the terminator isn't an item, and every arg after it is a positional
+            if index == terminator {
+                index += 1;
+            }
+            let arg: &String = args.get(index)?;
+            let arg_index: usize = index;
+            index += 1;
+
+            if !is_flag(arg) || arg_index > terminator {
+                return Some(Ok(ParsedItem::Positional { arg_index, value: arg.clone() }));
+            }
+            if tokenizer.is_unknown_flag(arg) {
+                return Some(Err(unknown_flags_error(&[(arg_index, arg)], &valid_flags)));
+            }
+
+            //the next arg is the list/data of a flag that takes one, even one before the last flag of a cluster, which is an error, so it isn't a positional
+            let next: Option<&String> = args.get(index).filter(|next| !is_flag(next) && tokenizer.find_value_taker(arg).is_some());
+            index += usize::from(next.is_some());
+            if let Some(option_index) = tokenizer.find_misplaced(arg) {
+                let flag: &str = tokenizer.get_short_name(option_index);
+                return Some(Err(ParseError::new(
+                    ParseErrorKind::MisplacedFlag,
+                    Some(arg_index),
+                    &format!("User Error: flag({}) in '{}' (arg {}) takes a value, so it can only be the last flag of a cluster", flag, arg, arg_index),
+                ).with_flag(flag)));
+            }
+
+            //the list/data is given after the '=', or is the next arg
+            let (value, value_index): (Option<&str>, usize) = match tokenizer.split(arg) {
+                Token::Equals(_, value) => (Some(value), arg_index),
+                _ => (next.map(|next| next.as_str()), arg_index + 1),
+            };
+            tokenizer.for_each_flag(arg, |option_index, flag| pending.push_back(match tokenizer.takes_value(option_index) {
+                false => Ok(ParsedItem::Flag { arg_index, option_index, flag: flag.to_string(), value: None }),
+                true => parse_value(&valid_options[option_index], option_index, arg_index, flag, value, value_index),
+            }));
+        }
+        pending.pop_front()
+    })
+}
+
+/// returns the item of the flag `flag` at `arg_index` of `option`, which takes a list/data, and was given `value` in the arg at `value_index`, see `option_parser::parse_iter()`
+fn parse_value(option: &option_args::ClOption, option_index: usize, arg_index: usize, flag: &str, value: Option<&str>, value_index: usize) -> Result<ParsedItem, ParseError> {
+    //DATA
+    let kind: &str = if matches!(option, option_args::ClOption::FlagList { .. }) {"list"} else {"data"};
+    let Some(value) = value else {
+        return Err(ParseError::new(ParseErrorKind::MissingValue, Some(arg_index), &format!("User Error: no {} found after flag({})", kind, flag)).with_flag(flag));
+    };
+    if value.is_empty() && value_index == arg_index {
+        return Err(ParseError::new(ParseErrorKind::MissingValue, Some(arg_index), &format!("User Error: no value given after '=' for flag({})", flag)).with_flag(flag));
+    }
+
+    //check it
+    let items: Vec<&str> = if kind.eq("list") {value.split(',').filter(|item| !item.is_empty()).collect()} else {vec![value]};
+    let mut matched: Vec<String> = Vec::with_capacity(items.len());
+    for item in items {
+        let matched_item: String = match_allowed_value(item, flag, option.get_allowed_values().map_or(&[], |allowed| allowed.as_slice()), option.get_ignore_case().unwrap_or_default(), &mut 1)
+            .map_err(|e| ParseError::new(ParseErrorKind::InvalidValue, Some(value_index), &e.to_string()).with_flag(flag))?;
+        if let Some(Err(e)) = option.get_value_parser().map(|parser| parser.check(&matched_item)) {
+            return Err(ParseError::new(ParseErrorKind::InvalidValue, Some(value_index), &format!("User Error: invalid value '{}' for flag({}): {}", matched_item, flag, e)).with_flag(flag));
+        }
+        matched.push(matched_item);
+    }
+
+    Ok(ParsedItem::Flag { arg_index, option_index, flag: flag.to_string(), value: Some(matched.join(",")) })
+}
+
+/// returns the indices of the present options in `results`, ordered by where their flags first occur in `args` before any `--` (after tidying them as `settings` would),
+/// options that are present without their flag being given (ei. set by a preset) come last, in the order they're in `results`
+pub(crate) fn order_by_first_occurrence(args: &[String], results: &[option_args::ClOption], settings: &ParserSettings) -> Vec<usize> {
+    //DATA
+    let valid_flags: HashSet<&str> = get_valid_flags(results);
+    let tokenizer: Tokenizer = Tokenizer::new(results);
+    let tidied_args: Vec<String> = before_terminator(args).iter().skip(1).map(|arg| tidy_arg(arg, &valid_flags, settings)).collect();
+
+    let mut present: Vec<(Option<usize>, usize)> = results.iter().enumerate()
+    .filter(|(_, option)| option.get_present())
+    .map(|(index, _)| (tidied_args.iter().position(|arg| tokenizer.has_flag_of(arg, index)), index))
+    .collect();
+
+    //none sorts before some, but options that weren't given go last
+    present.sort_by_key(|(first_occurrence, index)| (first_occurrence.is_none(), *first_occurrence, *index));
+    present.into_iter().map(|(_, index)| index).collect()
+}
+
+/// returns a warning for every flag in `args` that's a valid flag of an option in `valid_options` with the wrong number of dashes,
+/// ei. `-recursive` instead of `--recursive`, or `--r` instead of `-r`
+/// 
+/// these are errors by default, and accepted (with these warnings) with `ParserSettings::correct_dash_mistakes(true)`
+/// 
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag(&ClOptionInfo::new("-v", "", "Print more").unwrap()),
+///     ];
+///     let args: Vec<String> = ["foo.exe", "-recursive", "--v", "-r"].iter().map(|arg| arg.to_string()).collect();
+///     
+///     assert_eq!(option_parser::find_dash_mistakes(&args, &valid_options), vec![
+///         String::from("found '-recursive'; long options need two dashes: '--recursive'"),
+///         String::from("found '--v'; short options need one dash: '-v'"),
+///     ]);
+/// ```
+/// 
+/// how they're handled by the Parser
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings, Parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag(&ClOptionInfo::new("-v", "", "Print more").unwrap()),
+///     ];
+///     let to_args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+///     
+///     //by default they're errors, with targeted messages
+///     let error = Parser::new(&to_args(&["foo.exe", "-recursive"]), &valid_options, &[]).err().unwrap();
+///     assert_eq!(error.to_string(), "User Error: found '-recursive'; long options need two dashes: '--recursive'");
+///     let error = Parser::new(&to_args(&["foo.exe", "--v"]), &valid_options, &[]).err().unwrap();
+///     assert_eq!(error.to_string(), "User Error: found '--v'; short options need one dash: '-v'");
+///     
+///     //genuinely unknown flags still get the generic error
+///     let error = Parser::new(&to_args(&["foo.exe", "-quiet"]), &valid_options, &[]).err().unwrap();
+///     assert_eq!(error.to_string(), "User Error: unknown options: '-quiet' (arg 1)");
+///     
+///     //or they can be accepted, with a warning
+///     let settings = ParserSettings::new().correct_dash_mistakes(true);
+///     let parser = Parser::new_with_settings(&to_args(&["foo.exe", "-recursive"]), &valid_options, &[], &settings).unwrap();
+///     assert!(parser.get_option_arguments_found()[0].get_present());
+///     assert_eq!(parser.get_warnings(), &vec![String::from("found '-recursive'; long options need two dashes: '--recursive'")]);
+/// ```
+pub fn find_dash_mistakes(args: &[String], valid_options: &[option_args::ClOption]) -> Vec<String> {
+    let valid_flags: HashSet<&str> = get_valid_flags(valid_options);
+    before_terminator(args).iter().skip(1).filter_map(|arg| find_dash_mistake(arg, &valid_flags)).map(|(_, message)| message).collect()
+}
+
+/// returns the corrected flag, and a message explaining the mistake, if `arg` isn't a valid flag but is one with the wrong number of dashes
+pub(crate) fn find_dash_mistake(arg: &str, valid_flags: &HashSet<&str>) -> Option<(String, String)> {
+    if valid_flags.contains(arg) {
+        return None;
+    }
+
+    //a long flag with a single dash, ei. -recursive
+    if arg.starts_with('-') && !arg.starts_with("--") && arg.chars().count() > 2 && valid_flags.contains(format!("-{}", arg).as_str()) {
+        let corrected: String = format!("-{}", arg);
+        return Some((corrected.clone(), format!("found '{}'; long options need two dashes: '{}'", arg, corrected)));
+    }
+
+    //a short flag with two dashes, ei. --r
+    if let Some(short_flag) = arg.strip_prefix('-').filter(|flag| flag.starts_with('-') && flag.chars().count() == 2 && valid_flags.contains(flag)) {
+        return Some((short_flag.to_string(), format!("found '{}'; short options need one dash: '{}'", arg, short_flag)));
+    }
+
+    None
+}
+
+/// returns the flag `arg` was meant to be, and a message explaining the mistake, if `arg` starts with dashes that aren't ASCII (ei. `–recursive` with an en dash, as word processors type `--`),
+/// followed by a letter or digit, so a dash in the middle of an arg, or dashes on their own, are never a mistake
+/// 
+/// the flag is the first of `arg` with each of its dashes made a `-`, with two dashes, or with one, that's in `valid_flags`,
+/// or the first of them if none are, so it's an unknown flag
+fn find_unicode_dash(arg: &str, valid_flags: &HashSet<&str>) -> Option<(String, String)> {
+    //DATA
+    let is_dash = |c: char| matches!(c, '-' | '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE63}' | '\u{FF0D}');
+    let rest: &str = arg.trim_start_matches(is_dash);
+    let dashes: &str = &arg[..arg.len() - rest.len()];
+    let unicode_dash: char = dashes.chars().find(|c| *c != '-')?;
+    if !rest.starts_with(|c: char| c.is_alphanumeric()) {
+        return None;
+    }
+
+    let candidates: [String; 3] = [format!("{}{}", "-".repeat(dashes.chars().count()), rest), format!("--{}", rest), format!("-{}", rest)];
+    let meant: Option<&String> = candidates.iter().find(|candidate| valid_flags.contains(candidate.as_str()));
+    let message: String = match meant {
+        Some(meant) => format!("'{}' begins with a non-ASCII dash (U+{:04X}); did you mean '{}'?", arg, unicode_dash as u32, meant),
+        None => format!("'{}' begins with a non-ASCII dash (U+{:04X}); flags start with '-'", arg, unicode_dash as u32),
+    };
+    Some((meant.unwrap_or(&candidates[0]).clone(), message))
+}
+
+/// returns the indices of the flags in `args` typed with dashes that aren't ASCII (see `find_unicode_dash()`),
+/// except the program name, and the list/data of flags of options in `valid_options`
+pub(crate) fn find_unicode_dash_flags(args: &[String], valid_options: &[option_args::ClOption]) -> Vec<usize> {
+    let valid_flags: HashSet<&str> = get_valid_flags(valid_options);
+    let tokenizer: Tokenizer = Tokenizer::new(valid_options);
+    (1..args.len()).filter(|index| tokenizer.find_value_taker(&args[index - 1]).is_none() && find_unicode_dash(&args[*index], &valid_flags).is_some()).collect()
+}
+
+/// returns `args`, with the flags typed with dashes that aren't ASCII replaced with the flags they were meant to be (see `find_unicode_dash()`),
+/// except the program name, and the list/data of flags of options in `valid_options`, see `ParserSettings::normalize_unicode_dashes()`
+pub(crate) fn normalize_unicode_dashes(args: &[String], valid_options: &[option_args::ClOption]) -> Vec<String> {
+    //DATA
+    let valid_flags: HashSet<&str> = get_valid_flags(valid_options);
+    let tokenizer: Tokenizer = Tokenizer::new(valid_options);
+    let mut normalized: Vec<String> = Vec::with_capacity(args.len());
+
+    for (index, arg) in args.iter().enumerate() {
+        let meant: Option<String> = find_unicode_dash(arg, &valid_flags)
+        .filter(|_| index > 0 && tokenizer.find_value_taker(&normalized[index - 1]).is_none())
+        .map(|(meant, _)| meant);
+        normalized.push(meant.unwrap_or_else(|| arg.clone()));
+    }
+
+    normalized
+}
+
+/// returns the index of the first flag in `args` of the option in `valid_options` whose long flag is `--help`,
+/// none if it wasn't given before any `--` (see `find_terminator()`), the program name is never the help flag
+pub(crate) fn find_help_flag(args: &[String], valid_options: &[option_args::ClOption]) -> Option<usize> {
+    let help: usize = valid_options.iter().position(|option| option.get_long_flag().eq("--help"))?;
+    let tokenizer: Tokenizer = Tokenizer::new(valid_options);
+    (1..before_terminator(args).len()).find(|index| tokenizer.has_flag_of(&args[*index], help))
+}
+
+/// returns `arg` without trailing whitespace if it's a flag, see `ParserSettings::trim_flag_whitespace()`
+pub(crate) fn trim_flag(arg: &str) -> &str {
+    if arg.starts_with('-') {arg.trim_end()} else {arg}
+}
+
+/// returns `arg` tidied as `settings` say to before parsing, trimmed (see `option_parser::trim_flag()`), then with its dashes corrected (see `find_dash_mistake()`)
+pub(crate) fn tidy_arg(arg: &str, valid_flags: &HashSet<&str>, settings: &ParserSettings) -> String {
+    let arg: &str = if settings.get_trim_flag_whitespace() {trim_flag(arg)} else {arg};
+    match find_dash_mistake(arg, valid_flags) {
+        Some((corrected, _)) if settings.get_correct_dash_mistakes() => corrected,
+        _ => arg.to_string(),
+    }
+}
+
+/// returns a message explaining the mistake, if the flag at `index` in `args` is an option of a subcommand (see `ParserSettings::subcommand_options()`)
+/// that's given later in `args`
+fn find_misplaced_subcommand_option(args: &[String], index: usize, settings: &ParserSettings) -> Option<String> {
+    settings.get_all_subcommand_options().iter()
+    .filter(|(_, options)| options.iter().any(|option| option.has_flag(&args[index])))
+    .find(|(subcommand, _)| args[index..].iter().any(|arg| arg.eq(subcommand)))
+    .map(|(subcommand, _)| format!("'{}' is an option of the '{}' subcommand; place it after '{}'", args[index], subcommand, subcommand))
+}
+
+/// returns the set of every (non-empty) short and long flag of the options in `valid_options`
+pub(crate) fn get_valid_flags(valid_options: &[option_args::ClOption]) -> HashSet<&str> {
+    //every option has at most 2 flags
+    let mut valid_flags: HashSet<&str> = HashSet::with_capacity(valid_options.len() * 2);
+    valid_flags.extend(
+        valid_options.iter()
+        .flat_map(|option| [option.get_short_flag(), option.get_long_flag()])
+        .filter(|flag| !flag.is_empty())
+    );
+    valid_flags
 }
 
 /// gets the list after flag from command line arguments (args), if there is one
@@ -127,11 +1176,16 @@ pub fn parse_for_options(args: &[String], valid_options: &[option_args::ClOption
 /// # Note
 /// - you probably don't need to use this, try option_parser::parse_for_options() unless you know you need this
 /// - when using this, ensure that the returned list is as expected, as shown in examples, it will attempt to make a list out of whatever valid argument follows it
+/// - this finds the list the same way `option_parser::parse_for_options()` does: the arg after the first occurrence of `flag`, on its own or as the last flag of a cluster (ei. `-rf list`),
+///   or the text after the `=` if it's given as `--flag=list`, neither recognize `-flist`, so that's never found
+/// - since only `flag` is known, every other letter in a cluster with it is taken to be a short flag that doesn't take a list/data
 /// 
 /// # Errors
 /// - flag is not in args
 /// - flag is last element in args
 /// - element following flag in args starts with a `-` (is another flag)
+/// - flag is given as `--flag=`, with nothing after the `=`
+/// - flag is in a cluster of short flags, but isn't the last of them
 /// 
 /// # Examples
 /// ```
@@ -141,6 +1195,12 @@ pub fn parse_for_options(args: &[String], valid_options: &[option_args::ClOption
 ///     
 ///     assert!( option_parser::get_list_after_flag(&args, "--your-flag").is_ok() );
 ///     assert_eq!( option_parser::get_list_after_flag(&args, "--your-flag").unwrap(), vec!["your", "list"]);
+///     
+///     let args = vec![String::from("--your-flag=your,list")];
+///     assert_eq!( option_parser::get_list_after_flag(&args, "--your-flag").unwrap(), vec!["your", "list"]);
+///     
+///     let args = vec![String::from("-rf"),String::from("your,list")];
+///     assert_eq!( option_parser::get_list_after_flag(&args, "-f").unwrap(), vec!["your", "list"]);
 /// ```
 /// 
 /// some cases where it will fail
@@ -159,44 +1219,128 @@ pub fn parse_for_options(args: &[String], valid_options: &[option_args::ClOption
 ///     assert_eq!(option_parser::get_list_after_flag(&comma_separated, "--your-flag").unwrap(),                   vec!["your", "list"]);
 ///     assert_eq!(option_parser::get_list_after_flag(&wrong_list, "--your-flag").unwrap(),                        vec!["NotYourList"]);
 /// ```
-pub fn get_list_after_flag<'a>(args: &[String], flag: &'a str) -> Result<Vec<String>,Box<dyn Error>> {
+pub fn get_list_after_flag(args: &[String], flag: &str) -> Result<Vec<String>,Box<dyn Error>> {
+    let mut items: Vec<&str> = Vec::new();
+    split_list(args, flag, find_value_of_flag(args, flag)?, false, &mut items)?;
+    Ok(items.iter().map(|item| item.to_string()).collect())
+}
+
+/// gets the list after flag from command line arguments (args), like `option_parser::get_list_after_flag()`,
+/// but a list that starts with `[` is space separated, and ends at the `]` that closes it
+/// 
+/// # Note
+/// - you probably don't need to use this, try option_parser::parse_for_options() unless you know you need this
+/// - a bracketed list has to be in one arg, the parser joins bracketed lists that are spread across args before finding them, see `ClOption::bracketed_lists()`
+/// 
+/// # Errors
+/// - the same as `option_parser::get_list_after_flag()`
+/// - the list starts with `[`, but doesn't end with `]`
+/// 
+/// # Examples
+/// ```
+/// use clia::option_parser;
+/// //...
+///     let args = vec![String::from("--your-flag"), String::from("[your list]")];
+///     assert_eq!(option_parser::get_bracketed_list_after_flag(&args, "--your-flag").unwrap(), vec!["your", "list"]);
+///     
+///     let args = vec![String::from("--your-flag"), String::from("your,list")];
+///     assert_eq!(option_parser::get_bracketed_list_after_flag(&args, "--your-flag").unwrap(), vec!["your", "list"]);
+///     
+///     let args = vec![String::from("--your-flag"), String::from("[]")];
+///     assert!(option_parser::get_bracketed_list_after_flag(&args, "--your-flag").unwrap().is_empty());
+///     
+///     let args = vec![String::from("-rf"), String::from("[your list]")];
+///     assert_eq!(option_parser::get_bracketed_list_after_flag(&args, "-f").unwrap(), vec!["your", "list"]);
+///     
+///     let args = vec![String::from("--your-flag"), String::from("[your list")];
+///     assert_eq!(
+///         option_parser::get_bracketed_list_after_flag(&args, "--your-flag").unwrap_err().to_string(),
+///         "No closing ']' found for the list after flag(--your-flag) in args([\"--your-flag\", \"[your list\"])"
+///     );
+/// ```
+pub fn get_bracketed_list_after_flag(args: &[String], flag: &str) -> Result<Vec<String>,Box<dyn Error>> {
+    let mut items: Vec<&str> = Vec::new();
+    split_list(args, flag, find_value_of_flag(args, flag)?, true, &mut items)?;
+    Ok(items.iter().map(|item| item.to_string()).collect())
+}
+
+/// splits `list`, the list after flag in args, into `items`, replacing what was in it but reusing its storage,
+/// like `get_list_after_flag()`, or `get_bracketed_list_after_flag()` if `bracketed`, but without allocating the items
+fn split_list<'a>(args: &[String], flag: &str, list: &'a str, bracketed: bool, items: &mut Vec<&'a str>) -> Result<(), Box<dyn Error>> {
     //DATA
-    let flag_position:usize;
-    let arg_after_flag: String;
     let list_separator:char = ',';
-    //find the position of the flag
-    match args.iter().position(|arg| arg.eq(&flag)).ok_or(format!("Could not find flag({}) in args({:?})",flag,args).into()) {
-        Ok(pos) => flag_position = pos,
-        Err(e) => return Err(e),
+
+    items.clear();
+    match list.strip_prefix('[').filter(|_| bracketed) {
+        Some(inside) => match inside.strip_suffix(']') {
+            Some(inside) => items.extend(inside.split_whitespace()),
+            None => return Err(format!("No closing ']' found for the list after flag({}) in args({:?})", flag, args).into()),
+        },
+        None => items.extend(list.split(list_separator).filter(|item| !item.is_empty())), //split the string up at list_separators, and remove empty items
     }
+    Ok(())
+}
 
-    //if there is no list after the flag (no more arguments or next argument is another flag)
-    //flag is at end of list
-    match args.get(flag_position+1) {
-        Some(arg) => arg_after_flag = arg.clone(),
-        None => return Err(format!("No arguments after flag({}) in args({:?})", flag, args).into()),
+/// returns `args`, with the bracketed lists that are spread across args after the flags of options in `valid_options` that take them (see `ClOption::bracketed_lists()`)
+/// joined into one arg, with a space between each ei. `-f`, `[a`, `b`, `c]` becomes `-f`, `[a b c]`, or after an `=` ei. `--filter=[a`, `b]` becomes `--filter=[a b]`,
+/// and the index in `args` each of the returned args came from, flags are matched after tidying them as `settings` say to
+pub(crate) fn join_bracketed_lists(args: &[String], valid_options: &[option_args::ClOption], settings: &ParserSettings) -> (Vec<String>, Vec<usize>) {
+    //DATA
+    let valid_flags: HashSet<&str> = get_valid_flags(valid_options);
+    let tokenizer: Tokenizer = Tokenizer::new(valid_options);
+    let bracketed = |option: usize| valid_options[option].get_bracketed_lists() == Some(true);
+    let mut joined: Vec<String> = Vec::with_capacity(args.len());
+    let mut origins: Vec<usize> = Vec::with_capacity(args.len());
+    let mut args = args.iter().enumerate();
+
+    //the program name is never the flag of a list
+    if let Some((index, arg)) = args.next() {
+        joined.push(arg.clone());
+        origins.push(index);
     }
-    //arg following the flag is another flag
-    if arg_after_flag.starts_with("-") {
-        return Err(format!("No list found after flag({}) in args({:?})",flag,args).into());
+    while let Some((index, arg)) = args.next() {
+        //the list starts after the '=' of the flag, or is the next arg
+        let tidied: String = tidy_arg(arg, &valid_flags, settings);
+        let (mut list, list_index, opened): (String, usize, bool) = match tokenizer.split(&tidied) {
+            Token::Equals(flag, value) if tokenizer.get_option(flag).is_some_and(bracketed) => (arg.clone(), index, value.starts_with('[')),
+            _ => {
+                joined.push(arg.clone());
+                origins.push(index);
+                if !tokenizer.find_value_taker(&tidied).is_some_and(bracketed) {
+                    continue;
+                }
+                let Some((index, first)) = args.next() else {break};
+                (first.clone(), index, first.starts_with('['))
+            },
+        };
+
+        //the args up to the one that closes the list, or the end of args if none does
+        while opened && !list.ends_with(']') {
+            let Some((_, next)) = args.next() else {break};
+            list.push(' ');
+            list.push_str(next);
+        }
+        joined.push(list);
+        origins.push(list_index);
     }
 
-    //create and return list from arg_after_flag
-    return Ok(
-        arg_after_flag.split(list_separator) //split the string up at list_separators
-        .filter_map(|item| (!item.is_empty()).then(|| item.to_string())).collect() //remove empty items, convert parameters to Strings, and collect
-    );
+    (joined, origins)
 }
 
 /// gets the data after flag from command line arguments (args), if there is one
 /// 
 /// # Note
 /// - you probably don't need to use this, try option_parser::parse_for_options() unless you know you need this
+/// - this finds the data the same way `option_parser::parse_for_options()` does: the arg after the first occurrence of `flag`, on its own or as the last flag of a cluster (ei. `-rf data`),
+///   or the text after the `=` if it's given as `--flag=data`, neither recognize `-fdata`, so that's never found
+/// - since only `flag` is known, every other letter in a cluster with it is taken to be a short flag that doesn't take a list/data
 /// 
 /// # Errors
 /// - flag is not in args
 /// - flag is last element in args
 /// - element following flag in args starts with a `-` (is another flag)
+/// - flag is given as `--flag=`, with nothing after the `=`
+/// - flag is in a cluster of short flags, but isn't the last of them
 /// 
 /// # Examples
 /// ```
@@ -206,7 +1350,26 @@ pub fn get_list_after_flag<'a>(args: &[String], flag: &'a str) -> Result<Vec<Str
 ///     
 ///     assert!( option_parser::get_data_after_flag(&args, "--your-flag").is_ok() );
 ///     assert_eq!( option_parser::get_data_after_flag(&args, "--your-flag").unwrap(), "your-data" );
-/// 
+///     
+///     //the data can be given after an '=', only the first one separates it from the flag
+///     let args = vec![String::from("--your-flag=key=value"),String::from("not-your-data")];
+///     assert_eq!( option_parser::get_data_after_flag(&args, "--your-flag").unwrap(), "key=value" );
+///     
+///     let args = vec![String::from("--your-flag="),String::from("not-your-data")];
+///     assert_eq!(
+///         option_parser::get_data_after_flag(&args, "--your-flag").unwrap_err().to_string(),
+///         "No value after '=' in flag(--your-flag) in args([\"--your-flag=\", \"not-your-data\"])"
+///     );
+///     
+///     //or after a cluster of short flags that ends with the flag
+///     let args = vec![String::from("foo.exe"),String::from("-rF"),String::from("json")];
+///     assert_eq!( option_parser::get_data_after_flag(&args, "-F").unwrap(), "json" );
+///     
+///     let args = vec![String::from("foo.exe"),String::from("-Fr"),String::from("json")];
+///     assert_eq!(
+///         option_parser::get_data_after_flag(&args, "-F").unwrap_err().to_string(),
+///         "flag(-F) in '-Fr' takes a value, so it can only be the last flag of a cluster, in args([\"foo.exe\", \"-Fr\", \"json\"])"
+///     );
 /// ```
 /// 
 /// some cases where it will fail
@@ -223,26 +1386,268 @@ pub fn get_list_after_flag<'a>(args: &[String], flag: &'a str) -> Result<Vec<Str
 ///     assert_eq!(option_parser::get_data_after_flag(&flag_at_end, "--your-flag").unwrap_err().to_string(),       "No arguments after flag(--your-flag) in args([\"Not,Your,Data\", \"your-data\", \"--your-flag\"])");
 ///     assert_eq!(option_parser::get_data_after_flag(&wrong_data, "--your-flag").unwrap(),                        "Not,Your,Data");
 /// ```
-pub fn get_data_after_flag<'a>(args: &[String], flag: &'a str) -> Result<String,Box<dyn Error>> {
+/// 
+/// it agrees with `option_parser::parse_for_options()`, for every form of a flag
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, option_parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to count").unwrap(), "EXTENSIONS"),
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///     ];
+///     let cases: [(&[&str], bool); 12] = [
+///         (&["foo.exe", "--output", "a.txt", "-f", "rs,py"], true), //separate args
+///         (&["foo.exe", "-o", "a.txt", "-o", "b.txt"], true),       //repeated, the first wins
+///         (&["foo.exe", "--output=a.txt", "--filter=rs,py"], true), //= form
+///         (&["foo.exe", "--output=a.txt", "--output", "b.txt"], true),
+///         (&["foo.exe", "-ro", "a.txt", "-rf", "rs,py"], true),     //last flag of a cluster
+///         (&["foo.exe", "-rro", "a.txt", "-o", "b.txt"], true),
+///         (&["foo.exe", "-rf", "rs,py", "--output=-r"], true),
+///         (&["foo.exe", "-of", "a.txt"], false),                    //only the last flag of a cluster can take a value
+///         (&["foo.exe", "-oa.txt", "-frs,py"], false),              //attached form, unknown flags
+///         (&["foo.exe", "-o=a.txt"], false),                        //= form only works for long flags
+///         (&["foo.exe", "-ro"], false),                             //missing
+///         (&["foo.exe", "-f"], false),
+///     ];
+///     
+///     for (case, parses) in cases {
+///         let args: Vec<String> = case.iter().map(|arg| arg.to_string()).collect();
+///         let data = ["-o", "--output"].iter().find_map(|flag| option_parser::get_data_after_flag(&args, flag).ok());
+///         let list = ["-f", "--filter"].iter().find_map(|flag| option_parser::get_list_after_flag(&args, flag).ok());
+///         
+///         match option_parser::parse_for_options(&args, &valid_options) {
+///             Ok(found) => {
+///                 assert!(parses, "{:?}", case);
+///                 assert_eq!(found[0].get_data_opt().map(|data| data.to_string()), data, "{:?}", case);
+///                 assert_eq!(found[1].get_present().then(|| found[1].get_list().unwrap().clone()), list, "{:?}", case);
+///             },
+///             //and neither finds the values attached to short flags, or of a flag in the middle of a cluster
+///             Err(_) => {
+///                 assert!(!parses, "{:?}", case);
+///                 assert_ne!(data.as_deref(), Some("a.txt"), "{:?}", case);
+///             },
+///         }
+///     }
+/// ```
+pub fn get_data_after_flag(args: &[String], flag: &str) -> Result<String,Box<dyn Error>> {
+    Ok(find_value_of_flag(args, flag)?.to_string())
+}
+
+/// returns the list/data of the first occurrence of `flag` in `args`, split from them like the parser does (see `Tokenizer::for_flag()`):
+/// the arg after `flag` on its own or at the end of a cluster, or the text after the `=` in `flag=value` if `flag` is a long flag,
+/// with the errors of `option_parser::get_data_after_flag()`
+fn find_value_of_flag<'a>(args: &'a [String], flag: &str) -> Result<&'a str,Box<dyn Error>> {
     //DATA
-    let flag_position:usize;
-    let arg_after_flag: String;
-    //find the position of the flag
-    match args.iter().position(|arg| arg.eq(&flag)).ok_or(format!("Could not find flag({}) in args({:?})",flag,args).into()) {
-        Ok(pos) => flag_position = pos,
-        Err(e) => return Err(e),
+    let tokenizer: Tokenizer = Tokenizer::for_flag(flag);
+    let Some(flag_position) = args.iter().position(|arg| tokenizer.has_flag_of(arg, 0)) else {
+        return Err(format!("Could not find flag({}) in args({:?})",flag,args).into());
+    };
+
+    match tokenizer.split(&args[flag_position]) {
+        //the value after '=' can't be empty
+        Token::Equals(_, "") => Err(format!("No value after '=' in flag({}) in args({:?})", flag, args).into()),
+        Token::Equals(_, value) => Ok(value),
+        _ if tokenizer.find_misplaced(&args[flag_position]).is_some() => Err(format!("flag({}) in '{}' takes a value, so it can only be the last flag of a cluster, in args({:?})", flag, args[flag_position], args).into()),
+        _ => find_value_at(args, flag, flag_position),
     }
+}
+
 
-    //if there is no data after the flag (no more arguments or next argument is another flag)
+/// returns the list/data of the occurrence of `flag` at `flag_position` in `args`, the arg after it,
+/// with the errors of `option_parser::get_data_after_flag()`
+fn find_value_at<'a>(args: &'a [String], flag: &str, flag_position: usize) -> Result<&'a str,Box<dyn Error>> {
+    //if there is no value after the flag (no more arguments or next argument is another flag)
     //flag is at end of list
-    match args.get(flag_position+1) {
-        Some(arg) => arg_after_flag = arg.clone(),
+    let arg_after_flag: &str = match args.get(flag_position+1) {
+        Some(arg) => arg,
         None => return Err(format!("No arguments after flag({}) in args({:?})", flag, args).into()),
-    }
+    };
     //arg following the flag is another flag
-    if arg_after_flag.starts_with("-") {
+    if is_flag(arg_after_flag) {
         return Err(format!("No list found after flag({}) in args({:?})",flag,args).into());
     }
 
-    return Ok(arg_after_flag);
+    Ok(arg_after_flag)
+}
+
+/// returns every letter of `arg` that isn't a short flag in `valid_flags`, once each, ei. `'-z', '-q' aren't short flags`, if it looks like a cluster of short flags (see `Tokenizer`),
+/// so the user knows which letters to fix, it looks like one if any of its letters is a short flag
+fn find_unknown_in_cluster(arg: &str, valid_flags: &HashSet<&str>) -> Option<String> {
+    let letters: &str = arg.strip_prefix('-').filter(|letters| letters.len() > 1 && letters.chars().all(|letter| letter.is_ascii_alphabetic()))?;
+    let (known, mut unknown): (Vec<String>, Vec<String>) = letters.chars().map(|letter| format!("-{}", letter)).partition(|flag| valid_flags.contains(flag.as_str()));
+    if unknown.is_empty() || known.is_empty() {
+        return None;
+    }
+    let mut seen: HashSet<String> = HashSet::with_capacity(unknown.len());
+    unknown.retain(|flag| seen.insert(flag.clone()));
+    let unknown: String = unknown.iter().map(|flag| format!("'{}'", flag)).collect::<Vec<String>>().join(", ");
+    Some(if unknown.contains(',') {format!("{} aren't short flags", unknown)} else {format!("{} isn't a short flag", unknown)})
+}
+
+/// returns the error for the `unknown` flags, and the index of the arg each was given in, in the order they were given,
+/// every one in one message, with the letters of a cluster that aren't short flags in `valid_flags` (see `find_unknown_in_cluster()`),
+/// so the parser, `option_parser::parse_iter()` and `Parser::assert_no_unknown_flags()` word it the same
+pub(crate) fn unknown_flags_error(unknown: &[(usize, &str)], valid_flags: &HashSet<&str>) -> ParseError {
+    ParseError::new(
+        ParseErrorKind::UnknownFlag,
+        unknown.first().map(|(index, _)| *index),
+        &format!("User Error: unknown options: {}", unknown.iter().map(|(index, arg)| match find_unknown_in_cluster(arg, valid_flags) {
+            Some(letters) => format!("'{}' (arg {}, {})", arg, index, letters),
+            None => format!("'{}' (arg {})", arg, index),
+        }).collect::<Vec<String>>().join(", ")),
+    ).with_flag(unknown.first().map_or("", |(_, arg)| arg))
+}
+
+/// what an arg is made of, see `Tokenizer::split()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    /// an arg that isn't a flag, ei. a parameter, or the list/data of the flag before it
+    Positional,
+    /// a flag on its own, of an option or not
+    Flag(&'a str),
+    /// a long flag of an option that takes a list/data, and the list/data given it after an `=`, which is never a flag, even if it starts with a `-`
+    Equals(&'a str, &'a str),
+    /// a cluster of short flags of options, the letters after its dash
+    Cluster(&'a str),
+}
+
+/// splits args into the flags and values they're made of, by the rules the parser and the `Classifier` share, so they always agree:
+/// - a flag of an option is a flag, even if it looks like a cluster
+/// - a long flag of an option that takes a list/data can be given it after an `=`, ei. `--format=json`, only the first `=` splits, so the value can contain more of them
+/// - a long flag with one dash is a dash mistake (see `find_dash_mistake()`), never a cluster
+/// - a dash followed by letters that are all short flags of options is a cluster of them, ei. `-rf`,
+///   only the last can take a list/data, which is the arg after the cluster as usual
+/// 
+/// args are never rewritten, so what the parser reports is where the user typed it, and every form of every flag is found up front, so splitting an arg doesn't allocate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Tokenizer {
+    flags: HashMap<String, usize>,
+    mistakes: HashSet<String>,
+    short_flags: HashMap<char, usize>,
+    short_names: Vec<String>,
+    takes_value: Vec<bool>,
+    other_letters: Option<usize>,
+}
+impl Tokenizer {
+    /// creates a new Tokenizer for `valid_options`, if more than one option has a flag, it's a flag of the first of them
+    pub(crate) fn new(valid_options: &[option_args::ClOption]) -> Tokenizer {
+        //DATA
+        let valid_flags: HashSet<&str> = get_valid_flags(valid_options);
+        let mut flags: HashMap<String, usize> = HashMap::with_capacity(valid_flags.len());
+        let mut short_flags: HashMap<char, usize> = HashMap::with_capacity(valid_options.len());
+
+        for (index, option) in valid_options.iter().enumerate() {
+            for flag in [option.get_short_flag(), option.get_long_flag()].into_iter().filter(|flag| !flag.is_empty()) {
+                flags.entry(flag.to_string()).or_insert(index);
+            }
+            if let Some(letter) = option.get_short_flag().strip_prefix('-').and_then(|letter| letter.chars().next()) {
+                short_flags.entry(letter).or_insert(index);
+            }
+        }
+
+        Tokenizer {
+            flags,
+            //the long flags with one dash
+            mistakes: valid_flags.iter().filter_map(|flag| flag.strip_prefix('-')).filter(|mistake| find_dash_mistake(mistake, &valid_flags).is_some()).map(|mistake| mistake.to_string()).collect(),
+            short_flags,
+            short_names: valid_options.iter().map(|option| option.get_short_flag().to_string()).collect(),
+            takes_value: valid_options.iter().map(|option| matches!(option, option_args::ClOption::FlagList { .. } | option_args::ClOption::FlagData { .. })).collect(),
+            other_letters: None,
+        }
+    }
+
+    /// creates a new Tokenizer for when only `flag` is known, and takes a list/data,
+    /// every other letter is a short flag that doesn't, since there's no telling, see `option_parser::get_data_after_flag()`
+    pub(crate) fn for_flag(flag: &str) -> Tokenizer {
+        Tokenizer {
+            flags: HashMap::from([(flag.to_string(), 0)]),
+            mistakes: HashSet::new(),
+            short_flags: flag.strip_prefix('-').filter(|letter| letter.chars().count() == 1).and_then(|letter| letter.chars().next()).map(|letter| (letter, 0)).into_iter().collect(),
+            short_names: vec![flag.to_string(), String::new()],
+            takes_value: vec![true, false],
+            other_letters: Some(1),
+        }
+    }
+
+
+    /// returns what `arg` is made of
+    pub(crate) fn split<'a>(&self, arg: &'a str) -> Token<'a> {
+        if !is_flag(arg) {
+            return Token::Positional;
+        }
+        if self.flags.contains_key(arg) || self.mistakes.contains(arg) {
+            return Token::Flag(arg);
+        }
+        if let Some((flag, value)) = arg.split_once('=').filter(|(flag, _)| flag.starts_with("--") && self.get_option(flag).is_some_and(|option| self.takes_value[option])) {
+            return Token::Equals(flag, value);
+        }
+        match arg[1..].len() > 1 && arg[1..].chars().all(|letter| letter.is_ascii_alphabetic() && self.get_letter_option(letter).is_some()) {
+            true => Token::Cluster(&arg[1..]),
+            false => Token::Flag(arg),
+        }
+    }
+
+    /// returns the index of the option `flag` is a flag of, if it's a flag of one
+    pub(crate) fn get_option(&self, flag: &str) -> Option<usize> {self.flags.get(flag).copied()}
+
+    /// returns the index of the option `letter` is the short flag of, if it's the short flag of one, see `Tokenizer::for_flag()`
+    pub(crate) fn get_letter_option(&self, letter: char) -> Option<usize> {self.short_flags.get(&letter).copied().or(self.other_letters)}
+
+    /// returns how many options there are
+    pub(crate) fn get_option_count(&self) -> usize {self.takes_value.len()}
+
+    /// returns true if the option at `option` takes a list/data
+    pub(crate) fn takes_value(&self, option: usize) -> bool {self.takes_value[option]}
+
+    /// calls `found` with the index of the option of every flag of an option in `arg`, and the flag as it was typed, in order,
+    /// more than one if it's a cluster of short flags, whose flags are typed as their short flags
+    pub(crate) fn for_each_flag<'a>(&'a self, arg: &'a str, mut found: impl FnMut(usize, &'a str)) {
+        match self.split(arg) {
+            Token::Positional => {},
+            Token::Flag(flag) | Token::Equals(flag, _) => if let Some(option) = self.get_option(flag) {
+                found(option, flag);
+            },
+            Token::Cluster(letters) => for option in letters.chars().filter_map(|letter| self.get_letter_option(letter)) {
+                found(option, &self.short_names[option]);
+            },
+        }
+    }
+
+    /// returns true if `arg` is a flag, but not of an option, a cluster of them, or one given its list/data after an `=`
+    pub(crate) fn is_unknown_flag(&self, arg: &str) -> bool {
+        matches!(self.split(arg), Token::Flag(flag) if self.get_option(flag).is_none())
+    }
+
+    /// returns true if `arg` has a flag of the option at `option`, on its own, in a cluster, or given its list/data after an `=`
+    pub(crate) fn has_flag_of(&self, arg: &str, option: usize) -> bool {
+        let mut found: bool = false;
+        self.for_each_flag(arg, |flag_option, _| found |= flag_option == option);
+        found
+    }
+
+    /// returns the index of the option whose list/data is the arg after `arg`, if `arg` is a flag of one that takes a list/data, or a cluster with one,
+    /// the first of them if there's more than one, which is an error (see `Tokenizer::find_misplaced()`) but still takes the arg after it
+    pub(crate) fn find_value_taker(&self, arg: &str) -> Option<usize> {
+        match self.split(arg) {
+            Token::Flag(flag) => self.get_option(flag).filter(|option| self.takes_value[*option]),
+            Token::Cluster(letters) => letters.chars().filter_map(|letter| self.get_letter_option(letter)).find(|option| self.takes_value[*option]),
+            Token::Positional | Token::Equals(..) => None,
+        }
+    }
+
+    /// returns the index of the option of a flag in `arg` that takes a list/data, but isn't the last flag of the cluster `arg` is, which is an error
+    pub(crate) fn find_misplaced(&self, arg: &str) -> Option<usize> {
+        let Token::Cluster(letters) = self.split(arg) else {return None};
+        let mut options = letters.chars().filter_map(|letter| self.get_letter_option(letter)).peekable();
+        while let Some(option) = options.next() {
+            if self.takes_value[option] && options.peek().is_some() {
+                return Some(option);
+            }
+        }
+        None
+    }
+
+    /// returns the short flag of the option at `option`, as it's typed in a cluster
+    pub(crate) fn get_short_name(&self, option: usize) -> &str {&self.short_names[option]}
 }
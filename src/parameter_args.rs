@@ -18,12 +18,22 @@
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_doc_code_examples)]
 
+use crate::help;
+use crate::value_parser::ValueParser;
+
 /// stores data related to parameter arguments
 #[derive(Clone, Debug, PartialEq)]
 pub struct ClParameter {
     name: String,
     description: String,
     data: String,
+    optional: bool,
+    variadic: bool,
+    values: Vec<String>,
+    value_parser: Option<ValueParser>,
+    stdin_if_dash: bool,
+    from_stdin: bool,
+    raw_data: String,
 }
 impl ClParameter {
     /// creates a new ClParameter with the given info
@@ -31,6 +41,8 @@ impl ClParameter {
     /// `name` is the name of this Argument
     /// `description` is the description for this Argument. what is it? what is it for?
     /// 
+    /// the parameter is required, see `ClParameter::optional()` for one that doesn't have to be given
+    /// 
     /// # Examples
     /// ```
     /// use clia::parameter_args::ClParameter;
@@ -44,13 +56,198 @@ impl ClParameter {
     /// assert_eq!(example_parameter.get_data(), "");
     /// ```
     pub fn new(name: &str, description: &str) -> ClParameter {
-        let arg = ClParameter {
+        ClParameter {
             name: name.to_string().to_ascii_uppercase(),
             description: description.to_string(),
             data: String::new(),
-        };
+            optional: false,
+            variadic: false,
+            values: Vec::new(),
+            value_parser: None,
+            stdin_if_dash: false,
+            from_stdin: false,
+            raw_data: String::new(),
+        }
+    }
+
+    /// creates a new optional ClParameter, the same as `ClParameter::new(name, description).optional(true)`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, parameter_parser};
+    /// //...
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("QUERY", "String to search for"), ClParameter::new_optional("PATH", "Path to search in")];
+    ///     assert_eq!(expected_parameters[1], ClParameter::new("PATH", "Path to search in").optional(true));
+    ///     
+    ///     let parse = |args: &[&str]| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         parameter_parser::parse_for_parameters(&args, &expected_parameters)
+    ///     };
+    ///     let parameters = parse(&["foo.exe", "needle"]).unwrap();
+    ///     assert_eq!((parameters[0].get_data(), parameters[1].get_data()), ("needle", ""));
+    ///     let parameters = parse(&["foo.exe", "needle", "src"]).unwrap();
+    ///     assert_eq!((parameters[0].get_data(), parameters[1].get_data()), ("needle", "src"));
+    ///     assert!(parse(&["foo.exe"]).is_err()); //QUERY is still required
+    /// ```
+    pub fn new_optional(name: &str, description: &str) -> ClParameter {
+        ClParameter::new(name, description).optional(true)
+    }
+
+    /// if `optional` is true, this parameter doesn't have to be given, and its data is left empty if it isn't, defaults to false
+    /// 
+    /// optional parameters don't have to be the last ones, see `parameter_parser::parse_for_parameters()` for how the args are assigned to them
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     let parameter = ClParameter::new("PATH", "Path to search in").optional(true);
+    ///     assert!(parameter.get_optional());
+    /// ```
+    /// 
+    /// the Parser knows which args are the data of options, so they aren't mistaken for optional parameters
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT")];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in").optional(true), ClParameter::new("QUERY", "String to search for")];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--format"), String::from("BULLET"), String::from("needle")];
+    ///     
+    ///     let parser = Parser::new(&args, &valid_options, &expected_parameters).unwrap();
+    ///     assert_eq!(parser.get_parameter_arguments_found()[0].get_data(), "");
+    ///     assert_eq!(parser.get_parameter_arguments_found()[1].get_data(), "needle");
+    /// ```
+    pub fn optional(mut self, optional: bool) -> ClParameter {
+        self.optional = optional;
+        self
+    }
+
+    /// if `variadic` is true, this parameter takes every positional the other parameters don't (at least one, unless it's optional too), defaults to false
+    /// 
+    /// the variadic parameter doesn't have to be the last one, ei. `<SRC>... <DEST>`, but there can only be one,
+    /// see `parameter_parser::parse_for_parameters()` for how the args are assigned
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     let parameter = ClParameter::new("SRC", "Files to copy").variadic(true);
+    ///     assert!(parameter.get_variadic());
+    /// ```
+    pub fn variadic(mut self, variadic: bool) -> ClParameter {
+        self.variadic = variadic;
+        self
+    }
+
+    /// checks the data of this parameter with `parser` while parsing, so data that isn't well-formed is an error naming this parameter,
+    /// a variadic parameter has each of its values checked, erroring on the first that isn't well-formed with its index in `get_values()`,
+    /// and optional parameters that aren't given aren't checked
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, parameter_parser, value_parser::ValueParser};
+    /// //...
+    ///     let expected_parameters: Vec<ClParameter> = vec![
+    ///         ClParameter::new("COUNT", "How many results to show").with_value_parser(ValueParser::IntParser),
+    ///         ClParameter::new("QUERY", "String to search for"),
+    ///     ];
+    ///     
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("10"), String::from("abc")];
+    ///     assert_eq!(parameter_parser::parse_for_parameters(&args, &expected_parameters).unwrap()[0].get_data(), "10");
+    ///     
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("abc"), String::from("abc")];
+    ///     assert_eq!(
+    ///         parameter_parser::parse_for_parameters(&args, &expected_parameters).unwrap_err().to_string(),
+    ///         "User Error: invalid value 'abc' for parameter(COUNT): expected an integer"
+    ///     );
+    /// ```
+    /// 
+    /// variadic parameters
+    /// ```
+    /// use clia::{parameter_args::ClParameter, parameter_parser, value_parser::ValueParser};
+    /// //...
+    ///     let expected_parameters: Vec<ClParameter> = vec![
+    ///         ClParameter::new("SRC", "Files to copy").variadic(true).with_value_parser(ValueParser::PathParser { must_exist: true }),
+    ///         ClParameter::new("DEST", "Where to copy them"),
+    ///     ];
+    ///     let parse = |args: &[&str]| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         parameter_parser::parse_for_parameters(&args, &expected_parameters)
+    ///     };
+    ///     
+    ///     //every item is valid
+    ///     assert_eq!(parse(&["cp", "src", "Cargo.toml", "dir"]).unwrap()[0].get_values(), &vec![String::from("src"), String::from("Cargo.toml")]);
+    ///     
+    ///     //the first invalid item is reported, with its index
+    ///     assert_eq!(
+    ///         parse(&["cp", "src", "missing.txt", "also_missing.txt", "dir"]).unwrap_err().to_string(),
+    ///         "User Error: invalid value 'missing.txt' at index 1 of parameter(SRC): expected a path that exists"
+    ///     );
+    /// ```
+    pub fn with_value_parser(mut self, parser: ValueParser) -> ClParameter {
+        self.value_parser = Some(parser);
+        self
+    }
 
-        arg
+    /// if `stdin_if_dash` is true, when this parameter is given a lone `-` its data is replaced with the contents of stdin (without the trailing newline),
+    /// so `echo "needle" | foo.exe path -` works, defaults to false
+    /// 
+    /// the `-` is still available from `ClParameter::get_raw_data()`, and without this a `-` is just data,
+    /// stdin can be replaced with `ParserSettings::stdin_with()`, ei. for tests
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in"), ClParameter::new("QUERY", "String to search for").stdin_if_dash(true)];
+    ///     let settings = ParserSettings::new().stdin_with(|| String::from("needle\n"));
+    ///     let args: Vec<String> = ["foo.exe", "path/to/search", "-"].iter().map(|arg| arg.to_string()).collect();
+    ///     
+    ///     let parser = Parser::new_with_settings(&args, &[], &expected_parameters, &settings).unwrap();
+    ///     assert_eq!(parser.get_parameter_arguments_found()[1].get_data(), "needle");
+    ///     assert_eq!(parser.get_parameter_arguments_found()[1].get_raw_data(), "-");
+    ///     
+    ///     //without opting in, the - is just data
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in"), ClParameter::new("QUERY", "String to search for")];
+    ///     let parser = Parser::new_with_settings(&args, &[], &expected_parameters, &settings).unwrap();
+    ///     assert_eq!(parser.get_parameter_arguments_found()[1].get_data(), "-");
+    /// ```
+    /// 
+    /// a path parameter that reads `-` as stdin itself, as is conventional, accepts it without opting in
+    /// ```
+    /// use clia::{parameter_args::ClParameter, value_parser::ValueParser, Parser};
+    /// //...
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("FILE", "File to search, or - for stdin").with_value_parser(ValueParser::PathParser { must_exist: true })];
+    ///     let args: Vec<String> = ["foo.exe", "-"].iter().map(|arg| arg.to_string()).collect();
+    ///     
+    ///     let parser = Parser::new(&args, &[], &expected_parameters).unwrap();
+    ///     assert_eq!(parser.get_parameter_arguments_found()[0].get_data(), "-");
+    /// ```
+    pub fn stdin_if_dash(mut self, stdin_if_dash: bool) -> ClParameter {
+        self.stdin_if_dash = stdin_if_dash;
+        self
+    }
+
+    /// if `from_stdin` is true, this is the parameter the program reads from stdin when it isn't given, like the file of `grep`, defaults to false
+    /// 
+    /// the parser doesn't read stdin for it, `Parser::wants_stdin_for()` returns it when the program should,
+    /// and it's made optional too, since it doesn't have to be given
+    /// 
+    /// # Notes:
+    /// - only the first parameter that's read from stdin is, if more than one is marked
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     let parameter = ClParameter::new("FILE", "File to search, or stdin if not given").from_stdin(true);
+    ///     assert!(parameter.get_from_stdin());
+    ///     assert!(parameter.get_optional());
+    /// ```
+    pub fn from_stdin(mut self, from_stdin: bool) -> ClParameter {
+        self.from_stdin = from_stdin;
+        self.optional |= from_stdin;
+        self
     }
 
     /// Creates an instruction line for this option, usually used for documentation or manuals
@@ -65,7 +262,23 @@ impl ClParameter {
     /// assert_eq!(parameter_1.gen_help_line(),     String::from("    PATH:\n        Path to search in"));
     /// assert_eq!(parameter_2.gen_help_line(),     String::from("    QUERY:\n        String to search for, all the stuff after the path wrap in \"'s if it contains spaces"));
     /// ```
-    pub fn gen_help_line(&self) -> String {format!("    {}:\n        {}",self.name, self.description)}
+    pub fn gen_help_line(&self) -> String {help::trim_line_ends(&format!("    {}:\n        {}",self.name, self.description))}
+
+    /// Creates an instruction line for this option, like `ClParameter::gen_help_line()`, but with the description wrapped
+    /// so that no line is wider than `width` (unless a single word doesn't fit)
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// 
+    /// let parameter = ClParameter::new("QUERY", "String to search for, all the stuff after the path wrap in \"'s if it contains spaces");
+    /// 
+    /// assert_eq!(parameter.gen_help_line_wrapped(100), parameter.gen_help_line());
+    /// assert_eq!(parameter.gen_help_line_wrapped(50),  String::from("    QUERY:\n        String to search for, all the stuff after\n        the path wrap in \"'s if it contains spaces"));
+    /// ```
+    pub fn gen_help_line_wrapped(&self, width: usize) -> String {
+        help::trim_line_ends(&format!("    {}:\n        {}", self.name, help::join_indented(&help::wrap(&self.description, width.saturating_sub(8)), 8)))
+    }
 
 
     //getter methods
@@ -99,6 +312,92 @@ impl ClParameter {
     /// ```
     pub fn get_data(&self) -> &str {&self.data}
 
+    /// get the value of `optional`
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     let example_parameter: ClParameter = ClParameter::new("PATH", "Path of file/folder to search");
+    ///     assert!(!example_parameter.get_optional());
+    /// ```
+    pub fn get_optional(&self) -> bool {self.optional}
+
+    /// get whether this parameter is optional, the same as `ClParameter::get_optional()`
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     assert!(ClParameter::new_optional("PATH", "Path of file/folder to search").is_optional());
+    ///     assert!(!ClParameter::new("PATH", "Path of file/folder to search").is_optional());
+    /// ```
+    pub fn is_optional(&self) -> bool {self.optional}
+
+    /// get the value of `variadic`
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     let example_parameter: ClParameter = ClParameter::new("PATH", "Path of file/folder to search");
+    ///     assert!(!example_parameter.get_variadic());
+    /// ```
+    pub fn get_variadic(&self) -> bool {self.variadic}
+
+    /// get a reference to `values`, every positional a variadic parameter took, in order (its data is them joined with spaces)
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     let mut example_parameter: ClParameter = ClParameter::new("SRC", "Files to copy").variadic(true);
+    ///     assert!(example_parameter.get_values().is_empty());
+    ///     
+    ///     example_parameter.set_values(&[String::from("a.txt"), String::from("b.txt")]);
+    ///     assert_eq!(example_parameter.get_values(), &vec![String::from("a.txt"), String::from("b.txt")]);
+    ///     assert_eq!(example_parameter.get_data(), "a.txt b.txt");
+    /// ```
+    pub fn get_values(&self) -> &Vec<String> {&self.values}
+
+    /// get a reference to `value_parser`, which checks that the data of this parameter is well-formed
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, value_parser::ValueParser};
+    /// //...
+    ///     let example_parameter: ClParameter = ClParameter::new("COUNT", "How many results to show");
+    ///     assert_eq!(example_parameter.get_value_parser(), None);
+    ///     assert_eq!(example_parameter.with_value_parser(ValueParser::IntParser).get_value_parser(), Some(&ValueParser::IntParser));
+    /// ```
+    pub fn get_value_parser(&self) -> Option<&ValueParser> {self.value_parser.as_ref()}
+
+    /// get the value of `stdin_if_dash`
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     let example_parameter: ClParameter = ClParameter::new("QUERY", "String to search for");
+    ///     assert!(!example_parameter.get_stdin_if_dash());
+    /// ```
+    pub fn get_stdin_if_dash(&self) -> bool {self.stdin_if_dash}
+
+    /// get the value of `from_stdin`
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     let example_parameter: ClParameter = ClParameter::new("FILE", "File to search");
+    ///     assert!(!example_parameter.get_from_stdin());
+    /// ```
+    pub fn get_from_stdin(&self) -> bool {self.from_stdin}
+
+    /// get a reference to `raw_data`, the arg this parameter was given, which is its data unless it was replaced with stdin, see `ClParameter::stdin_if_dash()`
+    /// # Examples
+    /// ```
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     let mut example_parameter: ClParameter = ClParameter::new("QUERY", "String to search for");
+    ///     example_parameter.set_data("needle");
+    ///     assert_eq!(example_parameter.get_raw_data(), "needle");
+    /// ```
+    pub fn get_raw_data(&self) -> &str {&self.raw_data}
+
 
     //setter methods
 
@@ -139,5 +438,26 @@ impl ClParameter {
     ///     example_parameter.set_data("new data");
     ///     assert_eq!(example_parameter.get_data(), "new data");
     /// ```
-    pub fn set_data(&mut self, new_data: &str) {self.data = new_data.to_string();}
+    pub fn set_data(&mut self, new_data: &str) {
+        self.data = new_data.to_string();
+        self.raw_data = new_data.to_string();
+    }
+
+    /// replaces `data` with `from_stdin`, keeping `raw_data`
+    pub(crate) fn replace_data_with_stdin(&mut self, from_stdin: &str) {self.data = from_stdin.to_string();}
+
+    /// set `values` to `new_values`, and `data` to them joined with spaces, see `ClParameter::get_values()`
+    /// # Examples
+    /// ``` 
+    /// use clia::parameter_args::ClParameter;
+    /// //...
+    ///     let mut example_parameter: ClParameter = ClParameter::new("SRC", "Files to copy").variadic(true);
+    ///     example_parameter.set_values(&[String::from("a.txt")]);
+    ///     assert_eq!(example_parameter.get_data(), "a.txt");
+    /// ```
+    pub fn set_values(&mut self, new_values: &[String]) {
+        self.values = new_values.to_vec();
+        self.data = new_values.join(" ");
+        self.raw_data = self.data.clone();
+    }
 }
@@ -6,8 +6,13 @@
 #![warn(rustdoc::missing_doc_code_examples)]
 
 use std::error::Error;
+use std::ops::Range;
 
+use crate::classifier::{Classifier, TokenClass};
+use crate::option_args;
 use crate::parameter_args;
+use crate::parse_error::{ParseError, ParseErrorKind, ParseErrors};
+use crate::parser_settings::ParserSettings;
 
 
 /// parse args for Parameters
@@ -16,10 +21,37 @@ use crate::parameter_args;
 /// 
 /// # Notes: 
 /// - the order of elements in `expected_parameters` is the order these arguments must appear in.
-/// - these arguments are the last things a user types in the command line (after Options)
+/// - these arguments are the positionals, the last run of args that aren't flags (or the list/data of one), so they can come after or before the options,
+///   ei. `path query` in both `foo.exe -r path query` and `foo.exe path query -r`
+/// - every arg after the first `--` is a positional too, even if it looks like a flag, see `option_parser::find_terminator()`
+/// - the first element of `args` is the program name, so it's never a parameter
+/// - empty `args` are fine so long as `expected_parameters` is empty too
+/// 
+/// # Optional parameters:
+/// if any parameter is optional (see `ClParameter::optional()`), the parameters are assigned to the positionals, the args after the last option:
+/// - every required parameter is given a positional first, then the positionals that are left over go to the optional parameters, from left to right
+/// - so with `[A] B [C]` (A and C optional), 1 positional is B, 2 are A and B, and 3 are A, B, and C
+/// - optional parameters that aren't given have empty data
+/// 
+/// this function doesn't know your options, so it can't tell the list/data of an option from a positional, ei. `foo.exe path --format NUMERIC`,
+/// if there are more positionals than parameters the parameters take the last ones,
+/// `Parser::new()` and `parameter_parser::parse_for_parameters_strict()` know your options so don't have this problem,
+/// and report positionals left over after every parameter is given one as errors (`Parser::new()` doesn't if unmatched args are allowed, see `ParserSettings::allow_unmatched_args()`,
+/// or unknown flags are allowed, see `ParserSettings::allow_unknown_flags()`, since they could take a list/data)
+/// 
+/// # Variadic parameters:
+/// one parameter can be variadic (see `ClParameter::variadic()`), it doesn't have to be the last one, ei. `<SRC>... <DEST>` like `cp`:
+/// - the parameters before it take one positional each from the left, and the parameters after it take one each from the right
+/// - the variadic parameter takes every positional left in the middle, which must be at least one, unless it's optional
+/// - when there's a variadic parameter, the other parameters always take a positional, even if they're optional
 /// 
 /// # Errors
-/// - `args` is too short to have all the expected data
+/// - there are fewer positionals than required parameters, the error names the parameters that are missing
+/// - more than one parameter is variadic
+/// - the data of a parameter isn't well-formed, see `ClParameter::with_value_parser()`
+/// - stdin can't be read for a parameter given `-`, see `ClParameter::stdin_if_dash()`
+/// 
+/// the error is a `parse_error::ParseErrors`
 /// 
 /// # Examples
 /// ```
@@ -36,25 +68,416 @@ use crate::parameter_args;
 ///     let parsed_parameters: Vec<ClParameter> = parameter_parser::parse_for_parameters(&args, &expected_parameters).unwrap();
 /// ```
 /// 
+/// optional parameters
+/// ```
+/// use clia::{parameter_args::ClParameter,parameter_parser};
+/// //...
+///     let expected_parameters: Vec<ClParameter> = vec![
+///         ClParameter::new("A", "Optional").optional(true),
+///         ClParameter::new("B", "Required"),
+///         ClParameter::new("C", "Optional").optional(true),
+///     ];
+///     let parse = |args: &[&str]| -> Vec<String> {
+///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+///         parameter_parser::parse_for_parameters(&args, &expected_parameters).unwrap().iter().map(|parameter| parameter.get_data().to_string()).collect()
+///     };
+///     
+///     assert_eq!(parse(&["foo.exe", "--recursive", "b"]), vec!["", "b", ""]);
+///     assert_eq!(parse(&["foo.exe", "--recursive", "a", "b"]), vec!["a", "b", ""]);
+///     assert_eq!(parse(&["foo.exe", "--recursive", "a", "b", "c"]), vec!["a", "b", "c"]);
+///     
+///     //B is still required
+///     assert!(parameter_parser::parse_for_parameters(&[String::from("foo.exe"), String::from("--recursive")], &expected_parameters).is_err());
+/// ```
+/// 
+/// variadic parameters
+/// ```
+/// use clia::{parameter_args::ClParameter,parameter_parser};
+/// //...
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("SRC", "Files to copy").variadic(true), ClParameter::new("DEST", "Where to copy them")];
+///     let parse = |args: &[&str]| {
+///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+///         parameter_parser::parse_for_parameters(&args, &expected_parameters)
+///     };
+///     
+///     let parameters = parse(&["cp", "a.txt", "dir"]).unwrap();
+///     assert_eq!(parameters[0].get_values(), &vec![String::from("a.txt")]);
+///     assert_eq!(parameters[1].get_data(), "dir");
+///     
+///     let parameters = parse(&["cp", "-r", "a.txt", "b.txt", "dir"]).unwrap();
+///     assert_eq!(parameters[0].get_values(), &vec![String::from("a.txt"), String::from("b.txt")]);
+///     assert_eq!(parameters[1].get_data(), "dir");
+///     
+///     //SRC needs at least one
+///     assert!(parse(&["cp", "dir"]).is_err());
+///     
+///     //only one parameter can be variadic
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("A", "").variadic(true), ClParameter::new("B", "").variadic(true)];
+///     assert_eq!(
+///         parameter_parser::parse_for_parameters(&[String::from("foo.exe"), String::from("a")], &expected_parameters).unwrap_err().to_string(),
+///         "BUG: only one parameter can be variadic, but A and B are"
+///     );
+/// ```
+/// 
+/// options after the parameters, the parser knows which flags take a list/data
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parse_error::{ParseErrorKind, ParseErrors}, Parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+///     ];
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in"), ClParameter::new("QUERY", "String to search for")];
+///     let parse = |args: &[&str]| {
+///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+///         Parser::new(&args, &valid_options, &expected_parameters)
+///     };
+///     
+///     for args in [vec!["prog", "--format", "NUMERIC", "path", "query"], vec!["prog", "path", "query", "--format", "NUMERIC"], vec!["prog", "-r", "path", "query", "-F", "NUMERIC"]] {
+///         let parser = parse(&args).unwrap();
+///         assert_eq!(parser.get_one("PATH"), Some("path"));
+///         assert_eq!(parser.get_one("QUERY"), Some("query"));
+///         assert_eq!(parser.get_one("format"), Some("NUMERIC"));
+///     }
+///     
+///     //the missing parameters are named
+///     assert_eq!(parse(&["prog", "path", "--format", "NUMERIC"]).err().unwrap().to_string(), "User Error: missing parameter: QUERY (1 positional argument given, 2 required)");
+///     assert_eq!(parse(&["prog", "-r"]).err().unwrap().to_string(), "User Error: missing parameters: PATH, QUERY (0 positional arguments given, 2 required)");
+///     
+///     //the list/data of options never counts towards the parameters
+///     let valued_options: Vec<ClOption> = vec![
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+///     ];
+///     let parse_valued = |args: &[&str]| {
+///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+///         Parser::new(&args, &valued_options, &expected_parameters)
+///     };
+///     for args in [vec!["prog", "--format", "NUMERIC", "onlyoneparam"], vec!["prog", "--filter", "rs,toml", "onlyoneparam"]] {
+///         assert_eq!(parse_valued(&args).err().unwrap().to_string(), "User Error: missing parameter: QUERY (1 positional argument given, 2 required)");
+///     }
+///     let parser = parse_valued(&["prog", "--format", "NUMERIC", "--filter", "rs,toml", "path", "query"]).unwrap();
+///     assert_eq!((parser.get_one("PATH"), parser.get_one("QUERY")), (Some("path"), Some("query")));
+///     
+///     //and positionals left over are errors
+///     let error = parse(&["prog", "path", "query", "extra", "-r"]).err().unwrap();
+///     let errors = error.downcast_ref::<ParseErrors>().unwrap().get_errors();
+///     assert_eq!(errors.len(), 1);
+///     assert_eq!(errors[0].get_kind(), ParseErrorKind::UnexpectedPositional);
+///     assert_eq!(errors[0].get_arg_index(), Some(3));
+///     assert_eq!(errors[0].to_string(), "User Error: unexpected argument 'extra' (arg 3), every parameter was already given");
+/// ```
+/// 
+/// args without the program name, or with nothing else, never panic
+/// ```
+/// use clia::{parameter_args::ClParameter, parameter_parser, Parser};
+/// //...
+///     let required: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+///     let optional: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in").optional(true)];
+///     let variadic: Vec<ClParameter> = vec![ClParameter::new("FILES", "Files to search").variadic(true)];
+///     
+///     for args in [vec![], vec![String::from("prog")]] {
+///         //no parameters expected, so nothing is missing
+///         assert!(parameter_parser::parse_for_parameters(&args, &[]).unwrap().is_empty());
+///         assert!(Parser::new(&args, &[], &[]).is_ok());
+///         
+///         //required parameters are missing
+///         assert_eq!(parameter_parser::parse_for_parameters(&args, &required).unwrap_err().to_string(), "User Error: missing parameter: PATH (0 positional arguments given, 1 required)");
+///         assert_eq!(parameter_parser::parse_for_parameters(&args, &variadic).unwrap_err().to_string(), "User Error: missing parameter: FILES (0 positional arguments given, 1 required)");
+///         assert_eq!(Parser::new(&args, &[], &required).err().unwrap().to_string(), "User Error: missing parameter: PATH (0 positional arguments given, 1 required)");
+///         
+///         //optional ones are just empty
+///         assert_eq!(parameter_parser::parse_for_parameters(&args, &optional).unwrap()[0].get_data(), "");
+///     }
+/// ```
+/// 
 pub fn parse_for_parameters(args: &[String], expected_parameters: &[parameter_args::ClParameter]) -> Result<Vec<parameter_args::ClParameter>,Box<dyn Error>> {
+    let (results, errors) = collect_parameters(args, &[], expected_parameters, &Classifier::new(&[], &ParserSettings::new()), 1, false, None);
+
+    if errors.is_empty() {
+        Ok(results)
+    } else {
+        Err(Box::new(ParseErrors::new(errors)))
+    }
+}
+
+/// parse args for parameters, like `parameter_parser::parse_for_parameters()`, but positionals left over after every parameter is given one are errors instead of ignored,
+/// the flags in args, and the list/data after those of `valid_options`, aren't positionals
+/// 
+/// # Errors
+/// - the same errors as `parameter_parser::parse_for_parameters()`
+/// - there are more positionals than `expected_parameters` (and none of them are variadic), each extra gets its own error with its index in `args`
+/// 
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parameter_parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT")];
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in"), ClParameter::new("QUERY", "String to search for")];
+///     let parse = |args: &[&str]| {
+///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+///         parameter_parser::parse_for_parameters_strict(&args, &expected_parameters, &valid_options)
+///     };
+///     
+///     //exactly right, the data of --format isn't a positional
+///     let parameters = parse(&["foo.exe", "--format", "NUMERIC", "path", "query"]).unwrap();
+///     assert_eq!((parameters[0].get_data(), parameters[1].get_data()), ("path", "query"));
+///     
+///     //too few
+///     assert_eq!(parse(&["foo.exe", "path"]).unwrap_err().to_string(), "User Error: missing parameter: QUERY (1 positional argument given, 2 required)");
+///     
+///     //too many, every extra is named
+///     assert_eq!(
+///         parse(&["foo.exe", "path", "query", "extra", "more"]).unwrap_err().to_string(),
+///         "User Error: unexpected argument 'extra' (arg 3), every parameter was already given\n\
+///          User Error: unexpected argument 'more' (arg 4), every parameter was already given"
+///     );
+///     assert_eq!(
+///         parameter_parser::parse_for_parameters_strict(&[String::from("foo.exe"), String::from("path")], &[], &valid_options).unwrap_err().to_string(),
+///         "User Error: unexpected argument 'path' (arg 1), no parameters are expected"
+///     );
+///     
+///     //the lenient version takes the last ones
+///     let args: Vec<String> = ["foo.exe", "path", "query", "extra"].iter().map(|arg| arg.to_string()).collect();
+///     assert_eq!(parameter_parser::parse_for_parameters(&args, &expected_parameters).unwrap()[0].get_data(), "query");
+/// ```
+pub fn parse_for_parameters_strict(args: &[String], expected_parameters: &[parameter_args::ClParameter], valid_options: &[option_args::ClOption]) -> Result<Vec<parameter_args::ClParameter>,Box<dyn Error>> {
+    let (results, errors) = collect_parameters(args, &[], expected_parameters, &Classifier::new(valid_options, &ParserSettings::new()), 1, true, None);
+
+    if errors.is_empty() {
+        Ok(results)
+    } else {
+        Err(Box::new(ParseErrors::new(errors)))
+    }
+}
+
+/// parses args for parameters, returning every error found instead of stopping at the first, the positionals are found from `first` in args with `classifier` (see `find_positionals()`),
+/// positionals left over after every parameter is given one are errors if `strict`, otherwise the parameters take the last ones,
+/// `stdin` replaces the real stdin, if given, and errors point at the index in `origins` of the args at each index in `args`, if it's there (see `option_parser::join_bracketed_lists()`)
+pub(crate) fn collect_parameters(args: &[String], origins: &[usize], expected_parameters: &[parameter_args::ClParameter], classifier: &Classifier, first: usize, strict: bool, stdin: Option<&option_args::LazyDefault>) -> (Vec<parameter_args::ClParameter>, Vec<ParseError>) {
+    let (mut results, mut errors) = assign_parameters(args, origins, expected_parameters, &find_positionals(args, classifier, first), strict);
+
+    //replace the data of parameters given a - with stdin, which can only be read once, so they all get the same contents
+    let mut from_stdin: Option<String> = None;
+    for parameter in results.iter_mut().filter(|parameter| parameter.get_stdin_if_dash() && parameter.get_data().eq("-")) {
+        if from_stdin.is_none() {
+            match stdin.map_or_else(|| std::io::read_to_string(std::io::stdin()), |stdin| Ok(stdin.produce())) {
+                Ok(contents) => from_stdin = Some(contents.strip_suffix('\n').map_or(contents.as_str(), |contents| contents.strip_suffix('\r').unwrap_or(contents)).to_string()),
+                Err(e) => {
+                    errors.push(ParseError::new(ParseErrorKind::Other, None, &format!("User Error: couldn't read stdin for parameter({}): {}", parameter.get_name(), e)));
+                    break;
+                },
+            }
+        }
+        if let Some(contents) = &from_stdin {
+            parameter.replace_data_with_stdin(contents);
+        }
+    }
+
+    //check the data of the parameters that were given is well-formed
+    if errors.is_empty() {
+        for parameter in results.iter() {
+            let Some(parser) = parameter.get_value_parser() else {continue};
+            if parameter.get_variadic() {
+                //only the first invalid item of a variadic parameter is reported, with its index
+                let invalid = parameter.get_values().iter().enumerate().find_map(|(index, value)| parser.check(value).err().map(|e| (index, value, e)));
+                if let Some((index, value, e)) = invalid {
+                    errors.push(ParseError::new(ParseErrorKind::InvalidValue, None, &format!("User Error: invalid value '{}' at index {} of parameter({}): {}", value, index, parameter.get_name(), e)));
+                }
+            } else if !parameter.get_data().is_empty() || !parameter.get_optional() {
+                if let Err(e) = parser.check(parameter.get_data()) {
+                    errors.push(ParseError::new(ParseErrorKind::InvalidValue, None, &format!("User Error: invalid value '{}' for parameter({}): {}", parameter.get_data(), parameter.get_name(), e)));
+                }
+            }
+        }
+    }
+
+    (results, errors)
+}
+
+/// assigns the `positionals` (their indices in args) to the parameters, see `collect_parameters()`
+fn assign_parameters(args: &[String], origins: &[usize], expected_parameters: &[parameter_args::ClParameter], positionals: &[usize], strict: bool) -> (Vec<parameter_args::ClParameter>, Vec<ParseError>) {
     //DATA
-    let mut results: Vec<parameter_args::ClParameter> = Vec::new();
+    let mut results: Vec<parameter_args::ClParameter> = expected_parameters.to_vec();
+    let required: usize = expected_parameters.iter().filter(|parameter| !parameter.get_optional()).count();
+
+    //a variadic parameter changes how positionals are assigned
+    let variadics: Vec<usize> = expected_parameters.iter().enumerate().filter(|(_, parameter)| parameter.get_variadic()).map(|(index, _)| index).collect();
+    match variadics.as_slice() {
+        [] => {},
+        [variadic] => return collect_variadic_parameters(args, positionals, results, *variadic),
+        _ => {
+            let names: Vec<&str> = variadics.iter().map(|index| expected_parameters[*index].get_name()).collect();
+            return (results, vec![ParseError::new(ParseErrorKind::Other, None, &format!("BUG: only one parameter can be variadic, but {} are", names.join(" and ")))]);
+        },
+    }
+
+    //return an error if required parameters are missing, or there are positionals left over
+    if positionals.len() < required {
+        let needed: Vec<&parameter_args::ClParameter> = expected_parameters.iter().filter(|parameter| !parameter.get_optional()).collect();
+        return (results, vec![missing_parameters_error(&needed[positionals.len()..], positionals.len(), required)]);
+    }
+    let positionals: &[usize] = if positionals.len() > expected_parameters.len() {
+        if strict {
+            return (results, unexpected_positionals_errors(args, origins, &positionals[expected_parameters.len()..], expected_parameters.len()));
+        }
+        &positionals[find_unmatched(expected_parameters, positionals).len()..]
+    } else {
+        positionals
+    };
+    let given: usize = positionals.len();
+
+    //required parameters get a positional first, the optional parameters from left to right get the rest
+    let mut optional_left: usize = given - required;
+    let mut positionals = positionals.iter().map(|index| &args[*index]);
+    for result in results.iter_mut() {
+        if result.get_optional() {
+            if optional_left == 0 {
+                continue;
+            }
+            optional_left -= 1;
+        }
+        if let Some(arg) = positionals.next() {
+            result.set_data(arg);
+        }
+    }
+
+    (results, Vec::new())
+}
+
+/// parses args for parameters, when the parameter at `variadic` in `results` is variadic
+fn collect_variadic_parameters(args: &[String], positionals: &[usize], mut results: Vec<parameter_args::ClParameter>, variadic: usize) -> (Vec<parameter_args::ClParameter>, Vec<ParseError>) {
+    //DATA
+    let given: usize = positionals.len();
+    let fixed: usize = results.len() - 1;
+    let variadic_needs: usize = usize::from(!results[variadic].get_optional());
+
+    //return an error if there aren't enough positionals, the parameters that need one and go without are missing
+    if given < fixed + variadic_needs {
+        let needed: Vec<&parameter_args::ClParameter> = results.iter().enumerate().filter(|(index, _)| *index != variadic || variadic_needs == 1).map(|(_, parameter)| parameter).collect();
+        let error: ParseError = missing_parameters_error(&needed[given..], given, needed.len());
+        return (results, vec![error]);
+    }
 
-    //return an error is args is too short
-    if args.len()-1 < expected_parameters.len() {
-        return Err(format!("User Error: the amount of passed args is too small to possibly contain all the expected data").into());
+    //the parameters before the variadic one take from the left, the ones after from the right, and the variadic takes the middle
+    let positionals: Vec<String> = positionals.iter().map(|index| args[*index].clone()).collect();
+    let after: usize = fixed - variadic;
+    for (result, arg) in results[..variadic].iter_mut().zip(positionals.iter()) {
+        result.set_data(arg);
+    }
+    for (result, arg) in results[variadic+1..].iter_mut().zip(positionals[given-after..].iter()) {
+        result.set_data(arg);
     }
+    results[variadic].set_values(&positionals[variadic..given-after]);
+
+    (results, Vec::new())
+}
 
-    //look at the last expected_parameters.len() elements of args
-    for arg in (&args[args.len()-expected_parameters.len()..]).iter().enumerate() {
-        if let Some(expected_parameter) = expected_parameters.get(arg.0) {
-            results.push(expected_parameter.clone())
-        } else { return Err(format!("Bug: index {} out of bounds of expected_parameters", arg.0).into());}
+/// returns the error for the `missing` parameters, naming every one of them, when only `given` of the `required` positionals were given
+fn missing_parameters_error(missing: &[&parameter_args::ClParameter], given: usize, required: usize) -> ParseError {
+    let names: Vec<&str> = missing.iter().map(|parameter| parameter.get_name()).collect();
+    ParseError::new(ParseErrorKind::MissingParameters, None, &format!(
+        "User Error: missing {}: {} ({} positional argument{} given, {} required)",
+        if names.len() == 1 {"parameter"} else {"parameters"},
+        names.join(", "),
+        given,
+        if given == 1 {""} else {"s"},
+        required,
+    ))
+}
 
-        results[arg.0].set_data(arg.1);
+/// returns an error for every positional at the `unexpected` indices in `args`, left over after every parameter was given one, pointing at their index in `origins` if they're in it
+fn unexpected_positionals_errors(args: &[String], origins: &[usize], unexpected: &[usize], expected: usize) -> Vec<ParseError> {
+    unexpected.iter().copied().map(|index| (index, origins.get(index).copied().unwrap_or(index))).map(|(index, origin)| ParseError::new(
+        ParseErrorKind::UnexpectedPositional,
+        Some(origin),
+        &format!("User Error: unexpected argument '{}' (arg {}), {}", args[index], origin, if expected == 0 {"no parameters are expected"} else {"every parameter was already given"}),
+    )).collect()
+}
+
+/// returns the index of the parameter in `expected_parameters` the arg at each index is given, when the `positionals` (their indices in the args) are assigned to them like `collect_parameters()` does
+/// 
+/// the positionals are assigned their own indices instead of themselves, so this can never disagree with parsing
+pub(crate) fn find_parameter_indices(expected_parameters: &[parameter_args::ClParameter], positionals: &[usize]) -> Vec<Option<usize>> {
+    //DATA
+    let len: usize = positionals.last().map_or(0, |last| last + 1);
+    let indices: Vec<String> = (0..len).map(|index| index.to_string()).collect();
+    let mut parameters: Vec<Option<usize>> = vec![None; len];
+
+    let (results, errors) = assign_parameters(&indices, &[], expected_parameters, positionals, false);
+    if !errors.is_empty() {
+        return parameters;
+    }
+    for (parameter, result) in results.iter().enumerate() {
+        let given: Vec<&str> = if result.get_variadic() {result.get_values().iter().map(|value| value.as_str()).collect()} else {vec![result.get_data()]};
+        for index in given.into_iter().filter_map(|index| index.parse::<usize>().ok()) {
+            parameters[index] = Some(parameter);
+        }
     }
+    parameters
+}
+
+/// returns the indices of the positionals that no parameter is given, out of the `positionals` (see `find_positionals()`),
+/// the first ones, since the parameters take the last positionals, none if a parameter is variadic, since it takes every one left over
+pub(crate) fn find_unmatched<'a>(expected_parameters: &[parameter_args::ClParameter], positionals: &'a [usize]) -> &'a [usize] {
+    if expected_parameters.iter().any(|parameter| parameter.get_variadic()) {
+        return &[];
+    }
+    &positionals[..positionals.len().saturating_sub(expected_parameters.len())]
+}
+
+/// returns the indices of the positionals in `args`, the last run of args that `classifier` classifies as positionals (see `Classifier::classify()`),
+/// so options can be given after the parameters too, ei. `path query` in `foo.exe -r path query --format NUMERIC`
+/// 
+/// every arg after the first `--` is a positional, along with the run right before it, ei. `query -r` in `foo.exe query -- -r`,
+/// but the `--` itself isn't, see `option_parser::find_terminator()`
+/// 
+/// the args before `first` (the program name, and the subcommand if there is one) are never positionals
+pub(crate) fn find_positionals(args: &[String], classifier: &Classifier, first: usize) -> Vec<usize> {
+    //DATA
+    let mut positionals: Range<usize> = args.len()..args.len();
 
+    for index in first.max(1)..args.len() {
+        match classifier.classify(&args[index], Some(&args[index - 1])) {
+            TokenClass::Positional if positionals.end == index => positionals.end = index + 1,
+            TokenClass::Positional => positionals = index..index + 1,
+            TokenClass::Terminator => {
+                let start: usize = if positionals.end == index {positionals.start} else {index};
+                return (start..args.len()).filter(|positional| *positional != index).collect();
+            },
+            _ => {},
+        }
+    }
 
-    return Ok(results);
+    positionals.collect()
+}
 
-}
\ No newline at end of file
+/// returns a warning for every parameter in `parameters` whose data is the long flag of an option in `valid_options` without its dashes,
+/// since the user probably meant to type the flag (ei. `foo.exe recursive path` instead of `foo.exe --recursive path`)
+/// 
+/// warnings are in the same order as `parameters`
+/// 
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parameter_parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+///     let mut parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in"), ClParameter::new("QUERY", "String to search for")];
+///     parameters[0].set_data("recursive");
+///     parameters[1].set_data("r"); //short flags are too short to tell
+///     
+///     assert_eq!(
+///         parameter_parser::find_flag_like_parameters(&parameters, &valid_options),
+///         vec![String::from("'recursive' was used as the PATH parameter, did you mean '--recursive'?")]
+///     );
+/// ```
+pub fn find_flag_like_parameters(parameters: &[parameter_args::ClParameter], valid_options: &[option_args::ClOption]) -> Vec<String> {
+    parameters.iter()
+    .filter_map(|parameter| {
+        valid_options.iter()
+        .map(|option| option.get_long_flag())
+        .find(|long_flag| !long_flag.is_empty() && long_flag.trim_start_matches('-').eq(parameter.get_data()))
+        .map(|long_flag| format!("'{}' was used as the {} parameter, did you mean '{}'?", parameter.get_data(), parameter.get_name(), long_flag))
+    })
+    .collect()
+}
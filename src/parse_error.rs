@@ -0,0 +1,444 @@
+//! # Parse error
+//! 'parse_error' is a module containing utilities for
+//! reporting the errors found while parsing CLI Arguments
+//!
+//! validation happens in a fixed pipeline of phases, in the order they're declared in `ValidationPhase`,
+//! and every error found is reported (not just the first), ordered by phase, then by where in the args it was found,
+//! so the order errors are reported in never depends on implementation details
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use std::error::Error;
+use std::fmt;
+
+use crate::spec::CliSpec;
+
+/// the phases of validation, in the order they run
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationPhase {
+    /// deciding which args belong to which option, including expanding presets
+    TokenClaiming,
+    /// finding flags that aren't defined by any valid option
+    UnknownFlags,
+    /// checking the list/data of every option, ei. that it was given, and is an allowed value
+    ValueValidation,
+    /// checking how many times options occurred
+    OccurrenceConstraints,
+    /// checking which options require, or conflict with, other options
+    Relations,
+    /// checking that required options are present
+    RequiredOptions,
+    /// checking parameters
+    Parameters,
+    /// checks that run after everything else
+    PostValidation,
+}
+
+/// the kinds of errors that can be found while parsing, each is found in one phase
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// the preset of a ClOption::FlagPreset isn't valid
+    InvalidPreset,
+    /// response files and presets that include each other, are nested too deeply, or can't be read, see `expansion::Expander`
+    InvalidExpansion,
+    /// an arg before the parameters that isn't a flag, or the list/data of one, see `ParserSettings::stray_args()`
+    UnexpectedArgument,
+    /// flags that aren't defined by any valid option
+    UnknownFlag,
+    /// a flag that is defined, but was typed wrong or in the wrong place, ei. `-recursive` instead of `--recursive`
+    MisplacedFlag,
+    /// a flag that needs a list/data wasn't followed by one
+    MissingValue,
+    /// the list/data of a flag isn't valid, ei. isn't one of its allowed values
+    InvalidValue,
+    /// a flag that can only be given once was given more than once, see `ClOption::on_repeat()`
+    RepeatedFlag,
+    /// the options and parameters given break a constraint between them, see `constraints::Constraints`
+    ConstraintViolated,
+    /// there were more positionals than parameters, see `parameter_parser::parse_for_parameters()`
+    UnexpectedPositional,
+    /// there weren't enough args for the expected parameters
+    MissingParameters,
+    /// the help flag was given, so the other errors aren't reported, see `Parser::new()`
+    HelpRequested,
+    /// anything else
+    Other,
+}
+impl ParseErrorKind {
+    /// get the phase errors of this kind are found in
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseErrorKind, ValidationPhase};
+    /// //...
+    ///     assert_eq!(ParseErrorKind::UnknownFlag.get_phase(), ValidationPhase::UnknownFlags);
+    ///     assert_eq!(ParseErrorKind::InvalidValue.get_phase(), ValidationPhase::ValueValidation);
+    /// ```
+    pub fn get_phase(&self) -> ValidationPhase {
+        match self {
+            ParseErrorKind::InvalidPreset | ParseErrorKind::InvalidExpansion | ParseErrorKind::UnexpectedArgument | ParseErrorKind::HelpRequested => ValidationPhase::TokenClaiming,
+            ParseErrorKind::UnknownFlag | ParseErrorKind::MisplacedFlag => ValidationPhase::UnknownFlags,
+            ParseErrorKind::MissingValue | ParseErrorKind::InvalidValue => ValidationPhase::ValueValidation,
+            ParseErrorKind::RepeatedFlag => ValidationPhase::OccurrenceConstraints,
+            ParseErrorKind::ConstraintViolated => ValidationPhase::Relations,
+            ParseErrorKind::UnexpectedPositional | ParseErrorKind::MissingParameters => ValidationPhase::Parameters,
+            ParseErrorKind::Other => ValidationPhase::PostValidation,
+        }
+    }
+
+    /// get the snake_case name of this kind, as used in json
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::ParseErrorKind;
+    /// //...
+    ///     assert_eq!(ParseErrorKind::UnknownFlag.get_name(), "unknown_flag");
+    /// ```
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ParseErrorKind::InvalidPreset => "invalid_preset",
+            ParseErrorKind::InvalidExpansion => "invalid_expansion",
+            ParseErrorKind::UnexpectedArgument => "unexpected_argument",
+            ParseErrorKind::UnknownFlag => "unknown_flag",
+            ParseErrorKind::MisplacedFlag => "misplaced_flag",
+            ParseErrorKind::MissingValue => "missing_value",
+            ParseErrorKind::InvalidValue => "invalid_value",
+            ParseErrorKind::RepeatedFlag => "repeated_flag",
+            ParseErrorKind::ConstraintViolated => "constraint_violated",
+            ParseErrorKind::UnexpectedPositional => "unexpected_positional",
+            ParseErrorKind::MissingParameters => "missing_parameters",
+            ParseErrorKind::HelpRequested => "help_requested",
+            ParseErrorKind::Other => "other",
+        }
+    }
+}
+
+/// a single error found while parsing
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    arg_index: Option<usize>,
+    flag: Option<String>,
+    message: String,
+}
+impl ParseError {
+    /// creates a new ParseError of `kind`, at `arg_index` in the args (if it's about a specific arg)
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseError, ParseErrorKind, ValidationPhase};
+    /// //...
+    ///     let error = ParseError::new(ParseErrorKind::UnknownFlag, Some(2), "User Error: unknown options: '-z' (arg 2)");
+    ///     assert_eq!(error.get_kind(), ParseErrorKind::UnknownFlag);
+    ///     assert_eq!(error.get_phase(), ValidationPhase::UnknownFlags);
+    ///     assert_eq!(error.get_arg_index(), Some(2));
+    ///     assert_eq!(error.to_string(), "User Error: unknown options: '-z' (arg 2)");
+    /// ```
+    pub fn new(kind: ParseErrorKind, arg_index: Option<usize>, message: &str) -> ParseError {
+        ParseError {
+            kind,
+            arg_index,
+            flag: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// sets the flag this error is about
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseError, ParseErrorKind};
+    /// //...
+    ///     let error = ParseError::new(ParseErrorKind::MissingValue, Some(1), "User Error: no data").with_flag("--format");
+    ///     assert_eq!(error.get_flag(), Some("--format"));
+    /// ```
+    pub fn with_flag(mut self, flag: &str) -> ParseError {
+        self.flag = Some(flag.to_string());
+        self
+    }
+
+    /// replaces the message of this error, see `Messages::localize()`
+    pub(crate) fn with_message(mut self, message: &str) -> ParseError {
+        self.message = message.to_string();
+        self
+    }
+
+    /// returns this error as a json object, with its kind (as `error`), flag (if there is one), arg index (if there is one), and message
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parse_error::ParseErrors, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"])];
+    ///
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--frmat")];
+    ///     let error = Parser::new(&args, &valid_options, &[]).err().unwrap();
+    ///     assert_eq!(
+    ///         error.downcast_ref::<ParseErrors>().unwrap().get_errors()[0].to_json(),
+    ///         r#"{"error":"unknown_flag","flag":"--frmat","arg_index":1,"message":"User Error: unknown options: '--frmat' (arg 1)"}"#
+    ///     );
+    ///
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--format"), String::from("\"FANCY\"")];
+    ///     let error = Parser::new(&args, &valid_options, &[]).err().unwrap();
+    ///     assert_eq!(
+    ///         error.downcast_ref::<ParseErrors>().unwrap().get_errors()[0].to_json(),
+    ///         r#"{"error":"invalid_value","flag":"--format","arg_index":2,"message":"User Error: invalid value '\"FANCY\"' for flag(--format), possible values are: DEFAULT, BULLET"}"#
+    ///     );
+    /// ```
+    pub fn to_json(&self) -> String {
+        //DATA
+        let mut fields: Vec<String> = vec![format!("\"error\":\"{}\"", self.kind.get_name())];
+
+        if let Some(flag) = &self.flag {
+            fields.push(format!("\"flag\":\"{}\"", escape_json(flag)));
+        }
+        if let Some(arg_index) = self.arg_index {
+            fields.push(format!("\"arg_index\":{}", arg_index));
+        }
+        fields.push(format!("\"message\":\"{}\"", escape_json(&self.message)));
+
+        format!("{{{}}}", fields.join(","))
+    }
+
+    //getter methods
+    /// get the kind of this error
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseError, ParseErrorKind};
+    /// //...
+    ///     assert_eq!(ParseError::new(ParseErrorKind::MissingParameters, None, "").get_kind(), ParseErrorKind::MissingParameters);
+    /// ```
+    pub fn get_kind(&self) -> ParseErrorKind {self.kind}
+
+    /// get the phase this error was found in
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseError, ParseErrorKind, ValidationPhase};
+    /// //...
+    ///     assert_eq!(ParseError::new(ParseErrorKind::MissingParameters, None, "").get_phase(), ValidationPhase::Parameters);
+    /// ```
+    pub fn get_phase(&self) -> ValidationPhase {self.kind.get_phase()}
+
+    /// get the index in the args of the arg this error is about, if it's about a specific arg
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseError, ParseErrorKind};
+    /// //...
+    ///     assert_eq!(ParseError::new(ParseErrorKind::MissingParameters, None, "").get_arg_index(), None);
+    /// ```
+    ///
+    /// it's the index of the arg as it was given, even if it's a cluster of short flags, is given a value after an `=`, or comes after a bracketed list
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parse_error::ParseErrors, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-i", "--ignore-case", "Ignore case").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("", "--offset", "Lines to skip").unwrap(), "OFFSET"),
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS").bracketed_lists(true),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let arg_indices = |args: &[&str]| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         let error = Parser::new(&args, &valid_options, &expected_parameters).err().unwrap();
+    ///         error.downcast_ref::<ParseErrors>().unwrap().get_errors().iter().map(|e| e.get_arg_index()).collect::<Vec<Option<usize>>>()
+    ///     };
+    ///     
+    ///     assert_eq!(arg_indices(&["foo.exe", "-ri", "-z", "path"]), vec![Some(2)]);
+    ///     assert_eq!(arg_indices(&["foo.exe", "--offset=3", "-z", "path"]), vec![Some(2)]);
+    ///     assert_eq!(arg_indices(&["foo.exe", "-f", "[rs", "toml]", "-z", "path"]), vec![Some(4)]);
+    ///     assert_eq!(arg_indices(&["foo.exe", "-f", "[rs", "toml]", "path", "extra"]), vec![Some(5)]);
+    ///     //a flag that takes a value before the end of a cluster is an error, but still takes the next arg, so that isn't a parameter too
+    ///     assert_eq!(arg_indices(&["foo.exe", "-Fr", "x", "path"]), vec![Some(1)]);
+    /// ```
+    pub fn get_arg_index(&self) -> Option<usize> {self.arg_index}
+
+    /// get the flag this error is about, if it's about a specific flag
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseError, ParseErrorKind};
+    /// //...
+    ///     assert_eq!(ParseError::new(ParseErrorKind::MissingParameters, None, "").get_flag(), None);
+    /// ```
+    pub fn get_flag(&self) -> Option<&str> {self.flag.as_deref()}
+
+    /// get a reference to the message of this error
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseError, ParseErrorKind};
+    /// //...
+    ///     assert_eq!(ParseError::new(ParseErrorKind::MissingParameters, None, "User Error: oops").get_message(), "User Error: oops");
+    /// ```
+    pub fn get_message(&self) -> &str {&self.message}
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+impl Error for ParseError {}
+
+/// every error found while parsing, ordered by phase, then by arg index
+///
+/// this is the error returned (boxed) by `Parser::new()`, `option_parser::parse_for_options()`, and `parameter_parser::parse_for_parameters()`,
+/// so it can be recovered with `downcast_ref::<ParseErrors>()`, its Display is the message of every error, one per line
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseErrors {
+    errors: Vec<ParseError>,
+}
+impl ParseErrors {
+    /// creates a new ParseErrors from `errors`, putting them in reporting order (phase, then arg index, errors about no specific arg first)
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseError, ParseErrorKind, ParseErrors};
+    /// //...
+    ///     let errors = ParseErrors::new(vec![
+    ///         ParseError::new(ParseErrorKind::MissingParameters, None, "User Error: too few args"),
+    ///         ParseError::new(ParseErrorKind::InvalidValue, Some(4), "User Error: bad value"),
+    ///         ParseError::new(ParseErrorKind::MissingValue, Some(2), "User Error: missing value"),
+    ///     ]);
+    ///     assert_eq!(errors.to_string(), "User Error: missing value\nUser Error: bad value\nUser Error: too few args");
+    /// ```
+    pub fn new(mut errors: Vec<ParseError>) -> ParseErrors {
+        errors.sort_by_key(|error| (error.get_phase(), error.arg_index));
+        ParseErrors { errors }
+    }
+
+    /// returns every error as a json array of the objects returned by `ParseError::to_json()`, in reporting order
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseError, ParseErrorKind, ParseErrors};
+    /// //...
+    ///     let errors = ParseErrors::new(vec![
+    ///         ParseError::new(ParseErrorKind::MissingParameters, None, "User Error: too few args"),
+    ///         ParseError::new(ParseErrorKind::MissingValue, Some(1), "User Error: missing value").with_flag("-F"),
+    ///     ]);
+    ///     assert_eq!(
+    ///         errors.to_json(),
+    ///         r#"[{"error":"missing_value","flag":"-F","arg_index":1,"message":"User Error: missing value"},{"error":"missing_parameters","message":"User Error: too few args"}]"#
+    ///     );
+    /// ```
+    pub fn to_json(&self) -> String {
+        format!("[{}]", self.errors.iter().map(|error| error.to_json()).collect::<Vec<String>>().join(","))
+    }
+
+    /// returns every error formatted to be shown to the user of a program defined by `spec`, that was run with `args`:
+    /// ```text
+    /// error: 2 problems with the command line
+    ///   - [unknown_flag] unknown options: '--frmat' (arg 1) (at '--frmat')
+    ///   - [missing_parameters] missing parameter: PATH (0 positional arguments given, 1 required)
+    ///
+    /// USAGE: foo.exe [OPTIONS]... <PATH>
+    /// try 'foo.exe --help' for more information
+    /// ```
+    ///
+    /// # Notes:
+    /// - each error is shown with the name of its kind (see `ParseErrorKind::get_name()`) and, if it's about a specific arg, that arg
+    /// - the `User Error: ` prefix of messages is dropped, since every error shown to the user is about them (`BUG: ` is kept)
+    /// - a single error is shown on the first line, without the count
+    /// - the program name is the first of `args`, or the title of `spec` if `args` is empty
+    /// - the hint about `--help` is only shown if `spec` has an option with that long flag
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parse_error::{ParseError, ParseErrorKind, ParseErrors}, spec::CliSpec, Parser};
+    /// //...
+    ///     let spec = CliSpec::new(
+    ///         &[ClOption::new_flag(&ClOptionInfo::new("-h", "--help", "Show help").unwrap())],
+    ///         &[ClParameter::new("PATH", "Path to search in")],
+    ///     ).with_about("foo.exe", "by Anthony Rubick", "Just here as an example");
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--frmat")];
+    ///
+    ///     let errors = ParseErrors::new(vec![
+    ///         ParseError::new(ParseErrorKind::MissingParameters, None, "User Error: too few args"),
+    ///         ParseError::new(ParseErrorKind::UnknownFlag, Some(1), "User Error: unknown options: '--frmat' (arg 1)"),
+    ///     ]);
+    ///     assert_eq!(
+    ///         errors.render(&spec, &args),
+    ///         "error: 2 problems with the command line\n  \
+    ///         - [unknown_flag] unknown options: '--frmat' (arg 1) (at '--frmat')\n  \
+    ///         - [missing_parameters] too few args\n\
+    ///         \n\
+    ///         USAGE: foo.exe [OPTIONS]... <PATH>\n\
+    ///         try 'foo.exe --help' for more information"
+    ///     );
+    ///
+    ///     //a single error has no count
+    ///     let error = Parser::new(&[String::from("foo.exe")], spec.get_valid_options(), spec.get_expected_parameters()).err().unwrap();
+    ///     assert_eq!(
+    ///         error.downcast_ref::<ParseErrors>().unwrap().render(&spec, &[String::from("foo.exe")]),
+    ///         "error: [missing_parameters] missing parameter: PATH (0 positional arguments given, 1 required)\n\
+    ///         \n\
+    ///         USAGE: foo.exe [OPTIONS]... <PATH>\n\
+    ///         try 'foo.exe --help' for more information"
+    ///     );
+    /// ```
+    pub fn render(&self, spec: &CliSpec, args: &[String]) -> String {
+        //DATA
+        let program_name: &str = args.first().map_or(spec.get_title(), |arg| arg.as_str());
+        let lines: Vec<String> = self.errors.iter().map(|error| {
+            let message: &str = error.message.strip_prefix("User Error: ").unwrap_or(&error.message);
+            match error.arg_index.and_then(|arg_index| args.get(arg_index)) {
+                Some(arg) => format!("[{}] {} (at '{}')", error.kind.get_name(), message, arg),
+                None => format!("[{}] {}", error.kind.get_name(), message),
+            }
+        }).collect();
+
+        let mut rendered: String = match lines.as_slice() {
+            [line] => format!("error: {}\n", line),
+            _ => format!("error: {} problems with the command line\n{}", lines.len(), lines.iter().map(|line| format!("  - {}\n", line)).collect::<String>()),
+        };
+        rendered += format!("\n{}", spec.usage()).as_str();
+        if spec.get_valid_options().iter().any(|option| option.get_long_flag().eq("--help")) {
+            rendered += format!("\ntry '{} --help' for more information", program_name).as_str();
+        }
+
+        rendered
+    }
+
+    /// get a reference to the errors, in reporting order
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_error::{ParseError, ParseErrorKind, ParseErrors, ValidationPhase};
+    /// //...
+    ///     let errors = ParseErrors::new(vec![ParseError::new(ParseErrorKind::MissingParameters, None, "User Error: too few args")]);
+    ///     assert_eq!(errors.get_errors()[0].get_phase(), ValidationPhase::Parameters);
+    /// ```
+    pub fn get_errors(&self) -> &Vec<ParseError> {&self.errors}
+}
+impl fmt::Display for ParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.errors.iter().map(|error| error.to_string()).collect::<Vec<String>>().join("\n"))
+    }
+}
+impl Error for ParseErrors {}
+
+/// escapes `text` so it can be put in a json string
+pub(crate) fn escape_json(text: &str) -> String {
+    //DATA
+    let mut escaped: String = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            '\r' => escaped += "\\r",
+            '\t' => escaped += "\\t",
+            c if (c as u32) < 0x20 => escaped += format!("\\u{:04x}", c as u32).as_str(),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
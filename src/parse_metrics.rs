@@ -0,0 +1,120 @@
+//! # Parse metrics
+//! 'parse_metrics' is a module containing utilities for
+//! measuring a parse, ei. how many args there were and how long each phase of parsing took, to attribute startup time
+//!
+//! metrics are only collected when opted into with `ParserSettings::metrics(true)`, nothing is timed otherwise
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use std::time::Duration;
+
+/// stores the counters and durations of a parse
+///
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+///     ];
+///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+///     let args: Vec<String> = ["foo.exe", "-r", "--format", "BULLET", "path/to/search"].iter().map(|arg| arg.to_string()).collect();
+///
+///     let parser = Parser::new_with_settings(&args, &valid_options, &expected_parameters, &ParserSettings::new().metrics(true)).unwrap();
+///     let metrics = parser.metrics().unwrap();
+///     assert_eq!(metrics.get_arg_count(), 5);
+///     assert_eq!(metrics.get_flag_count(), 2);
+///     assert_eq!(metrics.get_total(), metrics.get_option_parsing() + metrics.get_parameter_parsing() + metrics.get_validation());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParseMetrics {
+    arg_count: usize,
+    flag_count: usize,
+    option_parsing: Duration,
+    parameter_parsing: Duration,
+    validation: Duration,
+}
+impl ParseMetrics {
+    /// creates a new ParseMetrics from what was measured
+    pub(crate) fn new(arg_count: usize, flag_count: usize, option_parsing: Duration, parameter_parsing: Duration, validation: Duration) -> ParseMetrics {
+        ParseMetrics {
+            arg_count,
+            flag_count,
+            option_parsing,
+            parameter_parsing,
+            validation,
+        }
+    }
+
+    //getter methods
+    /// get how many args were parsed, including the program name (and after expanding response files)
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let parser = Parser::new_with_settings(&[String::from("foo.exe")], &[], &[], &ParserSettings::new().metrics(true)).unwrap();
+    ///     assert_eq!(parser.metrics().unwrap().get_arg_count(), 1);
+    /// ```
+    pub fn get_arg_count(&self) -> usize {self.arg_count}
+
+    /// get how many of the args were classified as flags, see `option_parser::is_flag()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let parser = Parser::new_with_settings(&[String::from("foo.exe")], &[], &[], &ParserSettings::new().metrics(true)).unwrap();
+    ///     assert_eq!(parser.metrics().unwrap().get_flag_count(), 0);
+    /// ```
+    pub fn get_flag_count(&self) -> usize {self.flag_count}
+
+    /// get how long parsing for options took, including expanding response files and applying presets
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use clia::{parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let parser = Parser::new_with_settings(&[String::from("foo.exe")], &[], &[], &ParserSettings::new().metrics(true)).unwrap();
+    ///     assert!(parser.metrics().unwrap().get_option_parsing() < Duration::from_secs(1));
+    /// ```
+    pub fn get_option_parsing(&self) -> Duration {self.option_parsing}
+
+    /// get how long parsing for parameters took
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use clia::{parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let parser = Parser::new_with_settings(&[String::from("foo.exe")], &[], &[], &ParserSettings::new().metrics(true)).unwrap();
+    ///     assert!(parser.metrics().unwrap().get_parameter_parsing() < Duration::from_secs(1));
+    /// ```
+    pub fn get_parameter_parsing(&self) -> Duration {self.parameter_parsing}
+
+    /// get how long everything after parsing took, ei. finding warnings and duplicated flags
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use clia::{parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let parser = Parser::new_with_settings(&[String::from("foo.exe")], &[], &[], &ParserSettings::new().metrics(true)).unwrap();
+    ///     assert!(parser.metrics().unwrap().get_validation() < Duration::from_secs(1));
+    /// ```
+    pub fn get_validation(&self) -> Duration {self.validation}
+
+    /// get how long the whole parse took, the sum of every phase
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let parser = Parser::new_with_settings(&[String::from("foo.exe")], &[], &[], &ParserSettings::new().metrics(true)).unwrap();
+    ///     assert!(parser.metrics().unwrap().get_total() >= parser.metrics().unwrap().get_option_parsing());
+    /// ```
+    pub fn get_total(&self) -> Duration {self.option_parsing + self.parameter_parsing + self.validation}
+}
@@ -0,0 +1,98 @@
+//! # Parse report
+//! 'parse_report' is a module containing utilities for
+//! auditing a successful parse, ei. finding out which machinery produced the final values, not just what they are
+//!
+//! reports are only collected when opted into with `ParserSettings::with_report(true)`
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use crate::{constraints::Constraint, value_parser::ValueParser};
+
+/// something that happened during a parse
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReportEntry {
+    /// the preset of a ClOption::FlagPreset was applied
+    PresetApplied {
+        /// the flag of the FlagPreset
+        flag: String,
+        /// the flags of the options the preset set, options the user set themselves aren't overridden so aren't included
+        applied_to: Vec<String>,
+    },
+    /// the list/data of an option was checked against its allowed values, and passed
+    ValuesChecked {
+        /// the flag of the option
+        flag: String,
+        /// the values that were checked, as they're stored after matching
+        values: Vec<String>,
+    },
+    /// the list/data of an option that wasn't given was provided by a value provider, see `ParserSettings::with_value_provider()`
+    ValueProvided {
+        /// the flag of the option
+        flag: String,
+        /// the name of the provider
+        provider: String,
+    },
+    /// the data of a FlagData that wasn't given was produced by its lazy default, see `ClOption::default_with()`
+    DefaultApplied {
+        /// the flag of the option
+        flag: String,
+        /// the value the default produced
+        value: String,
+    },
+    /// the list/data of an option was checked by its value parser, and passed, see `ClOption::with_value_parser()`
+    ValidatorPassed {
+        /// the flag of the option
+        flag: String,
+        /// the value parser that checked it
+        validator: ValueParser,
+    },
+    /// a constraint was evaluated, and passed, see `ParserSettings::with_constraints()`
+    ConstraintPassed(Constraint),
+    /// the value of a parameter, or the list/data of a required option, was asked for because it wasn't given, see `Parser::parse_or_prompt()`
+    Prompted {
+        /// the name of the parameter, or the flag of the option
+        name: String,
+    },
+    /// a warning was emitted, see `Parser::get_warnings()`
+    Warning(String),
+}
+
+/// stores every `ReportEntry` of a parse, in the order they happened
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParseReport {
+    entries: Vec<ReportEntry>,
+}
+impl ParseReport {
+    /// creates a new, empty, ParseReport
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parse_report::ParseReport;
+    /// //...
+    ///     assert!(ParseReport::new().get_entries().is_empty());
+    /// ```
+    pub fn new() -> ParseReport {
+        ParseReport::default()
+    }
+
+    /// adds `entry` to the end of the report
+    pub(crate) fn push(&mut self, entry: ReportEntry) {
+        self.entries.push(entry);
+    }
+
+    //getter methods
+    /// get a reference to every entry of the report, in the order they happened
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parse_report::ReportEntry, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-r")];
+    ///
+    ///     let parser = Parser::new_with_settings(&args, &valid_options, &[], &ParserSettings::new().with_report(true)).unwrap();
+    ///     assert!(parser.report().unwrap().get_entries().is_empty()); //nothing interesting happened
+    /// ```
+    pub fn get_entries(&self) -> &Vec<ReportEntry> {&self.entries}
+}
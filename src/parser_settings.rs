@@ -0,0 +1,954 @@
+//! # Parser settings
+//! 'parser_settings' is a module containing utilities for
+//! customizing how CLI Arguments are parsed
+//!
+//! the defaults reproduce the behavior of `Parser::new()`, settings are opted into one at a time
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{artifact::Artifact, constraints::Constraints, expansion, messages::Messages, option_args::{ClOption, ClOptionInfo, LazyDefault}, prompt, suggest};
+
+/// what `Parser::new_with_settings()` does with stray args, args before the parameters that aren't flags, or the list/data of a flag,
+/// ei. `extra` in `foo.exe -f rs,toml extra -F NUMERIC path`, see `option_parser::find_stray_args()`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StrayArgs {
+    /// stray args are ignored, so what the user typed in them is dropped without a word, only use this if that's what you want
+    Ignore,
+    /// every stray arg is an error, of kind `ParseErrorKind::UnexpectedArgument`
+    #[default]
+    Error,
+    /// stray args are moved, in order, to just before the parameters, so they can be parsed as parameters
+    Positional,
+}
+
+/// looks up the list/data of an option, see `ValueProvider`
+type ProvideFn = dyn Fn(&ClOptionInfo) -> Option<String> + Send + Sync;
+
+/// a named fallback for the list/data of options that weren't given, ei. one that looks them up in a keychain, see `ParserSettings::with_value_provider()`
+/// 
+/// ValueProviders are equal if they have the same name and share the same closure
+#[derive(Clone)]
+pub struct ValueProvider {
+    name: String,
+    provider: Arc<ProvideFn>,
+}
+impl ValueProvider {
+    /// creates a new ValueProvider named `name`, that looks up the list/data of an option with `provider`
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::ClOptionInfo, parser_settings::ValueProvider};
+    /// //...
+    ///     let provider = ValueProvider::new("keychain", |info: &ClOptionInfo| (info.get_long_flag() == "--token").then(|| String::from("hunter2")));
+    ///     assert_eq!(provider.get_name(), "keychain");
+    ///     assert_eq!(provider.provide(&ClOptionInfo::new("-t", "--token", "Token to log in with").unwrap()), Some(String::from("hunter2")));
+    ///     assert_eq!(provider.provide(&ClOptionInfo::new("-u", "--user", "User to log in as").unwrap()), None);
+    ///     assert_eq!(provider, provider.clone());
+    /// ```
+    pub fn new(name: &str, provider: impl Fn(&ClOptionInfo) -> Option<String> + Send + Sync + 'static) -> ValueProvider {
+        ValueProvider { name: name.to_string(), provider: Arc::new(provider) }
+    }
+
+    /// runs the closure, returning the list/data it provides for the option with `info`, if any
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::ClOptionInfo, parser_settings::ValueProvider};
+    /// //...
+    ///     let provider = ValueProvider::new("fixed", |_: &ClOptionInfo| Some(String::from("8")));
+    ///     assert_eq!(provider.provide(&ClOptionInfo::new("-j", "--jobs", "How many jobs to run at once").unwrap()), Some(String::from("8")));
+    /// ```
+    pub fn provide(&self, info: &ClOptionInfo) -> Option<String> {
+        (self.provider)(info)
+    }
+
+    /// get the name of this ValueProvider
+    /// 
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::ClOptionInfo, parser_settings::ValueProvider};
+    /// //...
+    ///     assert_eq!(ValueProvider::new("keychain", |_: &ClOptionInfo| None).get_name(), "keychain");
+    /// ```
+    pub fn get_name(&self) -> &str {&self.name}
+}
+impl fmt::Debug for ValueProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ValueProvider({:?})", self.name)
+    }
+}
+impl PartialEq for ValueProvider {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.eq(&other.name) && Arc::ptr_eq(&self.provider, &other.provider)
+    }
+}
+
+/// stores the settings used when parsing CLI Arguments
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParserSettings {
+    trim_flag_whitespace: bool,
+    allow_unknown_flags: bool,
+    allow_unmatched_args: bool,
+    keep_raw_args: bool,
+    with_report: bool,
+    subcommands: Vec<String>,
+    correct_dash_mistakes: bool,
+    normalize_unicode_dashes: bool,
+    subcommand_options: Vec<(String, Vec<ClOption>)>,
+    expand_response_files: bool,
+    max_expansion_depth: usize,
+    metrics: bool,
+    label: String,
+    stdin: Option<LazyDefault>,
+    generators: Vec<Artifact>,
+    disable_prompts: bool,
+    max_prompt_attempts: usize,
+    messages: Messages,
+    stray_args: StrayArgs,
+    value_providers: Vec<ValueProvider>,
+    max_suggestions: usize,
+    constraints: Constraints,
+}
+impl Default for ParserSettings {
+    fn default() -> Self {
+        ParserSettings {
+            trim_flag_whitespace: false,
+            allow_unknown_flags: false,
+            allow_unmatched_args: false,
+            keep_raw_args: false,
+            with_report: false,
+            subcommands: Vec::new(),
+            correct_dash_mistakes: false,
+            normalize_unicode_dashes: false,
+            subcommand_options: Vec::new(),
+            expand_response_files: false,
+            max_expansion_depth: expansion::DEFAULT_MAX_DEPTH,
+            metrics: false,
+            label: String::new(),
+            stdin: None,
+            generators: Vec::new(),
+            disable_prompts: false,
+            max_prompt_attempts: prompt::DEFAULT_MAX_ATTEMPTS,
+            messages: Messages::new(),
+            stray_args: StrayArgs::Error,
+            value_providers: Vec::new(),
+            max_suggestions: suggest::DEFAULT_MAX_SUGGESTIONS,
+            constraints: Constraints::new(),
+        }
+    }
+}
+impl ParserSettings {
+    /// creates a new ParserSettings with every setting at its default
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new();
+    ///     assert_eq!(settings, ParserSettings::default());
+    /// ```
+    pub fn new() -> ParserSettings {
+        ParserSettings::default()
+    }
+
+    //setter methods
+    /// if `trim` is true, trailing whitespace is removed from flag tokens (args starting with `-`) before they're matched,
+    /// so `"--recursive "` is treated as `"--recursive"`
+    ///
+    /// this is useful for args sourced from config files or scripts with sloppy quoting, defaults to false
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().trim_flag_whitespace(true);
+    ///     assert!(settings.get_trim_flag_whitespace());
+    /// ```
+    pub fn trim_flag_whitespace(mut self, trim: bool) -> ParserSettings {
+        self.trim_flag_whitespace = trim;
+        self
+    }
+
+    /// if `allow` is true, flags in the args that aren't defined by any valid option are ignored instead of causing an error
+    ///
+    /// this is useful for a first pass over args that may contain options you don't know about yet (ei. options contributed by plugins), defaults to false
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().allow_unknown_flags(true);
+    ///     assert!(settings.get_allow_unknown_flags());
+    /// ```
+    pub fn allow_unknown_flags(mut self, allow: bool) -> ParserSettings {
+        self.allow_unknown_flags = allow;
+        self
+    }
+
+    /// if `allow` is true, positionals left over after every expected parameter was given one are ignored instead of causing an error,
+    /// the parameters take the last positionals, and the ones before them can be accessed with `Parser::get_unmatched_arguments()`
+    ///
+    /// this keeps the lenient behavior of older versions, defaults to false (unmatched args are always allowed if `allow_unknown_flags` is true)
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let args: Vec<String> = ["foo.exe", "recursive", "src"].iter().map(|arg| arg.to_string()).collect();
+    ///
+    ///     assert!(Parser::new(&args, &[], &expected_parameters).is_err());
+    ///
+    ///     let settings = ParserSettings::new().allow_unmatched_args(true);
+    ///     assert!(settings.get_allow_unmatched_args());
+    ///     let parser = Parser::new_with_settings(&args, &[], &expected_parameters, &settings).unwrap();
+    ///     assert_eq!(parser.get_parameter_arguments_found()[0].get_data(), "src");
+    ///     assert_eq!(parser.get_unmatched_arguments(), &vec![String::from("recursive")]);
+    /// ```
+    pub fn allow_unmatched_args(mut self, allow: bool) -> ParserSettings {
+        self.allow_unmatched_args = allow;
+        self
+    }
+
+    /// if `keep` is true, the Parser keeps a copy of the args it parsed, so they can be accessed with `Parser::raw_args()`
+    /// and parsed again with `Parser::reparse_with()`, defaults to false
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().keep_raw_args(true);
+    ///     assert!(settings.get_keep_raw_args());
+    /// ```
+    pub fn keep_raw_args(mut self, keep: bool) -> ParserSettings {
+        self.keep_raw_args = keep;
+        self
+    }
+
+    /// if `report` is true, the Parser collects a `parse_report::ParseReport` of what happened during the parse (ei. which presets were applied),
+    /// which can be accessed with `Parser::report()`, defaults to false
+    ///
+    /// nothing is collected when this is false
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().with_report(true);
+    ///     assert!(settings.get_with_report());
+    /// ```
+    pub fn with_report(mut self, report: bool) -> ParserSettings {
+        self.with_report = report;
+        self
+    }
+
+    /// sets the names of the subcommands of your program, if the first arg after the program name is one of them,
+    /// it can be accessed with `Parser::subcommand()`, defaults to none
+    ///
+    /// this doesn't otherwise change how args are parsed, the subcommand is just a way to branch before using the options and parameters as usual
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().subcommands(&["build", "test"]);
+    ///     assert_eq!(settings.get_subcommands(), &vec![String::from("build"), String::from("test")]);
+    /// ```
+    pub fn subcommands(mut self, names: &[&str]) -> ParserSettings {
+        self.subcommands = names.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// sets the options that are only valid after the subcommand `name` (which is added to the subcommands, see `ParserSettings::subcommands()`)
+    ///
+    /// when the args start with `name`, these options are parsed along with the usual valid options (and found after them),
+    /// and if one of them is given before `name`, the error says to move it after `name`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings};
+    /// //...
+    ///     let add_options = vec![ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Files to add").unwrap(), "FILES")];
+    ///     let settings = ParserSettings::new().subcommand_options("add", &add_options);
+    ///     assert_eq!(settings.get_subcommands(), &vec![String::from("add")]);
+    ///     assert_eq!(settings.get_subcommand_options("add"), Some(&add_options));
+    /// ```
+    pub fn subcommand_options(mut self, name: &str, options: &[ClOption]) -> ParserSettings {
+        if !self.subcommands.iter().any(|subcommand| subcommand.eq(name)) {
+            self.subcommands.push(name.to_string());
+        }
+        self.subcommand_options.retain(|(subcommand, _)| subcommand.ne(name));
+        self.subcommand_options.push((name.to_string(), Vec::from(options)));
+        self
+    }
+
+    /// if `correct` is true, flags with the wrong number of dashes (ei. `-recursive` for `--recursive`, or `--r` for `-r`) are accepted as the flag they were meant to be,
+    /// and the Parser warns about them (see `option_parser::find_dash_mistakes()`), instead of causing an error, defaults to false
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().correct_dash_mistakes(true);
+    ///     assert!(settings.get_correct_dash_mistakes());
+    /// ```
+    pub fn correct_dash_mistakes(mut self, correct: bool) -> ParserSettings {
+        self.correct_dash_mistakes = correct;
+        self
+    }
+
+    /// if `normalize` is true, flags typed with dashes that aren't ASCII (ei. `–recursive` or `—format`, as pasted from a document) are accepted as the flag they were meant to be,
+    /// instead of causing an error, defaults to false
+    ///
+    /// dashes in the middle of an arg, and the list/data of a flag, are never changed
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-t", "--title", "Title of the output").unwrap(), "TITLE"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let args: Vec<String> = ["foo.exe", "\u{2013}recursive", "\u{2014}title", "\u{2014}draft", "path"].iter().map(|arg| arg.to_string()).collect();
+    ///     
+    ///     let settings = ParserSettings::new().normalize_unicode_dashes(true);
+    ///     assert!(settings.get_normalize_unicode_dashes());
+    ///     let parser = Parser::new_with_settings(&args, &valid_options, &expected_parameters, &settings).unwrap();
+    ///     assert!(parser.get_flag("recursive"));
+    ///     assert_eq!(parser.get_one("title"), Some("\u{2014}draft")); //the data is left alone
+    ///     assert_eq!(parser.get_one("PATH"), Some("path"));
+    ///     
+    ///     //by default, they're errors
+    ///     assert!(Parser::new(&args, &valid_options, &expected_parameters).is_err());
+    /// ```
+    pub fn normalize_unicode_dashes(mut self, normalize: bool) -> ParserSettings {
+        self.normalize_unicode_dashes = normalize;
+        self
+    }
+
+    /// if `expand` is true, args starting with `@` (ei. `@path/to/file`) are replaced with the whitespace separated args in that file, before anything else is parsed,
+    /// this includes args in presets, defaults to false
+    ///
+    /// response files can include other response files, see `ParserSettings::max_expansion_depth()`
+    ///
+    /// # Examples
+    /// ```
+    /// use std::fs;
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let path = std::env::temp_dir().join("clia_doctest_expand_response_files.args");
+    ///     fs::write(&path, "--recursive path/to/search").unwrap();
+    ///
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), format!("@{}", path.display())];
+    ///
+    ///     let parser = Parser::new_with_settings(&args, &valid_options, &expected_parameters, &ParserSettings::new().expand_response_files(true)).unwrap();
+    ///     assert!(parser.get_option_arguments_found()[0].get_present());
+    ///     assert_eq!(parser.get_parameter_arguments_found()[0].get_data(), "path/to/search");
+    /// ```
+    pub fn expand_response_files(mut self, expand: bool) -> ParserSettings {
+        self.expand_response_files = expand;
+        self
+    }
+
+    /// sets how many response files and presets can be nested inside each other (ei. a preset that includes a preset is nested twice), defaults to `expansion::DEFAULT_MAX_DEPTH` (8)
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_preset(&ClOptionInfo::new("", "--all", "All the checks").unwrap(), &["--fast", "--docs"]),
+    ///         ClOption::new_flag_preset(&ClOptionInfo::new("", "--fast", "The fast checks").unwrap(), &["--lint"]),
+    ///         ClOption::new_flag(&ClOptionInfo::new("", "--lint", "Lint").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("", "--docs", "Check docs").unwrap()),
+    ///     ];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--all")];
+    ///
+    ///     //presets that include presets, within the limit
+    ///     let parser = Parser::new(&args, &valid_options, &[]).unwrap();
+    ///     assert!(parser.get_option_arguments_found().iter().all(|option| option.get_present()));
+    ///
+    ///     //but exceeding it
+    ///     let error = Parser::new_with_settings(&args, &valid_options, &[], &ParserSettings::new().max_expansion_depth(1)).err().unwrap();
+    ///     assert_eq!(error.to_string(), "BUG: presets are nested too deeply (the limit is 1): --all -> --fast");
+    /// ```
+    pub fn max_expansion_depth(mut self, depth: usize) -> ParserSettings {
+        self.max_expansion_depth = depth;
+        self
+    }
+
+    /// if `metrics` is true, the Parser counts the args and times each phase of parsing, which can be accessed with `Parser::metrics()`, defaults to false
+    ///
+    /// nothing is counted or timed when this is false
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().metrics(true);
+    ///     assert!(settings.get_metrics());
+    /// ```
+    pub fn metrics(mut self, metrics: bool) -> ParserSettings {
+        self.metrics = metrics;
+        self
+    }
+
+    /// sets a name for the Parser, ei. the name of the plugin whose options it parses, used to title its section in `Parser::combined_help()`, defaults to none
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().label("pretty");
+    ///     assert_eq!(settings.get_label(), "pretty");
+    /// ```
+    pub fn label(mut self, label: &str) -> ParserSettings {
+        self.label = label.to_string();
+        self
+    }
+
+    /// sets a closure that produces what's read from stdin, instead of reading the real stdin, ei. for testing `ClParameter::stdin_if_dash()`, defaults to the real stdin
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().stdin_with(|| String::from("needle"));
+    ///     assert_eq!(settings.get_stdin().unwrap().produce(), "needle");
+    /// ```
+    pub fn stdin_with(mut self, stdin: impl Fn() -> String + Send + Sync + 'static) -> ParserSettings {
+        self.stdin = Some(LazyDefault::new(stdin));
+        self
+    }
+
+    /// sets the artifacts that can be generated with a `--generate <ARTIFACT>` option, which is added to the options parsed by `Parser::new_or_generate()`, defaults to none
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{artifact::Artifact, parser_settings::ParserSettings};
+    /// //...
+    ///     let settings = ParserSettings::new().with_generators(&[Artifact::Help, Artifact::FishCompletions]);
+    ///     assert_eq!(settings.get_generators(), &vec![Artifact::Help, Artifact::FishCompletions]);
+    /// ```
+    pub fn with_generators(mut self, generators: &[Artifact]) -> ParserSettings {
+        self.generators = generators.to_vec();
+        self
+    }
+
+    /// if `disable` is true, `Parser::parse_or_prompt()` never prompts for missing parameters or options, and fails like `Parser::new_with_settings()` instead, defaults to false
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().disable_prompts(true);
+    ///     assert!(settings.get_disable_prompts());
+    /// ```
+    pub fn disable_prompts(mut self, disable: bool) -> ParserSettings {
+        self.disable_prompts = disable;
+        self
+    }
+
+    /// sets how many times `Parser::parse_or_prompt()` asks for a missing parameter or option before giving up, when the values typed aren't valid, defaults to `prompt::DEFAULT_MAX_ATTEMPTS`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     let settings = ParserSettings::new().max_prompt_attempts(1);
+    ///     assert_eq!(settings.get_max_prompt_attempts(), 1);
+    /// ```
+    pub fn max_prompt_attempts(mut self, attempts: usize) -> ParserSettings {
+        self.max_prompt_attempts = attempts;
+        self
+    }
+
+    /// sets the templates used to reword the messages of errors, ei. to translate them, see `messages::Messages`, defaults to the built-in english messages
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{messages::Messages, parse_error::ParseErrorKind, parser_settings::ParserSettings};
+    /// //...
+    ///     let settings = ParserSettings::new().with_messages(Messages::new().with_template(ParseErrorKind::UnknownFlag, "option inconnue : {flag}"));
+    ///     assert_eq!(settings.get_messages().get_template(ParseErrorKind::UnknownFlag), Some("option inconnue : {flag}"));
+    /// ```
+    pub fn with_messages(mut self, messages: Messages) -> ParserSettings {
+        self.messages = messages;
+        self
+    }
+
+    /// sets what's done with stray args in the options, see `StrayArgs`, defaults to `StrayArgs::Error`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::{ParserSettings, StrayArgs}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let parse = |args: &[&str], stray_args: StrayArgs| {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         Parser::new_with_settings(&args, &valid_options, &expected_parameters, &ParserSettings::new().stray_args(stray_args))
+    ///     };
+    ///     let one_stray = ["foo.exe", "-f", "rs,toml", "extra", "-F", "NUMERIC", "path"];
+    ///     let two_strays = ["foo.exe", "first", "-f", "rs,toml", "second", "-F", "NUMERIC", "path"];
+    ///     
+    ///     //ignored, if you ask for it
+    ///     assert_eq!(parse(&one_stray, StrayArgs::Ignore).unwrap().get_one("PATH"), Some("path"));
+    ///     
+    ///     //errors
+    ///     assert_eq!(
+    ///         parse(&one_stray, StrayArgs::Error).err().unwrap().to_string(),
+    ///         "User Error: unexpected argument 'extra' (arg 3) before options finished"
+    ///     );
+    ///     assert_eq!(
+    ///         parse(&two_strays, StrayArgs::Error).err().unwrap().to_string(),
+    ///         "User Error: unexpected argument 'first' (arg 1) before options finished\nUser Error: unexpected argument 'second' (arg 4) before options finished"
+    ///     );
+    ///     
+    ///     //by default, so nothing the user typed is dropped
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let args: Vec<String> = ["p", "path", "-r", "query"].iter().map(|arg| arg.to_string()).collect();
+    ///     assert_eq!(
+    ///         Parser::new(&args, &valid_options, &expected_parameters).err().unwrap().to_string(),
+    ///         "User Error: unexpected argument 'path' (arg 1) before options finished"
+    ///     );
+    ///     
+    ///     //moved to the parameters, which only take one, so the others are unexpected, where they were given
+    ///     assert_eq!(
+    ///         parse(&two_strays, StrayArgs::Positional).err().unwrap().to_string(),
+    ///         "User Error: unexpected argument 'second' (arg 4), every parameter was already given\nUser Error: unexpected argument 'path' (arg 7), every parameter was already given"
+    ///     );
+    /// ```
+    ///
+    /// stray args that are moved are parsed as parameters in the order they were given, and the values of options are never stray
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::{ParserSettings, StrayArgs}, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![
+    ///         ClParameter::new("PATH", "Path to search in"),
+    ///         ClParameter::new("QUERY", "String to search for"),
+    ///         ClParameter::new("LIMIT", "Most results to show"),
+    ///     ];
+    ///     let settings = ParserSettings::new().stray_args(StrayArgs::Positional);
+    ///     let args: Vec<String> = ["foo.exe", "-F", "NUMERIC", "src", "-f", "rs,toml", "needle", "-r", "10"].iter().map(|arg| arg.to_string()).collect();
+    ///     
+    ///     let parser = Parser::new_with_settings(&args, &valid_options, &expected_parameters, &settings).unwrap();
+    ///     assert_eq!(parser.get_one("PATH"), Some("src"));
+    ///     assert_eq!(parser.get_one("QUERY"), Some("needle"));
+    ///     assert_eq!(parser.get_one("LIMIT"), Some("10"));
+    ///     assert_eq!(parser.get_one("format"), Some("NUMERIC"));
+    ///     assert_eq!(parser.get_many("filter"), Some(&[String::from("rs"), String::from("toml")][..]));
+    ///     
+    ///     //without stray args, nothing changes
+    ///     let args: Vec<String> = ["foo.exe", "-F", "NUMERIC", "-f", "rs,toml", "-r", "src", "needle", "10"].iter().map(|arg| arg.to_string()).collect();
+    ///     assert!(Parser::new_with_settings(&args, &valid_options, &expected_parameters, &settings.clone().stray_args(StrayArgs::Error)).is_ok());
+    ///     assert_eq!(
+    ///         Parser::new_with_settings(&args, &valid_options, &expected_parameters, &settings).unwrap().get_one("QUERY"),
+    ///         Parser::new(&args, &valid_options, &expected_parameters).unwrap().get_one("QUERY")
+    ///     );
+    /// ```
+    pub fn stray_args(mut self, stray_args: StrayArgs) -> ParserSettings {
+        self.stray_args = stray_args;
+        self
+    }
+
+    /// adds a fallback named `name` for the list/data of options that weren't given, ei. one that looks them up in a keychain,
+    /// providers are asked in the order they were added, after presets are applied, and before lazy defaults (see `ClOption::default_with()`)
+    /// 
+    /// # Notes:
+    /// - providers are only asked about FlagLists and FlagDatas that aren't present, and stop being asked once one provides a value
+    /// - provided values are checked, and split into lists, like values given in the args, and the option is present
+    /// - errors in provided values don't have an arg index, and their message names the provider
+    /// - each value provided is in the report, see `ParserSettings::with_report()`
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parse_report::ReportEntry, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-t", "--token", "Token to log in with").unwrap(), "TOKEN"),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-u", "--user", "User to log in as").unwrap(), "USER"),
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-s", "--scopes", "Scopes to request").unwrap(), "SCOPES"),
+    ///     ];
+    ///     let asked: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    ///     let keychain_asked = asked.clone();
+    ///     let settings = ParserSettings::new()
+    ///         .with_value_provider("keychain", move |info: &ClOptionInfo| {
+    ///             keychain_asked.lock().unwrap().push(format!("keychain {}", info.get_long_flag()));
+    ///             (info.get_long_flag() == "--token").then(|| String::from("from-keychain"))
+    ///         })
+    ///         .with_value_provider("service", |info: &ClOptionInfo| match info.get_long_flag() {
+    ///             "--token" => Some(String::from("from-service")),
+    ///             "--scopes" => Some(String::from("read,write")),
+    ///             _ => None,
+    ///         })
+    ///         .with_report(true);
+    ///     
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("--user"), String::from("anthony")];
+    ///     let parser = Parser::new_with_settings(&args, &valid_options, &[], &settings).unwrap();
+    ///     
+    ///     //the first provider to provide a value wins
+    ///     assert_eq!(parser.get_one("token"), Some("from-keychain"));
+    ///     //and lists are split
+    ///     assert_eq!(parser.get_many("scopes"), Some(&[String::from("read"), String::from("write")][..]));
+    ///     //options that were given aren't asked about
+    ///     assert_eq!(parser.get_one("user"), Some("anthony"));
+    ///     assert_eq!(*asked.lock().unwrap(), vec![String::from("keychain --token"), String::from("keychain --scopes")]);
+    ///     
+    ///     assert!(parser.report().unwrap().get_entries().contains(&ReportEntry::ValueProvided { flag: String::from("--token"), provider: String::from("keychain") }));
+    /// ```
+    /// 
+    /// provided values are checked like values given in the args
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings, value_parser::ValueParser, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-j", "--jobs", "How many jobs to run at once").unwrap(), "JOBS").with_value_parser(ValueParser::IntParser),
+    ///     ];
+    ///     let settings = ParserSettings::new().with_value_provider("config service", |_: &ClOptionInfo| Some(String::from("lots")));
+    ///     
+    ///     assert_eq!(
+    ///         Parser::new_with_settings(&[String::from("foo.exe")], &valid_options, &[], &settings).err().unwrap().to_string(),
+    ///         "User Error: invalid value 'lots' for flag(--jobs): expected an integer (provided by config service)"
+    ///     );
+    /// ```
+    pub fn with_value_provider(mut self, name: &str, provider: impl Fn(&ClOptionInfo) -> Option<String> + Send + Sync + 'static) -> ParserSettings {
+        self.value_providers.push(ValueProvider::new(name, provider));
+        self
+    }
+
+    /// sets how many invalid values get a suggestion (ei. `did you mean 'MARKDOWN'?`) in a parse, the errors of any after that don't have one,
+    /// so a command line with hundreds of invalid values doesn't take long to report, defaults to `suggest::DEFAULT_MAX_SUGGESTIONS`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--formats", "Formats to output").unwrap(), "FORMATS").with_allowed_values(&["BULLET", "MARKDOWN"]),
+    ///     ];
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-f"), String::from("markdwn,bulet")];
+    ///     
+    ///     let errors = Parser::new_with_settings(&args, &valid_options, &[], &ParserSettings::new().max_suggestions(1)).err().unwrap().to_string();
+    ///     assert_eq!(
+    ///         errors,
+    ///         "User Error: invalid value 'markdwn' for flag(-f), did you mean 'MARKDOWN'? possible values are: BULLET, MARKDOWN\nUser Error: invalid value 'bulet' for flag(-f), possible values are: BULLET, MARKDOWN"
+    ///     );
+    /// ```
+    ///
+    /// lots of invalid values
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     //500 allowed values, ei. VALUE-AA, VALUE-AB, ...
+    ///     let allowed: Vec<String> = (0..500_u32).map(|index| format!("VALUE-{}", [index / 26, index % 26].iter().map(|letter| char::from_u32('A' as u32 + letter).unwrap()).collect::<String>())).collect();
+    ///     let allowed: Vec<&str> = allowed.iter().map(|value| value.as_str()).collect();
+    ///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag_list(&ClOptionInfo::new("-v", "--values", "Values to use").unwrap(), "VALUES").with_allowed_values(&allowed)];
+    ///     
+    ///     //200 invalid values, each one typo away from an allowed value
+    ///     let typos: Vec<String> = allowed[..200].iter().map(|value| value.replacen("VALUE", "VALEU", 1)).collect();
+    ///     let args: Vec<String> = vec![String::from("foo.exe"), String::from("-v"), typos.join(",")];
+    ///     
+    ///     let start = std::time::Instant::now();
+    ///     let errors = Parser::new_with_settings(&args, &valid_options, &[], &ParserSettings::new()).err().unwrap().to_string();
+    ///     assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    ///     
+    ///     let errors: Vec<&str> = errors.lines().collect();
+    ///     assert_eq!(errors.len(), 200);
+    ///     for (index, error) in errors.iter().enumerate() {
+    ///         assert_eq!(error.contains(&format!("did you mean '{}'?", allowed[index])), index < 10, "{}", error);
+    ///     }
+    /// ```
+    pub fn max_suggestions(mut self, max: usize) -> ParserSettings {
+        self.max_suggestions = max;
+        self
+    }
+
+    /// sets the constraints checked after parsing, every one that's broken is an error, like `Constraints::evaluate()`, defaults to none
+    ///
+    /// # Notes:
+    /// - constraints are checked once the options are parsed without errors, so they're reported along with any errors in the parameters
+    /// - help takes priority over broken constraints, like it does over other errors
+    /// - the constraints that pass are added to the report, see `ParserSettings::with_report()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{constraints::{Constraint, Constraints}, option_args::{ClOption, ClOptionInfo}, parser_settings::ParserSettings, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-j", "--json", "Output json").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-p", "--pretty", "Pretty print the output").unwrap()),
+    ///     ];
+    ///     let settings = ParserSettings::new().with_constraints(Constraints::new().with(Constraint::Conflicts(String::from("json"), String::from("pretty"))));
+    ///     let to_args = |args: &[&str]| -> Vec<String> {args.iter().map(|arg| arg.to_string()).collect()};
+    ///     
+    ///     assert!(Parser::new_with_settings(&to_args(&["foo.exe", "-j"]), &valid_options, &[], &settings).is_ok());
+    ///     assert_eq!(
+    ///         Parser::new_with_settings(&to_args(&["foo.exe", "-j", "-p"]), &valid_options, &[], &settings).err().unwrap().to_string(),
+    ///         "User Error: flag(--json) can't be used with flag(--pretty)"
+    ///     );
+    /// ```
+    pub fn with_constraints(mut self, constraints: Constraints) -> ParserSettings {
+        self.constraints = constraints;
+        self
+    }
+
+    //getter methods
+    /// get the value of `trim_flag_whitespace`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(!ParserSettings::new().get_trim_flag_whitespace());
+    /// ```
+    pub fn get_trim_flag_whitespace(&self) -> bool {self.trim_flag_whitespace}
+
+    /// get the value of `allow_unknown_flags`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(!ParserSettings::new().get_allow_unknown_flags());
+    /// ```
+    pub fn get_allow_unknown_flags(&self) -> bool {self.allow_unknown_flags}
+
+    /// get the value of `allow_unmatched_args`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(!ParserSettings::new().get_allow_unmatched_args());
+    /// ```
+    pub fn get_allow_unmatched_args(&self) -> bool {self.allow_unmatched_args}
+
+    /// get the value of `keep_raw_args`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(!ParserSettings::new().get_keep_raw_args());
+    /// ```
+    pub fn get_keep_raw_args(&self) -> bool {self.keep_raw_args}
+
+    /// get the value of `with_report`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(!ParserSettings::new().get_with_report());
+    /// ```
+    pub fn get_with_report(&self) -> bool {self.with_report}
+
+    /// get a reference to `subcommands`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(ParserSettings::new().get_subcommands().is_empty());
+    /// ```
+    pub fn get_subcommands(&self) -> &Vec<String> {&self.subcommands}
+
+    /// get the value of `correct_dash_mistakes`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(!ParserSettings::new().get_correct_dash_mistakes());
+    /// ```
+    pub fn get_correct_dash_mistakes(&self) -> bool {self.correct_dash_mistakes}
+
+    /// get the value of `normalize_unicode_dashes`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(!ParserSettings::new().get_normalize_unicode_dashes());
+    /// ```
+    pub fn get_normalize_unicode_dashes(&self) -> bool {self.normalize_unicode_dashes}
+
+    /// get a reference to the options that are only valid after the subcommand `name`
+    /// # None
+    /// - returns none if no options were set for `name` with `ParserSettings::subcommand_options()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert_eq!(ParserSettings::new().get_subcommand_options("add"), None);
+    /// ```
+    pub fn get_subcommand_options(&self, name: &str) -> Option<&Vec<ClOption>> {
+        self.subcommand_options.iter().find(|(subcommand, _)| subcommand.eq(name)).map(|(_, options)| options)
+    }
+
+    /// get a reference to the options of every subcommand, paired with the subcommands name
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(ParserSettings::new().get_all_subcommand_options().is_empty());
+    /// ```
+    pub fn get_all_subcommand_options(&self) -> &Vec<(String, Vec<ClOption>)> {&self.subcommand_options}
+
+    /// get the value of `expand_response_files`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(!ParserSettings::new().get_expand_response_files());
+    /// ```
+    pub fn get_expand_response_files(&self) -> bool {self.expand_response_files}
+
+    /// get the value of `max_expansion_depth`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert_eq!(ParserSettings::new().get_max_expansion_depth(), 8);
+    /// ```
+    pub fn get_max_expansion_depth(&self) -> usize {self.max_expansion_depth}
+
+    /// get the value of `metrics`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(!ParserSettings::new().get_metrics());
+    /// ```
+    pub fn get_metrics(&self) -> bool {self.metrics}
+
+    /// get a reference to `label`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert_eq!(ParserSettings::new().get_label(), "");
+    /// ```
+    pub fn get_label(&self) -> &str {&self.label}
+
+    /// get a reference to what replaces stdin, see `ParserSettings::stdin_with()`
+    ///
+    /// # None
+    /// - the real stdin is used
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(ParserSettings::new().get_stdin().is_none());
+    /// ```
+    pub fn get_stdin(&self) -> Option<&LazyDefault> {self.stdin.as_ref()}
+
+    /// get a reference to the artifacts that can be generated, see `ParserSettings::with_generators()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(ParserSettings::new().get_generators().is_empty());
+    /// ```
+    pub fn get_generators(&self) -> &Vec<Artifact> {&self.generators}
+
+    /// get the value of `disable_prompts`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert!(!ParserSettings::new().get_disable_prompts());
+    /// ```
+    pub fn get_disable_prompts(&self) -> bool {self.disable_prompts}
+
+    /// get the value of `max_prompt_attempts`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert_eq!(ParserSettings::new().get_max_prompt_attempts(), 3);
+    /// ```
+    pub fn get_max_prompt_attempts(&self) -> usize {self.max_prompt_attempts}
+
+    /// get a reference to the templates used to reword the messages of errors, see `ParserSettings::with_messages()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{messages::Messages, parser_settings::ParserSettings};
+    /// //...
+    ///     assert_eq!(ParserSettings::new().get_messages(), &Messages::new());
+    /// ```
+    pub fn get_messages(&self) -> &Messages {&self.messages}
+
+    /// get what's done with stray args, see `ParserSettings::stray_args()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::{ParserSettings, StrayArgs};
+    /// //...
+    ///     assert_eq!(ParserSettings::new().get_stray_args(), StrayArgs::Error);
+    /// ```
+    pub fn get_stray_args(&self) -> StrayArgs {self.stray_args}
+
+    /// get a reference to the fallbacks for the list/data of options that weren't given, in the order they're asked, see `ParserSettings::with_value_provider()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::ClOptionInfo, parser_settings::ParserSettings};
+    /// //...
+    ///     let settings = ParserSettings::new().with_value_provider("keychain", |_: &ClOptionInfo| None).with_value_provider("service", |_: &ClOptionInfo| None);
+    ///     assert_eq!(settings.get_value_providers().iter().map(|provider| provider.get_name()).collect::<Vec<&str>>(), vec!["keychain", "service"]);
+    /// ```
+    pub fn get_value_providers(&self) -> &Vec<ValueProvider> {&self.value_providers}
+
+    /// get how many invalid values get a suggestion in a parse, see `ParserSettings::max_suggestions()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::parser_settings::ParserSettings;
+    /// //...
+    ///     assert_eq!(ParserSettings::new().get_max_suggestions(), 10);
+    /// ```
+    pub fn get_max_suggestions(&self) -> usize {self.max_suggestions}
+
+    /// get a reference to the constraints checked after parsing, see `ParserSettings::with_constraints()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{constraints::{Constraint, Constraints}, parser_settings::ParserSettings};
+    /// //...
+    ///     assert!(ParserSettings::new().get_constraints().get_constraints().is_empty());
+    ///     
+    ///     let constraints = Constraints::new().with(Constraint::Requires(String::from("append"), String::from("output")));
+    ///     assert_eq!(ParserSettings::new().with_constraints(constraints.clone()).get_constraints(), &constraints);
+    /// ```
+    pub fn get_constraints(&self) -> &Constraints {&self.constraints}
+}
@@ -0,0 +1,217 @@
+//! # Prompt
+//! 'prompt' is a module containing utilities for
+//! asking the user for the parameters, and required options, they didn't give, instead of failing to parse
+//!
+//! prompting is done by `Parser::parse_or_prompt()`, and can be scripted with `Parser::parse_or_prompt_with()`
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use std::error::Error;
+use std::io::{BufRead, Write};
+
+use crate::classifier::Classifier;
+use crate::constraints::Constraint;
+use crate::option_args;
+use crate::option_parser;
+use crate::parameter_args;
+use crate::parameter_parser;
+use crate::parser_settings::ParserSettings;
+
+/// the default number of times a missing parameter or option is asked for, see `ParserSettings::max_prompt_attempts()`
+pub const DEFAULT_MAX_ATTEMPTS: usize = 3;
+
+/// returns the required parameters in `expected_parameters` that aren't given in `args`, in the order they're expected
+///
+/// positionals are given to the required parameters from left to right, so it's the last ones that are missing
+///
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, prompt};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+///     let expected_parameters: Vec<ClParameter> = vec![
+///         ClParameter::new("PATH", "Path to search in"),
+///         ClParameter::new("QUERY", "String to search for"),
+///         ClParameter::new("LIMIT", "Most results to show").optional(true),
+///     ];
+///     let names = |args: &[&str]| -> Vec<String> {
+///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+///         prompt::missing_parameters(&args, &expected_parameters, &valid_options).iter().map(|parameter| parameter.get_name().to_string()).collect()
+///     };
+///
+///     assert_eq!(names(&["foo.exe", "-r"]), vec!["PATH", "QUERY"]);
+///     assert_eq!(names(&["foo.exe", "-r", "path/to/search"]), vec!["QUERY"]);
+///     assert!(names(&["foo.exe", "path/to/search", "needle"]).is_empty());
+/// ```
+pub fn missing_parameters<'a>(args: &[String], expected_parameters: &'a [parameter_args::ClParameter], valid_options: &[option_args::ClOption]) -> Vec<&'a parameter_args::ClParameter> {
+    let given: usize = parameter_parser::find_positionals(args, &Classifier::new(valid_options, &ParserSettings::new()), 1).len();
+    expected_parameters.iter().filter(|parameter| !parameter.get_optional()).skip(given).collect()
+}
+
+/// asks for the value of `parameter` by writing its name and description to `output`, and reading a line from `input`,
+/// asking again if the value isn't valid, up to `max_attempts` times
+///
+/// values are checked with the value parser of `parameter`, if it has one, and can't look like flags,
+/// since they're parsed as if they had been given in the args
+///
+/// # Errors
+/// - `input` ends, or can't be read, before a valid value is given
+/// - no valid value is given in `max_attempts` attempts
+///
+/// # Examples
+/// ```
+/// use clia::{parameter_args::ClParameter, prompt, value_parser::ValueParser};
+/// //...
+///     let parameter = ClParameter::new("COUNT", "How many to show").with_value_parser(ValueParser::IntParser);
+///
+///     let mut input = std::io::Cursor::new("abc\n10\n");
+///     let mut output: Vec<u8> = Vec::new();
+///     assert_eq!(prompt::ask(&parameter, &mut input, &mut output, 3).unwrap(), "10");
+///     assert_eq!(
+///         String::from_utf8(output).unwrap(),
+///         "COUNT — How many to show: invalid value 'abc': expected an integer\nCOUNT — How many to show: "
+///     );
+///
+///     //giving up
+///     let mut input = std::io::Cursor::new("abc\n-1x\n");
+///     assert_eq!(
+///         prompt::ask(&parameter, &mut input, &mut std::io::sink(), 2).unwrap_err().to_string(),
+///         "User Error: no valid value for parameter(COUNT) after 2 attempts"
+///     );
+///     //or running out of input
+///     assert_eq!(
+///         prompt::ask(&parameter, &mut std::io::Cursor::new(""), &mut std::io::sink(), 3).unwrap_err().to_string(),
+///         "User Error: no value given for parameter(COUNT)"
+///     );
+/// ```
+pub fn ask(parameter: &parameter_args::ClParameter, input: &mut dyn BufRead, output: &mut dyn Write, max_attempts: usize) -> Result<String, Box<dyn Error>> {
+    ask_until_valid(
+        &format!("{} — {}: ", parameter.get_name(), parameter.get_description()),
+        &format!("parameter({})", parameter.get_name()),
+        |value| parameter.get_value_parser().map_or(Ok(()), |parser| parser.check(value)),
+        input, output, max_attempts,
+    )
+}
+
+/// returns the options in `valid_options` that a `Constraint::RequiredUnless` in `settings` requires, but aren't given in `args`,
+/// nor are any of the options or parameters that would excuse them, in the order the constraints are declared
+///
+/// # Notes:
+/// - options are only required through constraints, see `ParserSettings::with_constraints()`
+/// - only options that take a list/data are returned, since there's nothing to ask for a plain flag
+///
+/// # Examples
+/// ```
+/// use clia::{constraints::{Constraint, Constraints}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, parser_settings::ParserSettings, prompt};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+///         ClOption::new_flag(&ClOptionInfo::new("-p", "--print", "Print the results instead").unwrap()),
+///     ];
+///     let settings = ParserSettings::new().with_constraints(Constraints::new().with(Constraint::RequiredUnless(String::from("output"), vec![String::from("print")])));
+///     let flags = |args: &[&str]| -> Vec<String> {
+///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+///         prompt::missing_options(&args, &valid_options, &[], &settings).iter().map(|option| option.get_long_flag().to_string()).collect()
+///     };
+///
+///     assert_eq!(flags(&["foo.exe"]), vec!["--output"]);
+///     assert!(flags(&["foo.exe", "--output=out.txt"]).is_empty());
+///     assert!(flags(&["foo.exe", "-p"]).is_empty());
+/// ```
+pub fn missing_options<'a>(args: &[String], valid_options: &'a [option_args::ClOption], expected_parameters: &[parameter_args::ClParameter], settings: &ParserSettings) -> Vec<&'a option_args::ClOption> {
+    //DATA
+    let (found, _) = option_parser::collect_options(args, valid_options, settings, None);
+    let given_parameters: usize = parameter_parser::find_positionals(args, &Classifier::new(valid_options, settings), 1).len();
+    let find_option = |id: &str| -> Option<usize> {
+        valid_options.iter().position(|option| !id.is_empty() && option.get_long_flag().strip_prefix("--") == Some(id))
+        .or_else(|| valid_options.iter().position(|option| !id.is_empty() && option.get_short_flag().strip_prefix('-') == Some(id)))
+    };
+    let given = |id: &str| -> bool {
+        match find_option(id) {
+            Some(index) => found.get(index).is_some_and(|option| option.get_present()),
+            None => expected_parameters.iter().position(|parameter| parameter.get_name().eq(id)).is_some_and(|index| index < given_parameters),
+        }
+    };
+
+    settings.get_constraints().get_constraints().iter()
+    .filter_map(|constraint| match constraint {
+        Constraint::RequiredUnless(id, others) if !given(id) && !others.iter().any(|other| given(other)) => find_option(id),
+        _ => None,
+    })
+    .map(|index| &valid_options[index])
+    .filter(|option| matches!(option, option_args::ClOption::FlagList { .. } | option_args::ClOption::FlagData { .. }))
+    .collect()
+}
+
+/// asks for the list/data of `option`, like `prompt::ask()` asks for the value of a parameter
+///
+/// values are checked like they would be if they had been given after the flag of `option` in the args,
+/// ei. against its allowed values and value parser
+///
+/// # Errors
+/// - the same as `prompt::ask()`
+///
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, prompt};
+/// //...
+///     let option = ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"]);
+///
+///     let mut input = std::io::Cursor::new("BULET\nBULLET\n");
+///     let mut output: Vec<u8> = Vec::new();
+///     assert_eq!(prompt::ask_option(&option, &mut input, &mut output, 3).unwrap(), "BULLET");
+///     assert_eq!(
+///         String::from_utf8(output).unwrap(),
+///         "--format — Format the output: invalid value 'BULET': User Error: invalid value 'BULET' for flag(--format), did you mean 'BULLET'? possible values are: DEFAULT, BULLET\n--format — Format the output: "
+///     );
+///
+///     //giving up
+///     assert_eq!(
+///         prompt::ask_option(&option, &mut std::io::Cursor::new("TABLE\n"), &mut std::io::sink(), 1).unwrap_err().to_string(),
+///         "User Error: no valid value for flag(--format) after 1 attempts"
+///     );
+/// ```
+pub fn ask_option(option: &option_args::ClOption, input: &mut dyn BufRead, output: &mut dyn Write, max_attempts: usize) -> Result<String, Box<dyn Error>> {
+    let flag: &str = option_parser::get_display_flag(option);
+    ask_until_valid(
+        &format!("{} — {}: ", flag, option.get_description()),
+        &format!("flag({})", flag),
+        |value| {
+            let args: Vec<String> = vec![String::new(), flag.to_string(), value.to_string()];
+            option_parser::parse_for_options_with_settings(&args, std::slice::from_ref(option), &ParserSettings::new()).map(|_| ()).map_err(|e| e.to_string())
+        },
+        input, output, max_attempts,
+    )
+}
+
+/// writes `prompt` to `output` and reads a line from `input`, until `check` accepts it, up to `max_attempts` times,
+/// `what` names what's being asked for in errors, ei. `parameter(PATH)`
+fn ask_until_valid(prompt: &str, what: &str, check: impl Fn(&str) -> Result<(), String>, input: &mut dyn BufRead, output: &mut dyn Write, max_attempts: usize) -> Result<String, Box<dyn Error>> {
+    for _ in 0..max_attempts {
+        write!(output, "{}", prompt)?;
+        output.flush()?;
+
+        //read a line, without its line ending
+        let mut line: String = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Err(format!("User Error: no value given for {}", what).into());
+        }
+        let value: &str = line.strip_suffix('\n').map_or(line.as_str(), |line| line.strip_suffix('\r').unwrap_or(line));
+
+        //check the value, asking again if it isn't valid
+        let checked: Result<(), String> = if option_parser::is_flag(value) {
+            Err(String::from("values can't start with '-'"))
+        } else if value.is_empty() {
+            Err(String::from("a value is required"))
+        } else {
+            check(value)
+        };
+        match checked {
+            Ok(()) => return Ok(value.to_string()),
+            Err(e) => writeln!(output, "invalid value '{}': {}", value, e)?,
+        }
+    }
+
+    Err(format!("User Error: no valid value for {} after {} attempts", what, max_attempts).into())
+}
@@ -0,0 +1,612 @@
+//! # Spec
+//! 'spec' is a module containing utilities for
+//! describing a command line programs entire interface (its options and parameters) as one value,
+//! and comparing them, ei. to catch accidental breaking changes between releases
+//!
+//! with the `build-spec` feature (on by default), a spec can be loaded from a toml file, see `spec::load_toml()`,
+//! or from one line per option and parameter, see `spec::load_lines()`
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use std::fmt;
+
+use crate::{classifier::{AnnotatedToken, Classifier, QuickScan}, constraints::{Constraint, Constraints}, help_items::{self, HelpItem}, option_args::ClOption, parameter_args::ClParameter, parser_settings::ParserSettings, synopsis::Synopsis};
+
+#[cfg(feature = "build-spec")]
+mod toml;
+#[cfg(feature = "build-spec")]
+mod lines;
+#[cfg(feature = "build-spec")]
+pub use toml::{load_toml, SpecError};
+#[cfg(feature = "build-spec")]
+pub use lines::load_lines;
+
+/// stores the definition of a command line programs interface, its valid options and expected parameters,
+/// and optionally the information shown in its help message and version
+#[derive(Clone, Debug, PartialEq)]
+pub struct CliSpec {
+    valid_options: Vec<ClOption>,
+    expected_parameters: Vec<ClParameter>,
+    title: String,
+    author: String,
+    program_description: String,
+    version: String,
+    constraints: Constraints,
+}
+impl CliSpec {
+    /// creates a new CliSpec with the given `valid_options` and `expected_parameters`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let valid_options = vec![ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())];
+    ///     let expected_parameters = vec![ClParameter::new("PATH", "Path to search in")];
+    ///
+    ///     let spec = CliSpec::new(&valid_options, &expected_parameters);
+    ///     assert_eq!(spec.get_valid_options(), &valid_options);
+    ///     assert_eq!(spec.get_expected_parameters(), &expected_parameters);
+    /// ```
+    pub fn new(valid_options: &[ClOption], expected_parameters: &[ClParameter]) -> CliSpec {
+        CliSpec {
+            valid_options: Vec::from(valid_options),
+            expected_parameters: Vec::from(expected_parameters),
+            title: String::new(),
+            author: String::new(),
+            program_description: String::new(),
+            version: String::new(),
+            constraints: Constraints::new(),
+        }
+    }
+
+    //builder methods
+    /// sets the `title`, `author`, and `program_description` shown in the help message, see `Parser::help()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     let spec = CliSpec::new(&[], &[]).with_about("foo.exe", "by Anthony Rubick", "Just here as an example");
+    ///     assert_eq!(spec.get_title(), "foo.exe");
+    ///     assert_eq!(spec.get_author(), "by Anthony Rubick");
+    ///     assert_eq!(spec.get_program_description(), "Just here as an example");
+    /// ```
+    pub fn with_about(mut self, title: &str, author: &str, program_description: &str) -> CliSpec {
+        self.title = title.to_string();
+        self.author = author.to_string();
+        self.program_description = program_description.to_string();
+        self
+    }
+
+    /// sets the `version` of the program, ei. `1.2.0`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     assert_eq!(CliSpec::new(&[], &[]).with_version("1.2.0").get_version(), "1.2.0");
+    /// ```
+    pub fn with_version(mut self, version: &str) -> CliSpec {
+        self.version = version.to_string();
+        self
+    }
+
+    /// sets the `constraints` between the options and parameters, checked after parsing by `Parser::parse_or_report()`,
+    /// and compared by `spec::diff()`, ei. to find options that became required
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{constraints::{Constraint, Constraints}, option_args::{ClOption, ClOptionInfo}, spec::CliSpec, Parser};
+    /// //...
+    ///     let constraints = Constraints::new().with(Constraint::RequiredUnless(String::from("output"), vec![]));
+    ///     let spec = CliSpec::new(&[ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE")], &[])
+    ///         .with_constraints(constraints.clone());
+    ///     assert_eq!(spec.get_constraints(), &constraints);
+    ///
+    ///     let (mut out, mut err): (Vec<u8>, Vec<u8>) = (Vec::new(), Vec::new());
+    ///     assert!(Parser::parse_or_report_to(&spec, &[String::from("foo.exe")], &mut out, &mut err).is_none());
+    ///     assert!(String::from_utf8(err).unwrap().contains("flag(--output) is required"));
+    /// ```
+    pub fn with_constraints(mut self, constraints: Constraints) -> CliSpec {
+        self.constraints = constraints;
+        self
+    }
+
+    /// returns the help message for this spec, see `Parser::help()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, spec::CliSpec, Parser};
+    /// //...
+    ///     let spec = CliSpec::new(&[], &[ClParameter::new("PATH", "Path to search in")]).with_about("foo.exe", "by Anthony Rubick", "Just here as an example");
+    ///     assert_eq!(spec.help(), Parser::help("foo.exe", "by Anthony Rubick", "Just here as an example", &[], &[ClParameter::new("PATH", "Path to search in")]));
+    /// ```
+    pub fn help(&self) -> String {
+        crate::Parser::help(&self.title, &self.author, &self.program_description, &self.valid_options, &self.expected_parameters)
+    }
+
+    /// returns the one line usage string of the help message, ei. `USAGE: foo.exe [OPTIONS]... <PATH>`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(
+    ///         &[ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())],
+    ///         &[ClParameter::new("PATH", "Path to search in")],
+    ///     ).with_about("foo.exe", "by Anthony Rubick", "Just here as an example");
+    ///     assert_eq!(spec.usage(), "USAGE: foo.exe [OPTIONS]... <PATH>");
+    /// ```
+    pub fn usage(&self) -> String {
+        self.synopsis().usage("USAGE:", &self.title)
+    }
+
+    /// returns which options of this spec have a flag in `args`, without parsing them, see `Classifier::quick_scan()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[
+    ///         ClOption::new_flag(&ClOptionInfo::new("-h", "--help", "Show help").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-V", "--version", "Show the version").unwrap()),
+    ///     ], &[]);
+    ///     let args: Vec<String> = ["foo.exe", "--version", "--frmat"].iter().map(|arg| arg.to_string()).collect();
+    ///
+    ///     //skip loading anything slow if only the version is wanted
+    ///     let scan = spec.quick_scan(&args);
+    ///     assert!(!scan.get_present()[0]);
+    ///     assert!(scan.get_present()[1]);
+    /// ```
+    ///
+    /// it agrees with the parser on clusters of short flags, and values given after an '='
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec, Parser};
+    /// //...
+    ///     let valid_options: Vec<ClOption> = vec![
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-i", "--ignore-case", "Ignore case").unwrap()),
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///     ];
+    ///     let expected_parameters: Vec<ClParameter> = vec![ClParameter::new("PATH", "Path to search in")];
+    ///     let spec = CliSpec::new(&valid_options, &expected_parameters);
+    ///
+    ///     for args in [["foo.exe", "-riF", "json", "src"], ["foo.exe", "-rF", "json", "src"], ["foo.exe", "--format=-i", "-r", "src"], ["foo.exe", "--format=json", "src", "-ir"]] {
+    ///         let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ///         let parser = Parser::new(&args, &valid_options, &expected_parameters).unwrap();
+    ///         assert_eq!(&parser.get_option_arguments_found().iter().map(|option| option.get_present()).collect::<Vec<bool>>(), spec.quick_scan(&args).get_present(), "{:?}", args);
+    ///     }
+    /// ```
+    pub fn quick_scan(&self, args: &[String]) -> QuickScan {
+        Classifier::new(&self.valid_options, &ParserSettings::new()).quick_scan(args)
+    }
+
+    /// returns what every arg in `args` is, down to its parts, and which parameter each positional is given, see `Classifier::annotate()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{classifier::TokenClass, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(
+    ///         &[ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT")],
+    ///         &[ClParameter::new("PATH", "Path to search in")],
+    ///     );
+    ///     let args: Vec<String> = ["foo.exe", "--format=json", "src"].iter().map(|arg| arg.to_string()).collect();
+    ///
+    ///     let tokens = spec.annotate(&args);
+    ///     assert_eq!(tokens[0].get_spans().iter().map(|span| span.get_class()).collect::<Vec<_>>(), vec![TokenClass::KnownFlag(0), TokenClass::ValueFor(0)]);
+    ///     assert_eq!(tokens[1].get_parameter(), Some("PATH"));
+    /// ```
+    pub fn annotate(&self, args: &[String]) -> Vec<AnnotatedToken> {
+        Classifier::new(&self.valid_options, &ParserSettings::new()).annotate(args, &self.expected_parameters)
+    }
+
+    /// returns what the usage line of the help message says, as data, see `synopsis::Synopsis`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(
+    ///         &[ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT")],
+    ///         &[ClParameter::new("PATH", "Path to search in")],
+    ///     ).with_about("foo.exe", "by Anthony Rubick", "Just here as an example");
+    ///
+    ///     let synopsis = spec.synopsis();
+    ///     assert_eq!(synopsis.get_options()[0].get_placeholder(), Some("FORMAT"));
+    ///     assert_eq!(synopsis.get_parameters()[0].get_name(), "PATH");
+    ///     assert_eq!(synopsis.usage("USAGE:", spec.get_title()), spec.usage());
+    /// ```
+    pub fn synopsis(&self) -> Synopsis {
+        Synopsis::new(self.valid_options.iter(), self.expected_parameters.iter())
+    }
+
+    /// returns what the help message shows about each option and parameter of this spec, in the order they're shown, see `help_items::HelpItem`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(
+    ///         &[ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT")],
+    ///         &[ClParameter::new("PATH", "Path to search in")],
+    ///     );
+    ///     assert_eq!(spec.help_items().map(|item| item.get_description()).collect::<Vec<&str>>(), vec!["Format the output", "Path to search in"]);
+    /// ```
+    pub fn help_items(&self) -> impl Iterator<Item = HelpItem<'_>> {
+        help_items::help_items(&self.valid_options, &self.expected_parameters)
+    }
+
+    /// get a reference to `valid_options`
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     assert!(CliSpec::new(&[], &[]).get_valid_options().is_empty());
+    /// ```
+    pub fn get_valid_options(&self) -> &Vec<ClOption> {&self.valid_options}
+
+    /// get a reference to `expected_parameters`
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     assert!(CliSpec::new(&[], &[]).get_expected_parameters().is_empty());
+    /// ```
+    pub fn get_expected_parameters(&self) -> &Vec<ClParameter> {&self.expected_parameters}
+
+    /// get a reference to `title`
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     assert_eq!(CliSpec::new(&[], &[]).get_title(), "");
+    /// ```
+    pub fn get_title(&self) -> &str {&self.title}
+
+    /// get a reference to `author`
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     assert_eq!(CliSpec::new(&[], &[]).get_author(), "");
+    /// ```
+    pub fn get_author(&self) -> &str {&self.author}
+
+    /// get a reference to `program_description`
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     assert_eq!(CliSpec::new(&[], &[]).get_program_description(), "");
+    /// ```
+    pub fn get_program_description(&self) -> &str {&self.program_description}
+
+    /// get a reference to `version`
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     assert_eq!(CliSpec::new(&[], &[]).get_version(), "");
+    /// ```
+    pub fn get_version(&self) -> &str {&self.version}
+
+    /// get a reference to `constraints`
+    /// # Examples
+    /// ```
+    /// use clia::{constraints::Constraints, spec::CliSpec};
+    /// //...
+    ///     assert_eq!(CliSpec::new(&[], &[]).get_constraints(), &Constraints::new());
+    /// ```
+    pub fn get_constraints(&self) -> &Constraints {&self.constraints}
+}
+
+/// whether a change to a command line interface can break existing invocations of the program
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compatibility {
+    /// invocations that worked before may not work after
+    Breaking,
+    /// every invocation that worked before still works
+    Compatible,
+}
+
+/// a change between two versions of a command line interface, options are identified by their flags
+#[derive(Clone, Debug, PartialEq)]
+pub enum CliChange {
+    /// an option that is no longer defined
+    RemovedOption {
+        /// the flags of the removed option
+        flags: String,
+    },
+    /// an option that wasn't defined before
+    AddedOption {
+        /// the flags of the added option
+        flags: String,
+    },
+    /// an option that kept its description, but whose flags changed
+    RenamedFlags {
+        /// the flags of the option before
+        old_flags: String,
+        /// the flags of the option after
+        new_flags: String,
+    },
+    /// an option whose placeholder (the name of its list or data, displayed in help messages) changed
+    ChangedPlaceholder {
+        /// the flags of the option
+        flags: String,
+        /// the placeholder before
+        old_placeholder: String,
+        /// the placeholder after
+        new_placeholder: String,
+    },
+    /// values that an option used to allow, but no longer does
+    RemovedAllowedValues {
+        /// the flags of the option
+        flags: String,
+        /// the values that are no longer allowed
+        values: Vec<String>,
+    },
+    /// an option that used to allow any value, but now only allows some
+    RestrictedValues {
+        /// the flags of the option
+        flags: String,
+        /// the values that are allowed now
+        values: Vec<String>,
+    },
+    /// an option that wasn't required before, but is now, see `Constraint::RequiredUnless`
+    RequiredOption {
+        /// the flags of the option
+        flags: String,
+    },
+    /// a parameter that wasn't expected before, which is breaking unless it's optional
+    AddedParameter {
+        /// the name of the parameter
+        name: String,
+        /// whether the parameter is optional, see `ClParameter::optional()`
+        optional: bool,
+    },
+    /// a parameter that is no longer expected
+    RemovedParameter {
+        /// the name of the parameter
+        name: String,
+    },
+}
+impl CliChange {
+    /// returns whether this change can break existing invocations of the program
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::spec::{CliChange, Compatibility};
+    /// //...
+    ///     assert_eq!(CliChange::RemovedOption { flags: String::from("--format") }.get_compatibility(), Compatibility::Breaking);
+    ///     assert_eq!(CliChange::AddedOption { flags: String::from("--format") }.get_compatibility(), Compatibility::Compatible);
+    ///     assert_eq!(CliChange::AddedParameter { name: String::from("PATH"), optional: false }.get_compatibility(), Compatibility::Breaking);
+    ///     assert_eq!(CliChange::AddedParameter { name: String::from("PATH"), optional: true }.get_compatibility(), Compatibility::Compatible);
+    /// ```
+    pub fn get_compatibility(&self) -> Compatibility {
+        match self {
+            CliChange::AddedOption { .. } | CliChange::ChangedPlaceholder { .. } | CliChange::AddedParameter { optional: true, .. } => Compatibility::Compatible,
+            CliChange::RemovedOption { .. }
+            | CliChange::RenamedFlags { .. }
+            | CliChange::RemovedAllowedValues { .. }
+            | CliChange::RestrictedValues { .. }
+            | CliChange::RequiredOption { .. }
+            | CliChange::AddedParameter { optional: false, .. }
+            | CliChange::RemovedParameter { .. } => Compatibility::Breaking,
+        }
+    }
+}
+impl fmt::Display for CliChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get_compatibility() {
+            Compatibility::Breaking => write!(f, "BREAKING: ")?,
+            Compatibility::Compatible => write!(f, "compatible: ")?,
+        }
+        match self {
+            CliChange::RemovedOption { flags } => write!(f, "removed option {}", flags),
+            CliChange::AddedOption { flags } => write!(f, "added option {}", flags),
+            CliChange::RenamedFlags { old_flags, new_flags } => write!(f, "renamed option {} to {}", old_flags, new_flags),
+            CliChange::ChangedPlaceholder { flags, old_placeholder, new_placeholder } => write!(f, "changed placeholder of option {} from <{}> to <{}>", flags, old_placeholder, new_placeholder),
+            CliChange::RemovedAllowedValues { flags, values } => write!(f, "option {} no longer allows: {}", flags, values.join(", ")),
+            CliChange::RestrictedValues { flags, values } => write!(f, "option {} now only allows: {}", flags, values.join(", ")),
+            CliChange::RequiredOption { flags } => write!(f, "option {} is now required", flags),
+            CliChange::AddedParameter { name, optional: true } => write!(f, "added optional parameter {}", name),
+            CliChange::AddedParameter { name, optional: false } => write!(f, "added required parameter {}", name),
+            CliChange::RemovedParameter { name } => write!(f, "removed parameter {}", name),
+        }
+    }
+}
+
+/// returns every change between the `old` and `new` versions of a command line interface,
+/// so they can be checked for breaking changes or listed in a changelog, also re-exported as `clia::diff()`
+///
+/// # Notes:
+/// - options are the same option if they share a short or long flag
+/// - an option of `old` that no option of `new` shares a flag with, is renamed if an unmatched option of `new` has the same description, and removed otherwise
+/// - an option is required if a `Constraint::RequiredUnless` of its spec names it, see `CliSpec::with_constraints()`
+/// - parameters are compared by name
+/// - changes are in the order: option changes (in the order of `old`'s options), added options, then parameter changes
+///
+/// # Examples
+/// ```
+/// use clia::{constraints::{Constraint, Constraints}, option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::{self, CliChange, CliSpec, Compatibility}};
+/// //...
+///     let old = CliSpec::new(
+///         &[
+///             ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///             ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET", "NUMERIC"]),
+///             ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+///             ClOption::new_flag_data(&ClOptionInfo::new("-s", "--sort", "Sort the output").unwrap(), "ORDER"),
+///             ClOption::new_flag(&ClOptionInfo::new("-v", "--verbose", "Print more").unwrap()),
+///         ],
+///         &[ClParameter::new("PATH", "Path to search in"), ClParameter::new("LIMIT", "Most results to show").optional(true)],
+///     );
+///     let new = CliSpec::new(
+///         &[
+///             ClOption::new_flag(&ClOptionInfo::new("-R", "--recurse", "Search through subdirectories").unwrap()),
+///             ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "NUMERIC"]),
+///             ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXT"),
+///             ClOption::new_flag_data(&ClOptionInfo::new("-s", "--sort", "Sort the output").unwrap(), "ORDER").with_allowed_values(&["NAME", "SIZE"]),
+///             ClOption::new_flag(&ClOptionInfo::new("-q", "--quiet", "Print less").unwrap()),
+///         ],
+///         &[
+///             ClParameter::new("PATH", "Path to search in"),
+///             ClParameter::new("QUERY", "String to search for"),
+///             ClParameter::new("DEPTH", "How deep to search").optional(true),
+///         ],
+///     ).with_constraints(Constraints::new().with(Constraint::RequiredUnless(String::from("format"), vec![String::from("quiet")])));
+///
+///     let changes = spec::diff(&old, &new);
+///     assert_eq!(changes, vec![
+///         CliChange::RenamedFlags { old_flags: String::from("-r, --recursive"), new_flags: String::from("-R, --recurse") },
+///         CliChange::RemovedAllowedValues { flags: String::from("-F, --format"), values: vec![String::from("BULLET")] },
+///         CliChange::RequiredOption { flags: String::from("-F, --format") },
+///         CliChange::ChangedPlaceholder { flags: String::from("-f, --filter"), old_placeholder: String::from("EXTENSIONS"), new_placeholder: String::from("EXT") },
+///         CliChange::RestrictedValues { flags: String::from("-s, --sort"), values: vec![String::from("NAME"), String::from("SIZE")] },
+///         CliChange::RemovedOption { flags: String::from("-v, --verbose") },
+///         CliChange::AddedOption { flags: String::from("-q, --quiet") },
+///         CliChange::RemovedParameter { name: String::from("LIMIT") },
+///         CliChange::AddedParameter { name: String::from("QUERY"), optional: false },
+///         CliChange::AddedParameter { name: String::from("DEPTH"), optional: true },
+///     ]);
+///     assert_eq!(
+///         changes.iter().map(|change| change.get_compatibility()).collect::<Vec<Compatibility>>(),
+///         vec![
+///             Compatibility::Breaking, Compatibility::Breaking, Compatibility::Breaking, Compatibility::Compatible, Compatibility::Breaking,
+///             Compatibility::Breaking, Compatibility::Compatible, Compatibility::Breaking, Compatibility::Breaking, Compatibility::Compatible,
+///         ]
+///     );
+///
+///     //changes can be listed in a changelog
+///     assert_eq!(changes[0].to_string(), "BREAKING: renamed option -r, --recursive to -R, --recurse");
+///     assert_eq!(changes[2].to_string(), "BREAKING: option -F, --format is now required");
+///     assert_eq!(changes[3].to_string(), "compatible: changed placeholder of option -f, --filter from <EXTENSIONS> to <EXT>");
+///     assert_eq!(changes[4].to_string(), "BREAKING: option -s, --sort now only allows: NAME, SIZE");
+///     assert_eq!(changes[7].to_string(), "BREAKING: removed parameter LIMIT");
+///     assert_eq!(changes[8].to_string(), "BREAKING: added required parameter QUERY");
+///     assert_eq!(changes[9].to_string(), "compatible: added optional parameter DEPTH");
+///
+///     //an interface has no changes from itself
+///     assert!(spec::diff(&new, &new).is_empty());
+///     //and is the same as clia::diff()
+///     assert_eq!(clia::diff(&old, &new), changes);
+/// ```
+///
+/// an option that was added already required breaks invocations too
+/// ```
+/// use clia::{constraints::{Constraint, Constraints}, option_args::{ClOption, ClOptionInfo}, spec::{self, CliChange, CliSpec}};
+/// //...
+///     let old = CliSpec::new(&[], &[]);
+///     let new = CliSpec::new(&[ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE")], &[])
+///         .with_constraints(Constraints::new().with(Constraint::RequiredUnless(String::from("o"), vec![])));
+///
+///     assert_eq!(spec::diff(&old, &new), vec![
+///         CliChange::AddedOption { flags: String::from("-o, --output") },
+///         CliChange::RequiredOption { flags: String::from("-o, --output") },
+///     ]);
+/// ```
+pub fn diff(old: &CliSpec, new: &CliSpec) -> Vec<CliChange> {
+    //DATA
+    let mut changes: Vec<CliChange> = Vec::new();
+    let mut matched_new_options: Vec<bool> = vec![false; new.valid_options.len()];
+
+    //find what happened to every old option
+    for old_option in old.valid_options.iter() {
+        if let Some(index) = new.valid_options.iter().position(|new_option| shares_flag(old_option, new_option)) {
+            matched_new_options[index] = true;
+            changes.extend(diff_option(old_option, &new.valid_options[index]));
+            if !is_required(old_option, &old.constraints) && is_required(&new.valid_options[index], &new.constraints) {
+                changes.push(CliChange::RequiredOption { flags: display_flags(&new.valid_options[index]) });
+            }
+        } else if let Some(index) = new.valid_options.iter().enumerate().position(|(index, new_option)| {
+            !matched_new_options[index]
+            && !old.valid_options.iter().any(|other| shares_flag(other, new_option))
+            && new_option.get_description().eq(old_option.get_description())
+        }) {
+            matched_new_options[index] = true;
+            changes.push(CliChange::RenamedFlags { old_flags: display_flags(old_option), new_flags: display_flags(&new.valid_options[index]) });
+            if !is_required(old_option, &old.constraints) && is_required(&new.valid_options[index], &new.constraints) {
+                changes.push(CliChange::RequiredOption { flags: display_flags(&new.valid_options[index]) });
+            }
+        } else {
+            changes.push(CliChange::RemovedOption { flags: display_flags(old_option) });
+        }
+    }
+
+    //every new option that wasn't matched to an old one was added
+    for (new_option, matched) in new.valid_options.iter().zip(matched_new_options) {
+        if !matched {
+            changes.push(CliChange::AddedOption { flags: display_flags(new_option) });
+            if is_required(new_option, &new.constraints) {
+                changes.push(CliChange::RequiredOption { flags: display_flags(new_option) });
+            }
+        }
+    }
+
+    //compare parameters by name
+    for old_parameter in old.expected_parameters.iter() {
+        if !new.expected_parameters.iter().any(|new_parameter| new_parameter.get_name().eq(old_parameter.get_name())) {
+            changes.push(CliChange::RemovedParameter { name: old_parameter.get_name().to_string() });
+        }
+    }
+    for new_parameter in new.expected_parameters.iter() {
+        if !old.expected_parameters.iter().any(|old_parameter| old_parameter.get_name().eq(new_parameter.get_name())) {
+            changes.push(CliChange::AddedParameter { name: new_parameter.get_name().to_string(), optional: new_parameter.is_optional() });
+        }
+    }
+
+    changes
+}
+
+/// returns the changes between two versions of the same option
+fn diff_option(old: &ClOption, new: &ClOption) -> Vec<CliChange> {
+    //DATA
+    let mut changes: Vec<CliChange> = Vec::new();
+
+    //placeholder
+    if let (Some(old_placeholder), Some(new_placeholder)) = (get_placeholder(old), get_placeholder(new)) {
+        if old_placeholder.ne(new_placeholder) {
+            changes.push(CliChange::ChangedPlaceholder { flags: display_flags(new), old_placeholder: old_placeholder.to_string(), new_placeholder: new_placeholder.to_string() });
+        }
+    }
+
+    //allowed values, an empty list allows anything
+    if let (Some(old_values), Some(new_values)) = (old.get_allowed_values(), new.get_allowed_values()) {
+        if old_values.is_empty() && !new_values.is_empty() {
+            changes.push(CliChange::RestrictedValues { flags: display_flags(new), values: new_values.clone() });
+        } else if !new_values.is_empty() {
+            let removed_values: Vec<String> = old_values.iter().filter(|value| !new_values.contains(value)).cloned().collect();
+            if !removed_values.is_empty() {
+                changes.push(CliChange::RemovedAllowedValues { flags: display_flags(new), values: removed_values });
+            }
+        }
+    }
+
+    changes
+}
+
+/// returns true if `a` and `b` share a short or long flag
+fn shares_flag(a: &ClOption, b: &ClOption) -> bool {
+    b.has_flag(a.get_short_flag()) || b.has_flag(a.get_long_flag())
+}
+
+/// returns true if a `Constraint::RequiredUnless` in `constraints` names `option`, by its long flag, or short flag, without its dashes, see `Parser::get_flag()`
+fn is_required(option: &ClOption, constraints: &Constraints) -> bool {
+    constraints.get_constraints().iter().any(|constraint| matches!(constraint, Constraint::RequiredUnless(id, _)
+        if !id.is_empty() && (option.get_long_flag().strip_prefix("--") == Some(id.as_str()) || option.get_short_flag().strip_prefix('-') == Some(id.as_str()))))
+}
+
+/// returns the name of the list or data of `option`, if it has one
+fn get_placeholder(option: &ClOption) -> Option<&str> {
+    match option {
+        ClOption::FlagList { list_name, .. } => Some(list_name),
+        ClOption::FlagData { data_name, .. } => Some(data_name),
+        _ => None,
+    }
+}
+
+/// returns the flags of `option` as they appear in help messages, ei. `-r, --recursive`
+fn display_flags(option: &ClOption) -> String {
+    [option.get_short_flag(), option.get_long_flag()].iter().filter(|flag| !flag.is_empty()).cloned().collect::<Vec<&str>>().join(", ")
+}
@@ -0,0 +1,236 @@
+//! loading a CliSpec from a spec with one option or parameter per line, see `spec::load_lines()`
+
+use crate::option_args::{ClOption, ClOptionInfo};
+use crate::parameter_args::ClParameter;
+use crate::spec::toml::{parse_string, SpecError};
+use crate::spec::CliSpec;
+
+/// a word of a line, and whether it was quoted
+struct Word {
+    text: String,
+    quoted: bool,
+}
+
+/// loads the definition of a program from a spec with one option or parameter per line, in the order they're defined,
+/// ei. `flag -r --recursive "Search through subdirectories"`, a shorter alternative to `spec::load_toml()`
+///
+/// the spec is checked as it's loaded, so a test that loads it catches mistakes before a user does
+///
+/// # Notes:
+/// - each line starts with its kind, then:
+///   - `flag`, `toggle`, and `count`, the flags of the option and its description, see `ClOption::new_flag()`, `ClOption::new_flag_toggle()`, and `ClOption::new_flag_count()`
+///   - `list` and `data`, the flags of the option, the name of its list/data, and its description, see `ClOption::new_flag_list()` and `ClOption::new_flag_data()`
+///   - `param`, the name of the parameter and its description, optionally followed by `optional` and/or `variadic`, see `ClParameter::new()`
+/// - descriptions are strings (`"..."` or `'...'`), and can be left out
+/// - blank lines, and lines starting with `#`, are ignored
+///
+/// # Errors
+/// every error is a `spec::SpecError` with the line it's about, ei. `line 3`:
+/// - a line doesn't start with one of the kinds above, or has words that don't belong to its kind
+/// - a flag isn't formatted properly, see `ClOptionInfo::new()`
+/// - a flag is used by more than one option, or a name by more than one parameter
+/// - more than one parameter is variadic
+///
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec};
+/// //...
+///     let spec = spec::load_lines(r#"
+///         ## options
+///         flag -r --recursive "Search through subdirectories"
+///         toggle -c --color "Color the output"
+///         count -v --verbose "Print more"
+///         list -f --filter EXTENSIONS "Extensions to search"
+///         data -F --format FORMAT "Format the output"
+///
+///         ## parameters
+///         param PATH "Path to search in"
+///         param QUERIES "Strings to search for" optional variadic
+///     "#).unwrap();
+///
+///     assert_eq!(spec.get_valid_options(), &vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_toggle(&ClOptionInfo::new("-c", "--color", "Color the output").unwrap()),
+///         ClOption::new_flag_count(&ClOptionInfo::new("-v", "--verbose", "Print more").unwrap()),
+///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+///     ]);
+///     assert_eq!(spec.get_expected_parameters(), &vec![
+///         ClParameter::new("PATH", "Path to search in"),
+///         ClParameter::new("QUERIES", "Strings to search for").optional(true).variadic(true),
+///     ]);
+///
+///     //options can have just one flag, and no description
+///     let spec = spec::load_lines("flag --verbose\ndata -o FILE").unwrap();
+///     assert_eq!(spec.get_valid_options()[0], ClOption::new_flag(&ClOptionInfo::new("", "--verbose", "").unwrap()));
+///     assert_eq!(spec.get_valid_options()[1], ClOption::new_flag_data(&ClOptionInfo::new("-o", "", "").unwrap(), "FILE"));
+/// ```
+///
+/// mistakes in the spec
+/// ```
+/// use clia::spec;
+/// //...
+///     //an unknown kind
+///     let error = spec::load_lines("flag -r --recursive\n\nswitch -v --verbose \"Print more\"").unwrap_err();
+///     assert_eq!(error.get_key_path(), "line 3");
+///     assert_eq!(error.to_string(), "BUG: invalid spec at line 3: expected one of: flag, toggle, count, list, data, param");
+///
+///     //a description that isn't quoted
+///     let error = spec::load_lines("flag -r --recursive Search through subdirectories").unwrap_err();
+///     assert_eq!(error.to_string(), "BUG: invalid spec at line 1: unexpected 'Search', descriptions must be quoted");
+///
+///     //data without a name
+///     let error = spec::load_lines("data -F --format \"Format the output\"").unwrap_err();
+///     assert_eq!(error.to_string(), "BUG: invalid spec at line 1: missing the name of the data");
+///
+///     //a malformed flag
+///     let error = spec::load_lines("flag -r --recursive-7").unwrap_err();
+///     assert_eq!(error.get_message(), "BUG: long_flag (\"--recursive-7\") improperly formated, it must be '--' followed by a word (or words separated by '-')");
+///
+///     //a flag used twice
+///     let error = spec::load_lines("flag -r --recursive\nflag -r --reverse").unwrap_err();
+///     assert_eq!(error.to_string(), "BUG: invalid spec at line 2: flag '-r' is already used by line 1");
+///
+///     //an unclosed string
+///     let error = spec::load_lines("param PATH \"Path to search in").unwrap_err();
+///     assert_eq!(error.to_string(), "BUG: invalid spec at line 1: unclosed string");
+/// ```
+pub fn load_lines(spec: &str) -> Result<CliSpec, SpecError> {
+    //DATA
+    let mut loaded: CliSpec = CliSpec::new(&[], &[]);
+    let mut option_lines: Vec<usize> = Vec::new();
+    let mut parameter_lines: Vec<usize> = Vec::new();
+
+    for (line_index, line) in spec.lines().enumerate() {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let path: String = format!("line {}", line_index + 1);
+        let words: Vec<Word> = split_words(line).ok_or_else(|| SpecError::new(&path, "unclosed string"))?;
+
+        let (kind, words) = words.split_first().filter(|(kind, _)| !kind.quoted).map(|(kind, words)| (kind.text.as_str(), words)).unwrap_or_default();
+        match kind {
+            "flag" | "toggle" | "count" | "list" | "data" => {
+                let option: ClOption = load_option(kind, words, &path)?;
+                for flag in [option.get_short_flag(), option.get_long_flag()].into_iter().filter(|flag| !flag.is_empty()) {
+                    if let Some(first) = loaded.valid_options.iter().position(|other| other.has_flag(flag)) {
+                        return Err(SpecError::new(&path, &format!("flag '{}' is already used by line {}", flag, option_lines[first])));
+                    }
+                }
+                loaded.valid_options.push(option);
+                option_lines.push(line_index + 1);
+            },
+            "param" => {
+                let parameter: ClParameter = load_parameter(words, &path)?;
+                if let Some(first) = loaded.expected_parameters.iter().position(|other| other.get_name().eq(parameter.get_name())) {
+                    return Err(SpecError::new(&path, &format!("name '{}' is already used by line {}", parameter.get_name(), parameter_lines[first])));
+                }
+                if parameter.get_variadic() && loaded.expected_parameters.iter().any(|other| other.get_variadic()) {
+                    return Err(SpecError::new(&path, "only one parameter can be variadic"));
+                }
+                loaded.expected_parameters.push(parameter);
+                parameter_lines.push(line_index + 1);
+            },
+            _ => return Err(SpecError::new(&path, "expected one of: flag, toggle, count, list, data, param")),
+        }
+    }
+
+    Ok(loaded)
+}
+
+/// returns the option of `kind` defined by the `words` after its kind, on the line at `path`
+fn load_option(kind: &str, words: &[Word], path: &str) -> Result<ClOption, SpecError> {
+    //DATA
+    let mut short: &str = "";
+    let mut long: &str = "";
+    let mut name: Option<&str> = None;
+    let mut description: Option<&str> = None;
+    let takes_value: bool = matches!(kind, "list" | "data");
+
+    //flags, then the name of the list/data if there is one, then the description
+    for word in words {
+        if word.quoted && description.is_none() {
+            description = Some(&word.text);
+        } else if word.text.starts_with("--") && long.is_empty() && name.is_none() && description.is_none() {
+            long = &word.text;
+        } else if word.text.starts_with('-') && !word.text.starts_with("--") && short.is_empty() && long.is_empty() && name.is_none() && description.is_none() {
+            short = &word.text;
+        } else if takes_value && !word.text.starts_with('-') && name.is_none() && description.is_none() {
+            name = Some(&word.text);
+        } else {
+            return Err(unexpected(word, path));
+        }
+    }
+
+    let info: ClOptionInfo = ClOptionInfo::new(short, long, description.unwrap_or_default()).map_err(|e| SpecError::new(path, &e.to_string()))?;
+    Ok(match kind {
+        "flag" => ClOption::new_flag(&info),
+        "toggle" => ClOption::new_flag_toggle(&info),
+        "count" => ClOption::new_flag_count(&info),
+        _ => {
+            let name: &str = name.ok_or_else(|| SpecError::new(path, &format!("missing the name of the {}", kind)))?;
+            if kind.eq("list") {ClOption::new_flag_list(&info, name)} else {ClOption::new_flag_data(&info, name)}
+        },
+    })
+}
+
+/// returns the parameter defined by the `words` after `param`, on the line at `path`
+fn load_parameter(words: &[Word], path: &str) -> Result<ClParameter, SpecError> {
+    //DATA
+    let Some((name, mut words)) = words.split_first().filter(|(name, _)| !name.quoted) else {
+        return Err(SpecError::new(path, "missing the name of the parameter"));
+    };
+    let mut description: &str = "";
+    let mut optional: bool = false;
+    let mut variadic: bool = false;
+
+    //the description, then whether it's optional and variadic
+    if let Some((word, rest)) = words.split_first().filter(|(word, _)| word.quoted) {
+        description = &word.text;
+        words = rest;
+    }
+    for word in words {
+        match word.text.as_str() {
+            "optional" if !word.quoted && !optional => optional = true,
+            "variadic" if !word.quoted && !variadic => variadic = true,
+            _ => return Err(unexpected(word, path)),
+        }
+    }
+
+    Ok(ClParameter::new(&name.text, description).optional(optional).variadic(variadic))
+}
+
+/// returns the error for a `word` that doesn't belong where it is, on the line at `path`
+fn unexpected(word: &Word, path: &str) -> SpecError {
+    if word.quoted {
+        SpecError::new(path, &format!("unexpected string \"{}\", only one description is allowed", word.text))
+    } else if word.text.starts_with('-') {
+        SpecError::new(path, &format!("unexpected '{}', an option has at most one short flag and one long flag, given before anything else", word.text))
+    } else {
+        SpecError::new(path, &format!("unexpected '{}', descriptions must be quoted", word.text))
+    }
+}
+
+/// splits `line` into its words, ei. `flag -r "Search through subdirectories"` is `flag`, `-r`, and `Search through subdirectories`,
+/// none if a string isn't closed
+fn split_words(line: &str) -> Option<Vec<Word>> {
+    //DATA
+    let mut words: Vec<Word> = Vec::new();
+    let mut rest: &str = line.trim_start();
+
+    while !rest.is_empty() {
+        if rest.starts_with(['"', '\'']) {
+            let (text, after) = parse_string(rest)?;
+            words.push(Word { text, quoted: true });
+            rest = after;
+        } else {
+            let end: usize = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            words.push(Word { text: rest[..end].to_string(), quoted: false });
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+    }
+
+    Some(words)
+}
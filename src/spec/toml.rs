@@ -0,0 +1,412 @@
+//! loading a CliSpec from a declarative toml file, see `spec::load_toml()`
+
+use std::error::Error;
+use std::fmt;
+
+use crate::option_args::{ClOption, ClOptionInfo};
+use crate::parameter_args::ClParameter;
+use crate::spec::CliSpec;
+
+/// an error in a toml spec, see `spec::load_toml()`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpecError {
+    key_path: String,
+    message: String,
+}
+impl SpecError {
+    /// creates a new SpecError about the key at `key_path`
+    pub(super) fn new(key_path: &str, message: &str) -> SpecError {
+        SpecError { key_path: key_path.to_string(), message: message.to_string() }
+    }
+
+    //getter methods
+    /// get a reference to the path of the key this error is about, ei. `options[1].short`, or of the table if it isn't about a key, ei. `options[1]`,
+    /// or the line if it isn't in a table
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::spec;
+    /// //...
+    ///     let error = spec::load_toml("[[options]]\nlong = \"--recursive\"\nshrot = \"-r\"").unwrap_err();
+    ///     assert_eq!(error.get_key_path(), "options[0].shrot");
+    /// ```
+    pub fn get_key_path(&self) -> &str {&self.key_path}
+
+    /// get a reference to the message of this error
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::spec;
+    /// //...
+    ///     let error = spec::load_toml("[[options]]\nlong = \"--recursive\"\nshrot = \"-r\"").unwrap_err();
+    ///     assert_eq!(error.get_message(), "unknown key");
+    /// ```
+    pub fn get_message(&self) -> &str {&self.message}
+}
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BUG: invalid spec at {}: {}", self.key_path, self.message)
+    }
+}
+impl Error for SpecError {}
+
+/// a value in a toml spec, only strings, booleans, and arrays of strings are needed
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    String(String),
+    Bool(bool),
+    Array(Vec<String>),
+}
+
+/// a table of a toml spec, with its path and its keys (with their paths) in the order they were given
+struct Table {
+    path: String,
+    keys: Vec<(String, String, Value)>,
+}
+impl Table {
+    /// returns the string at `key`, if it's given
+    fn string(&self, key: &str) -> Result<Option<&str>, SpecError> {
+        match self.find(key) {
+            Some((_, Value::String(value))) => Ok(Some(value)),
+            Some((path, _)) => Err(SpecError::new(path, "expected a string")),
+            None => Ok(None),
+        }
+    }
+
+    /// returns the boolean at `key`, or false if it isn't given
+    fn bool(&self, key: &str) -> Result<bool, SpecError> {
+        match self.find(key) {
+            Some((_, Value::Bool(value))) => Ok(*value),
+            Some((path, _)) => Err(SpecError::new(path, "expected true or false")),
+            None => Ok(false),
+        }
+    }
+
+    /// returns the array of strings at `key`, if it's given
+    fn array(&self, key: &str) -> Result<Option<Vec<&str>>, SpecError> {
+        match self.find(key) {
+            Some((_, Value::Array(values))) => Ok(Some(values.iter().map(|value| value.as_str()).collect())),
+            Some((path, _)) => Err(SpecError::new(path, "expected an array of strings")),
+            None => Ok(None),
+        }
+    }
+
+    /// returns the path and value of `key`, if it's given
+    fn find(&self, key: &str) -> Option<(&str, &Value)> {
+        self.keys.iter().find(|(name, _, _)| name.eq(key)).map(|(_, path, value)| (path.as_str(), value))
+    }
+
+    /// returns an error if any key isn't in `allowed`
+    fn check_keys(&self, allowed: &[&str]) -> Result<(), SpecError> {
+        match self.keys.iter().find(|(name, _, _)| !allowed.contains(&name.as_str())) {
+            Some((_, path, _)) => Err(SpecError::new(path, "unknown key")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// the keys every option can have
+const OPTION_KEYS: [&str; 5] = ["kind", "short", "long", "description", "long_description"];
+
+/// loads the definition of a program from a toml spec, ei. one added with `include_str!()`, so it can live in one file instead of pages of constructor calls
+///
+/// the spec is checked as it's loaded, so a test that loads it catches mistakes before a user does
+///
+/// # Notes:
+/// - the spec can have an `[about]` table, with a `title`, `author`, `description`, and `version`
+/// - each `[[options]]` table is an option, with a `short` and/or `long` flag, a `description`, and a `long_description`, and a `kind`:
+///   - `"flag"` (the default), see `ClOption::new_flag()`
+///   - `"toggle"`, see `ClOption::new_flag_toggle()`
+///   - `"count"`, see `ClOption::new_flag_count()`
+///   - `"preset"`, with the args it stands for as a `preset`, see `ClOption::new_flag_preset()`
+///   - `"list"`, with a `name` and optionally `allowed` values and `ignore_case`, see `ClOption::new_flag_list()`
+///   - `"data"`, with a `name` and optionally `allowed` values, `ignore_case`, and a `default`, see `ClOption::new_flag_data()`
+/// - each `[[parameters]]` table is a parameter, with a `name`, a `description`, and whether it's `optional` and `variadic`, see `ClParameter::new()`
+/// - values are strings (`"..."` or `'...'`), `true` or `false`, or arrays of strings on one line, anything else in toml isn't supported
+///
+/// # Errors
+/// every error is a `spec::SpecError` with the path of the key it's about:
+/// - a line isn't a table header, a key and a value, or a comment
+/// - a table or key isn't one listed above, or a value is of the wrong type
+/// - a flag isn't formatted properly, see `ClOptionInfo::new()`
+/// - a flag is used by more than one option, or a name by more than one parameter
+/// - more than one parameter is variadic
+/// - a flag in a preset isn't a flag of any option
+///
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec, Parser};
+/// //...
+///     let spec = spec::load_toml(r#"
+///         [about]
+///         title = "foo.exe"
+///         version = "1.2.0"
+///
+///         [[options]]
+///         short = "-r"
+///         long = "--recursive"
+///         description = "Search through subdirectories"
+///
+///         [[options]]
+///         long = "--format"
+///         description = "Format the output"
+///         kind = "data"
+///         name = "FORMAT"
+///         allowed = ["DEFAULT", "BULLET"] # matched ignoring case
+///         ignore_case = true
+///         default = "DEFAULT"
+///
+///         [[parameters]]
+///         name = "PATH"
+///         description = "Path to search in"
+///     "#).unwrap();
+///
+///     assert_eq!(spec.get_title(), "foo.exe");
+///     assert_eq!(spec.get_version(), "1.2.0");
+///     assert_eq!(spec.get_valid_options()[0], ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()));
+///     assert_eq!(spec.get_expected_parameters(), &vec![ClParameter::new("PATH", "Path to search in")]);
+///
+///     let parse = |args: &[&str]| Parser::new(&args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>(), spec.get_valid_options(), spec.get_expected_parameters()).unwrap();
+///     assert_eq!(parse(&["foo.exe", "--format", "bullet", "src"]).get_one("format"), Some("BULLET"));
+///     assert_eq!(parse(&["foo.exe", "src"]).get_option_arguments_found()[1].get_data(), Some("DEFAULT"));
+/// ```
+///
+/// mistakes in the spec
+/// ```
+/// use clia::spec;
+/// //...
+///     //an unknown key
+///     let error = spec::load_toml("[[options]]\nlong = \"--recursive\"\n\n[[options]]\nlong = \"--format\"\nkind = \"data\"\nplaceholder = \"FORMAT\"").unwrap_err();
+///     assert_eq!(error.get_key_path(), "options[1].placeholder");
+///     assert_eq!(error.to_string(), "BUG: invalid spec at options[1].placeholder: unknown key");
+///
+///     //a malformed flag
+///     let error = spec::load_toml("[[options]]\nshort = \"-r\"\nlong = \"-recursive\"").unwrap_err();
+///     assert_eq!(error.get_key_path(), "options[0].long");
+///     assert_eq!(error.get_message(), "BUG: long_flag (\"-recursive\") improperly formated, it must be '--' followed by a word (or words separated by '-')");
+///
+///     //a flag used twice
+///     let error = spec::load_toml("[[options]]\nshort = \"-r\"\n[[options]]\nshort = \"-r\"\nlong = \"--reverse\"").unwrap_err();
+///     assert_eq!(error.to_string(), "BUG: invalid spec at options[1].short: flag '-r' is already used by options[0]");
+///
+///     //a value of the wrong type
+///     let error = spec::load_toml("[[parameters]]\nname = \"PATH\"\noptional = \"yes\"").unwrap_err();
+///     assert_eq!(error.to_string(), "BUG: invalid spec at parameters[0].optional: expected true or false");
+///
+///     //and toml this doesn't understand
+///     let error = spec::load_toml("[about]\ntitle = \"foo.exe\"\nauthor = { name = \"Anthony\" }").unwrap_err();
+///     assert_eq!(error.to_string(), "BUG: invalid spec at about.author: expected a string, true, false, or an array of strings");
+/// ```
+pub fn load_toml(toml: &str) -> Result<CliSpec, SpecError> {
+    //DATA
+    let tables: Vec<Table> = parse_tables(toml)?;
+    let mut spec: CliSpec = CliSpec::new(&[], &[]);
+
+    for table in tables.iter() {
+        if table.path.eq("about") {
+            table.check_keys(&["title", "author", "description", "version"])?;
+            spec = spec.with_about(table.string("title")?.unwrap_or_default(), table.string("author")?.unwrap_or_default(), table.string("description")?.unwrap_or_default())
+            .with_version(table.string("version")?.unwrap_or_default());
+        } else if table.path.starts_with("options") {
+            spec.valid_options.push(load_option(table)?);
+        } else {
+            table.check_keys(&["name", "description", "optional", "variadic"])?;
+            let name: &str = table.string("name")?.ok_or_else(|| SpecError::new(&table.path, "missing key 'name'"))?;
+            spec.expected_parameters.push(ClParameter::new(name, table.string("description")?.unwrap_or_default()).optional(table.bool("optional")?).variadic(table.bool("variadic")?));
+        }
+    }
+
+    verify(&spec, &tables)?;
+    Ok(spec)
+}
+
+/// returns the option defined by `table`
+fn load_option(table: &Table) -> Result<ClOption, SpecError> {
+    //DATA
+    let kind: &str = table.string("kind")?.unwrap_or("flag");
+    let short: &str = table.string("short")?.unwrap_or_default();
+    let long: &str = table.string("long")?.unwrap_or_default();
+
+    //check the flags one at a time, to find which is malformed
+    for (key, builder) in [("short", ClOptionInfo::builder().short(short).long("--flag")), ("long", ClOptionInfo::builder().short("-f").long(long))] {
+        if let Err(e) = builder.build() {
+            return Err(SpecError::new(&format!("{}.{}", table.path, key), &e.to_string()));
+        }
+    }
+    let mut info: ClOptionInfo = ClOptionInfo::new(short, long, table.string("description")?.unwrap_or_default()).map_err(|e| SpecError::new(&table.path, &e.to_string()))?;
+    if let Some(long_description) = table.string("long_description")? {
+        info = info.with_long_description(long_description);
+    }
+
+    //the keys that depend on the kind
+    let extra_keys: &[&str] = match kind {
+        "flag" | "toggle" | "count" => &[],
+        "preset" => &["preset"],
+        "list" => &["name", "allowed", "ignore_case"],
+        "data" => &["name", "allowed", "ignore_case", "default"],
+        _ => return Err(SpecError::new(table.find("kind").map_or(table.path.as_str(), |(path, _)| path), "expected one of: flag, toggle, count, preset, list, data")),
+    };
+    table.check_keys(&[&OPTION_KEYS[..], extra_keys].concat())?;
+
+    let option: ClOption = match kind {
+        "flag" => ClOption::new_flag(&info),
+        "toggle" => ClOption::new_flag_toggle(&info),
+        "count" => ClOption::new_flag_count(&info),
+        "preset" => ClOption::new_flag_preset(&info, &table.array("preset")?.unwrap_or_default()),
+        _ => {
+            let name: &str = table.string("name")?.ok_or_else(|| SpecError::new(&table.path, "missing key 'name'"))?;
+            let mut option: ClOption = if kind.eq("list") {ClOption::new_flag_list(&info, name)} else {ClOption::new_flag_data(&info, name)};
+            if let Some(allowed) = table.array("allowed")? {
+                option = option.with_allowed_values(&allowed);
+            }
+            option = option.ignore_case(table.bool("ignore_case")?);
+            if let Some(default) = table.string("default")?.map(|default| default.to_string()) {
+                option = option.default_with(move || default.clone());
+            }
+            option
+        },
+    };
+
+    Ok(option)
+}
+
+/// checks the definition in `spec` is sound as a whole, `tables` are the tables it was loaded from
+fn verify(spec: &CliSpec, tables: &[Table]) -> Result<(), SpecError> {
+    let option_tables: Vec<&Table> = tables.iter().filter(|table| table.path.starts_with("options")).collect();
+    let parameter_tables: Vec<&Table> = tables.iter().filter(|table| table.path.starts_with("parameters")).collect();
+
+    //flags are used by one option each
+    for (index, option) in spec.valid_options.iter().enumerate() {
+        for (key, flag) in [("short", option.get_short_flag()), ("long", option.get_long_flag())].into_iter().filter(|(_, flag)| !flag.is_empty()) {
+            if let Some(first) = spec.valid_options[..index].iter().position(|other| other.has_flag(flag)) {
+                return Err(SpecError::new(&format!("{}.{}", option_tables[index].path, key), &format!("flag '{}' is already used by {}", flag, option_tables[first].path)));
+            }
+        }
+
+        //and presets are made of them
+        if let Some(unknown) = option.get_preset().and_then(|preset| preset.iter().find(|arg| arg.starts_with('-') && !spec.valid_options.iter().any(|other| other.has_flag(arg)))) {
+            return Err(SpecError::new(&format!("{}.preset", option_tables[index].path), &format!("'{}' isn't a flag of any option", unknown)));
+        }
+    }
+
+    //names are used by one parameter each, and only one parameter is variadic
+    for (index, parameter) in spec.expected_parameters.iter().enumerate() {
+        if let Some(first) = spec.expected_parameters[..index].iter().position(|other| other.get_name().eq(parameter.get_name())) {
+            return Err(SpecError::new(&format!("{}.name", parameter_tables[index].path), &format!("name '{}' is already used by {}", parameter.get_name(), parameter_tables[first].path)));
+        }
+        if parameter.get_variadic() && spec.expected_parameters[..index].iter().any(|other| other.get_variadic()) {
+            return Err(SpecError::new(&format!("{}.variadic", parameter_tables[index].path), "only one parameter can be variadic"));
+        }
+    }
+
+    Ok(())
+}
+
+/// splits `toml` into its tables
+fn parse_tables(toml: &str) -> Result<Vec<Table>, SpecError> {
+    //DATA
+    let mut tables: Vec<Table> = Vec::new();
+    let (mut options, mut parameters): (usize, usize) = (0, 0);
+
+    for (line_index, line) in toml.lines().enumerate() {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        //table headers
+        if line.starts_with('[') {
+            let header: &str = line.split('#').next().unwrap_or_default().trim();
+            let path: String = match header {
+                "[about]" if !tables.iter().any(|table| table.path.eq("about")) => String::from("about"),
+                "[[options]]" => {options += 1; format!("options[{}]", options - 1)},
+                "[[parameters]]" => {parameters += 1; format!("parameters[{}]", parameters - 1)},
+                _ => return Err(SpecError::new(&format!("line {}", line_index + 1), &format!("unknown or repeated table '{}'", header))),
+            };
+            tables.push(Table { path, keys: Vec::new() });
+            continue;
+        }
+
+        //keys
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(SpecError::new(&format!("line {}", line_index + 1), "expected a table header, or a key and a value"));
+        };
+        let key: &str = key.trim();
+        let Some(table) = tables.last_mut() else {
+            return Err(SpecError::new(key, "keys must be in a table"));
+        };
+        let path: String = format!("{}.{}", table.path, key);
+        if table.keys.iter().any(|(name, _, _)| name.eq(key)) {
+            return Err(SpecError::new(&path, "repeated key"));
+        }
+        let value: Value = parse_value(value.trim()).ok_or_else(|| SpecError::new(&path, "expected a string, true, false, or an array of strings"))?;
+        table.keys.push((key.to_string(), path, value));
+    }
+
+    Ok(tables)
+}
+
+/// parses `text` as a value, followed by nothing but a comment
+fn parse_value(text: &str) -> Option<Value> {
+    //returns the rest of the text, if it's only a comment
+    let end = |rest: &str| {
+        let rest: &str = rest.trim_start();
+        (rest.is_empty() || rest.starts_with('#')).then_some(())
+    };
+
+    if let Some(rest) = text.strip_prefix("true") {
+        return end(rest).map(|_| Value::Bool(true));
+    }
+    if let Some(rest) = text.strip_prefix("false") {
+        return end(rest).map(|_| Value::Bool(false));
+    }
+    if let Some(mut rest) = text.strip_prefix('[') {
+        let mut values: Vec<String> = Vec::new();
+        loop {
+            rest = rest.trim_start();
+            if let Some(rest) = rest.strip_prefix(']') {
+                return end(rest).map(|_| Value::Array(values));
+            }
+            let (value, after) = parse_string(rest)?;
+            values.push(value);
+
+            //values are separated by commas, and can have one after the last
+            let after: &str = after.trim_start();
+            rest = match after.strip_prefix(',') {
+                Some(after) => after,
+                None if after.starts_with(']') => after,
+                None => return None,
+            };
+        }
+    }
+    let (value, rest) = parse_string(text)?;
+    end(rest).map(|_| Value::String(value))
+}
+
+/// parses the string at the start of `text`, returning it and the text after it
+pub(super) fn parse_string(text: &str) -> Option<(String, &str)> {
+    //literal strings, with no escapes
+    if let Some(rest) = text.strip_prefix('\'') {
+        let (value, rest) = rest.split_once('\'')?;
+        return Some((value.to_string(), rest));
+    }
+
+    //basic strings
+    let mut chars = text.strip_prefix('"')?.char_indices();
+    let mut value: String = String::new();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &text[index + 2..])),
+            '\\' => value.push(match chars.next()?.1 {
+                'n' => '\n',
+                't' => '\t',
+                '"' => '"',
+                '\\' => '\\',
+                _ => return None,
+            }),
+            c => value.push(c),
+        }
+    }
+
+    None
+}
@@ -0,0 +1,150 @@
+//! # Suggest
+//! 'suggest' is a module containing utilities for
+//! suggesting what the user meant to type, ei. `did you mean 'MARKDOWN'?` for `markdwn`
+//!
+//! every suggestion goes through `suggest::closest()`, so they're all case-insensitive, and nothing is suggested for strings that aren't close to anything
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+/// the default number of invalid values that get suggestions in a parse, see `ParserSettings::max_suggestions()`
+pub const DEFAULT_MAX_SUGGESTIONS: usize = 10;
+
+/// returns the number of single character insertions, deletions, and substitutions needed to turn `a` into `b`, ignoring ascii case
+///
+/// # Examples
+/// ```
+/// use clia::suggest;
+/// //...
+///     assert_eq!(suggest::edit_distance("markdwn", "MARKDOWN"), 1);
+///     assert_eq!(suggest::edit_distance("biuld", "build"), 2);
+///     assert_eq!(suggest::edit_distance("", "test"), 4);
+///     assert_eq!(suggest::edit_distance("same", "SAME"), 0);
+/// ```
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    //DATA
+    let a: Vec<char> = a.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let b: Vec<char> = b.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    //only the previous row of the table is needed
+    for (i, a_char) in a.iter().enumerate() {
+        let mut current: Vec<usize> = Vec::with_capacity(b.len() + 1);
+        current.push(i + 1);
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution: usize = previous[j] + usize::from(a_char != b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// returns the edit distance between `a` and `b` (see `suggest::edit_distance()`) if it's at most `max`,
+/// only the part of the table within `max` of the diagonal is computed, and it stops as soon as the distance can't be at most `max`,
+/// so strings that aren't close are quick to rule out, however long they are
+///
+/// # None
+/// - returns none if the distance is more than `max`
+///
+/// # Examples
+/// ```
+/// use clia::suggest;
+/// //...
+///     assert_eq!(suggest::edit_distance_within("markdwn", "MARKDOWN", 2), Some(1));
+///     assert_eq!(suggest::edit_distance_within("biuld", "build", 1), None);
+///     assert_eq!(suggest::edit_distance_within("biuld", "build", 2), Some(2));
+///
+///     //the same as edit_distance, when it's close enough
+///     for (a, b) in [("kitten", "sitting"), ("", "test"), ("flaw", "lawn"), ("same", "SAME")] {
+///         for max in 0..8 {
+///             let distance = suggest::edit_distance(a, b);
+///             assert_eq!(suggest::edit_distance_within(a, b, max), (distance <= max).then_some(distance));
+///         }
+///     }
+///
+///     //long strings that aren't close are ruled out early
+///     assert_eq!(suggest::edit_distance_within(&"a".repeat(100_000), &"b".repeat(100_000), 3), None);
+/// ```
+pub fn edit_distance_within(a: &str, b: &str, max: usize) -> Option<usize> {
+    //DATA
+    let a: Vec<char> = a.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let b: Vec<char> = b.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let too_far: usize = max + 1;
+
+    //at least one insertion or deletion is needed for every character of difference in length
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+
+    //cells further than `max` from the diagonal are always too far, so they aren't computed
+    let mut previous: Vec<usize> = (0..=b.len()).map(|j| j.min(too_far)).collect();
+    for (i, a_char) in a.iter().enumerate() {
+        let mut current: Vec<usize> = vec![too_far; b.len() + 1];
+        current[0] = (i + 1).min(too_far);
+        for j in (i + 1).saturating_sub(max).max(1)..=(i + 1 + max).min(b.len()) {
+            let substitution: usize = previous[j - 1] + usize::from(*a_char != b[j - 1]);
+            current[j] = substitution.min(previous[j] + 1).min(current[j - 1] + 1).min(too_far);
+        }
+
+        //stop once every cell is too far, they can only grow
+        if current.iter().all(|distance| *distance > max) {
+            return None;
+        }
+        previous = current;
+    }
+
+    Some(previous[b.len()]).filter(|distance| *distance <= max)
+}
+
+/// returns the candidate closest to `value` (see `suggest::edit_distance()`), if it's close enough to be what the user meant to type
+///
+/// a candidate is close enough if at most a third of its characters (rounded up) need to change, ties go to the first candidate
+///
+/// distances are computed with `suggest::edit_distance_within()`, so candidates that can't be closer than the closest so far are ruled out early
+///
+/// # None
+/// - returns none if no candidate is close enough, so nonsense doesn't get an absurd suggestion
+///
+/// # Examples
+/// ```
+/// use clia::suggest;
+/// //...
+///     let formats = ["DEFAULT", "BULLET", "MARKDOWN", "NUMERIC"];
+///     assert_eq!(suggest::closest("markdwn", formats), Some("MARKDOWN"));
+///     assert_eq!(suggest::closest("bullet", formats), Some("BULLET"));
+///     assert_eq!(suggest::closest("xyz", formats), None);
+///     assert_eq!(suggest::closest("fancy", formats), None);
+/// ```
+pub fn closest<'a>(value: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    //DATA
+    let mut best: Option<(usize, &str)> = None;
+
+    //a later candidate has to be strictly closer to win, so there's no need to compute distances past that
+    for candidate in candidates {
+        if best.is_some_and(|(distance, _)| distance == 0) {
+            break;
+        }
+        let max: usize = candidate.chars().count().div_ceil(3).min(best.map_or(usize::MAX, |(distance, _)| distance - 1));
+        if let Some(distance) = edit_distance_within(value, candidate, max) {
+            best = Some((distance, candidate));
+        }
+    }
+
+    best.map(|(_, candidate)| candidate)
+}
+
+/// returns ` did you mean '{closest}'?` (with the leading space) for the candidate closest to `value`, see `suggest::closest()`,
+/// or an empty string if nothing is close enough, useful for appending to error messages
+///
+/// # Examples
+/// ```
+/// use clia::suggest;
+/// //...
+///     assert_eq!(suggest::did_you_mean("biuld", ["build", "test"]), " did you mean 'build'?");
+///     assert_eq!(suggest::did_you_mean("zzz", ["build", "test"]), "");
+/// ```
+pub fn did_you_mean<'a>(value: &str, candidates: impl IntoIterator<Item = &'a str>) -> String {
+    closest(value, candidates).map_or(String::new(), |closest| format!(" did you mean '{}'?", closest))
+}
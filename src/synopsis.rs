@@ -0,0 +1,253 @@
+//! # Synopsis
+//! 'synopsis' is a module containing utilities for
+//! describing what the usage line of a help message says, as data instead of a string, ei. to render a program's interface as a form
+//!
+//! a synopsis is made with `CliSpec::synopsis()`, and the usage line of help messages is rendered from one, see `Synopsis::usage()`
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use crate::option_args::ClOption;
+use crate::parameter_args::ClParameter;
+
+/// what an option of a synopsis is, see `SynopsisOption::get_kind()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SynopsisKind {
+    /// a ClOption::Flag
+    Flag,
+    /// a ClOption::FlagToggle
+    Toggle,
+    /// a ClOption::FlagCount
+    Count,
+    /// a ClOption::FlagPreset
+    Preset,
+    /// a ClOption::FlagList, which takes a list
+    List,
+    /// a ClOption::FlagData, which takes data
+    Data,
+}
+
+/// an option of a synopsis, its flags and what it takes after them
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SynopsisOption {
+    short_flag: String,
+    long_flag: String,
+    kind: SynopsisKind,
+    placeholder: Option<String>,
+}
+impl SynopsisOption {
+    /// creates a new SynopsisOption describing `option`
+    fn new(option: &ClOption) -> SynopsisOption {
+        let (kind, placeholder): (SynopsisKind, Option<&str>) = match option {
+            ClOption::Flag { .. } => (SynopsisKind::Flag, None),
+            ClOption::FlagToggle { .. } => (SynopsisKind::Toggle, None),
+            ClOption::FlagCount { .. } => (SynopsisKind::Count, None),
+            ClOption::FlagPreset { .. } => (SynopsisKind::Preset, None),
+            ClOption::FlagList { list_name, .. } => (SynopsisKind::List, Some(list_name)),
+            ClOption::FlagData { data_name, .. } => (SynopsisKind::Data, Some(data_name)),
+        };
+        SynopsisOption {
+            short_flag: option.get_short_flag().to_string(),
+            long_flag: option.get_long_flag().to_string(),
+            kind,
+            placeholder: placeholder.map(|placeholder| placeholder.to_string()),
+        }
+    }
+
+    //getter methods
+    /// get a reference to the short flag of the option, empty if it doesn't have one
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag(&ClOptionInfo::new("", "--recursive", "Search through subdirectories").unwrap())], &[]);
+    ///     assert_eq!(spec.synopsis().get_options()[0].get_short_flag(), "");
+    /// ```
+    pub fn get_short_flag(&self) -> &str {&self.short_flag}
+
+    /// get a reference to the long flag of the option, empty if it doesn't have one
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap())], &[]);
+    ///     assert_eq!(spec.synopsis().get_options()[0].get_long_flag(), "--recursive");
+    /// ```
+    pub fn get_long_flag(&self) -> &str {&self.long_flag}
+
+    /// get what the option is
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec, synopsis::SynopsisKind};
+    /// //...
+    ///     let spec = CliSpec::new(&[ClOption::new_flag_toggle(&ClOptionInfo::new("-c", "--color", "Color the output").unwrap())], &[]);
+    ///     assert_eq!(spec.synopsis().get_options()[0].get_kind(), SynopsisKind::Toggle);
+    /// ```
+    pub fn get_kind(&self) -> SynopsisKind {self.kind}
+
+    /// get a reference to the name of the list/data the option takes, shown in help messages ei. `FORMAT`
+    ///
+    /// # None
+    /// - the option doesn't take a list/data
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{option_args::{ClOption, ClOptionInfo}, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[
+    ///         ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT"),
+    ///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+    ///     ], &[]);
+    ///     assert_eq!(spec.synopsis().get_options()[0].get_placeholder(), Some("FORMAT"));
+    ///     assert_eq!(spec.synopsis().get_options()[1].get_placeholder(), None);
+    /// ```
+    pub fn get_placeholder(&self) -> Option<&str> {self.placeholder.as_deref()}
+}
+
+/// a parameter of a synopsis, its name and whether it's optional and variadic
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SynopsisParam {
+    name: String,
+    optional: bool,
+    variadic: bool,
+}
+impl SynopsisParam {
+    /// creates a new SynopsisParam describing `parameter`
+    fn new(parameter: &ClParameter) -> SynopsisParam {
+        SynopsisParam {
+            name: parameter.get_name().to_string(),
+            optional: parameter.get_optional(),
+            variadic: parameter.get_variadic(),
+        }
+    }
+
+    //getter methods
+    /// get a reference to the name of the parameter
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[], &[ClParameter::new("PATH", "Path to search in")]);
+    ///     assert_eq!(spec.synopsis().get_parameters()[0].get_name(), "PATH");
+    /// ```
+    pub fn get_name(&self) -> &str {&self.name}
+
+    /// get whether the parameter is optional, see `ClParameter::optional()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[], &[ClParameter::new("PATH", "Path to search in").optional(true)]);
+    ///     assert!(spec.synopsis().get_parameters()[0].get_optional());
+    /// ```
+    pub fn get_optional(&self) -> bool {self.optional}
+
+    /// get whether the parameter is variadic, see `ClParameter::variadic()`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[], &[ClParameter::new("FILES", "Files to search").variadic(true)]);
+    ///     assert!(spec.synopsis().get_parameters()[0].get_variadic());
+    /// ```
+    pub fn get_variadic(&self) -> bool {self.variadic}
+}
+
+/// stores what the usage line of a help message says: the options of a program, and its parameters in the order they're expected
+///
+/// # Examples
+/// ```
+/// use clia::{option_args::{ClOption, ClOptionInfo}, parameter_args::ClParameter, spec::CliSpec, synopsis::SynopsisKind, Parser};
+/// //...
+///     let valid_options: Vec<ClOption> = vec![
+///         ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+///         ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+///     ];
+///     let expected_parameters: Vec<ClParameter> = vec![
+///         ClParameter::new("QUERY", "String to search for"),
+///         ClParameter::new("LIMIT", "Most results to show").optional(true),
+///         ClParameter::new("FILES", "Files to search").variadic(true),
+///     ];
+///     let spec = CliSpec::new(&valid_options, &expected_parameters).with_about("foo.exe", "by Anthony Rubick", "Just here as an example");
+///
+///     let synopsis = spec.synopsis();
+///     assert_eq!(
+///         synopsis.get_options().iter().map(|option| (option.get_long_flag(), option.get_kind(), option.get_placeholder())).collect::<Vec<_>>(),
+///         vec![("--recursive", SynopsisKind::Flag, None), ("--filter", SynopsisKind::List, Some("EXTENSIONS"))]
+///     );
+///     assert_eq!(
+///         synopsis.get_parameters().iter().map(|parameter| (parameter.get_name(), parameter.get_optional(), parameter.get_variadic())).collect::<Vec<_>>(),
+///         vec![("QUERY", false, false), ("LIMIT", true, false), ("FILES", false, true)]
+///     );
+///
+///     //the usage line of the help message is rendered from it
+///     assert_eq!(synopsis.usage("USAGE:", "foo.exe"), "USAGE: foo.exe [OPTIONS]... <QUERY> [LIMIT] <FILES>...");
+///     assert!(spec.help().contains(&format!("\n{}\n", synopsis.usage("USAGE:", "foo.exe"))));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Synopsis {
+    options: Vec<SynopsisOption>,
+    parameters: Vec<SynopsisParam>,
+}
+impl Synopsis {
+    /// creates a new Synopsis of `valid_options` and `expected_parameters`
+    pub(crate) fn new<'a>(valid_options: impl IntoIterator<Item = &'a ClOption>, expected_parameters: impl IntoIterator<Item = &'a ClParameter>) -> Synopsis {
+        Synopsis {
+            options: valid_options.into_iter().map(SynopsisOption::new).collect(),
+            parameters: expected_parameters.into_iter().map(SynopsisParam::new).collect(),
+        }
+    }
+
+    /// returns the usage line of the help message for a program called `title`, that starts with `usage_title`, ei. `USAGE: foo.exe [OPTIONS]... <QUERY> [PATH]`,
+    /// required parameters are in angle brackets, optional ones in brackets, and a variadic parameter is followed by `...`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::{parameter_args::ClParameter, spec::CliSpec};
+    /// //...
+    ///     let spec = CliSpec::new(&[], &[ClParameter::new("PATH", "Path to search in")]);
+    ///     assert_eq!(spec.synopsis().usage("Usage:", "foo.exe"), "Usage: foo.exe <PATH>");
+    ///     
+    ///     let spec = CliSpec::new(&[], &[ClParameter::new("QUERY", "String to search for"), ClParameter::new_optional("PATH", "Path to search in")]);
+    ///     assert_eq!(spec.synopsis().usage("Usage:", "foo.exe"), "Usage: foo.exe <QUERY> [PATH]");
+    /// ```
+    pub fn usage(&self, usage_title: &str, title: &str) -> String {
+        let mut usage: String = format!("{} {}", usage_title, title);
+        if !self.options.is_empty() {
+            usage += " [OPTIONS]...";
+        }
+        for parameter in self.parameters.iter() {
+            //optional parameters are in brackets, required ones in angle brackets
+            let name: String = if parameter.optional {format!("[{}]", parameter.name)} else {format!("<{}>", parameter.name)};
+            usage += format!(" {}{}", name, if parameter.variadic {"..."} else {""}).as_str();
+        }
+        usage
+    }
+
+    //getter methods
+    /// get a reference to the options, in the order they're defined
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     assert!(CliSpec::new(&[], &[]).synopsis().get_options().is_empty());
+    /// ```
+    pub fn get_options(&self) -> &Vec<SynopsisOption> {&self.options}
+
+    /// get a reference to the parameters, in the order they're expected
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::spec::CliSpec;
+    /// //...
+    ///     assert!(CliSpec::new(&[], &[]).synopsis().get_parameters().is_empty());
+    /// ```
+    pub fn get_parameters(&self) -> &Vec<SynopsisParam> {&self.parameters}
+}
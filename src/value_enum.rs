@@ -0,0 +1,70 @@
+//! # Value enum
+//! 'value_enum' is a module containing utilities for
+//! binding the allowed values of a ClOption::FlagData to an enum, so you can `match` on the enum instead of a string
+//!
+//! implement `ValueEnum` with the `value_enum!` macro, define the option with `ClOption::new_flag_data_enum()`,
+//! and get the value with `Parser::option_enum()`
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+/// an enum whose variants are the allowed values of an option, usually implemented with the `value_enum!` macro
+///
+/// # Examples
+/// ```
+/// use clia::value_enum::ValueEnum;
+/// //...
+///     clia::value_enum! {
+///         Format { Default, Bullet, Markdown, Numeric }
+///     }
+///
+///     assert_eq!(Format::variants(), &["Default", "Bullet", "Markdown", "Numeric"]);
+///     assert_eq!(Format::from_name("Bullet"), Some(Format::Bullet));
+///     assert_eq!(Format::from_name("Fancy"), None);
+/// ```
+pub trait ValueEnum: Sized {
+    /// returns the names of every variant, in the order they were declared
+    fn variants() -> &'static [&'static str];
+    /// returns the variant named `name`, if there is one
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+/// defines a field-less enum and implements `value_enum::ValueEnum` for it, the name of each variant is its identifier
+///
+/// the enum derives Clone, Copy, Debug, PartialEq, and Eq, attributes (like doc comments) and a visibility can be given before the name
+///
+/// # Examples
+/// ```
+/// use clia::value_enum::ValueEnum;
+/// //...
+///     clia::value_enum! {
+///         /// formats to print output in
+///         pub Format { Default, Bullet }
+///     }
+///
+///     assert_eq!(Format::variants(), &["Default", "Bullet"]);
+/// ```
+#[macro_export]
+macro_rules! value_enum {
+    ($(#[$meta:meta])* $vis:vis $name:ident { $($variant:ident),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        $vis enum $name {
+            $(
+                #[allow(missing_docs)]
+                $variant,
+            )*
+        }
+        impl $crate::value_enum::ValueEnum for $name {
+            fn variants() -> &'static [&'static str] {
+                &[$(stringify!($variant)),*]
+            }
+            fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $(stringify!($variant) => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,225 @@
+//! # Value parser
+//! 'value_parser' is a module containing utilities for
+//! checking that the data of an option is well-formed, ei. that it's a number, or a path that exists
+//!
+//! attach a `ValueParser` to a ClOption::FlagData with `ClOption::with_value_parser()`, it runs while parsing,
+//! after the data has been matched against the options allowed values
+//!
+//! data can also be decoded into bytes with an `Encoding`, see `ClOption::encoding()`
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_doc_code_examples)]
+
+use std::path::Path;
+use std::time::Duration;
+
+/// the built-in checks for the data of an option
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueParser {
+    /// a whole number, ei. `-12`, that fits in an i64
+    IntParser,
+    /// a number, ei. `3.14`, that fits in an f64
+    FloatParser,
+    /// a boolean, any of (ignoring case): `true`, `false`, `yes`, `no`, `on`, `off`, `1`, `0`
+    BoolParser,
+    /// a path, that must exist on the file system if `must_exist` is true, `-` is always accepted, as it conventionally means stdin or stdout
+    PathParser {
+        /// does the path have to exist
+        must_exist: bool,
+    },
+    /// a url with a scheme and a host, ei. `https://example.com/path`
+    UrlParser,
+    /// a duration, ei. `30s`, see `value_parser::parse_duration()`
+    DurationParser,
+}
+impl ValueParser {
+    /// returns a description of what went wrong if `value` isn't well-formed according to this parser
+    ///
+    /// # Errors
+    /// - `value` isn't what this parser expects, the error describes what was expected, ei. `expected an integer`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::value_parser::ValueParser;
+    /// //...
+    ///     assert!(ValueParser::IntParser.check("-12").is_ok());
+    ///     assert_eq!(ValueParser::IntParser.check("twelve").unwrap_err(), "expected an integer");
+    ///     assert!(ValueParser::IntParser.check("1.5").is_err());
+    ///
+    ///     assert!(ValueParser::FloatParser.check("3.14").is_ok());
+    ///     assert!(ValueParser::FloatParser.check("12").is_ok());
+    ///     assert_eq!(ValueParser::FloatParser.check("pi").unwrap_err(), "expected a number");
+    ///
+    ///     for value in ["true", "FALSE", "yes", "No", "on", "off", "1", "0"] {
+    ///         assert!(ValueParser::BoolParser.check(value).is_ok());
+    ///     }
+    ///     assert!(ValueParser::BoolParser.check("maybe").is_err());
+    ///
+    ///     assert!(ValueParser::PathParser { must_exist: false }.check("path/that/doesnt/exist").is_ok());
+    ///     assert!(ValueParser::PathParser { must_exist: true }.check("src").is_ok());
+    ///     assert_eq!(ValueParser::PathParser { must_exist: true }.check("path/that/doesnt/exist").unwrap_err(), "expected a path that exists");
+    ///     assert!(ValueParser::PathParser { must_exist: false }.check("").is_err());
+    ///     assert!(ValueParser::PathParser { must_exist: true }.check("-").is_ok()); //conventionally stdin or stdout
+    ///
+    ///     assert!(ValueParser::UrlParser.check("https://example.com/path?query").is_ok());
+    ///     assert!(ValueParser::UrlParser.check("example.com").is_err()); //no scheme
+    ///     assert!(ValueParser::UrlParser.check("https://").is_err()); //no host
+    ///     assert!(ValueParser::UrlParser.check("1http://example.com").is_err()); //schemes start with a letter
+    ///
+    ///     assert!(ValueParser::DurationParser.check("500ms").is_ok());
+    ///     assert!(ValueParser::DurationParser.check("30x").is_err());
+    /// ```
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        match self {
+            ValueParser::IntParser => value.parse::<i64>().map(|_| ()).map_err(|_| String::from("expected an integer")),
+            ValueParser::FloatParser => value.parse::<f64>().map(|_| ()).map_err(|_| String::from("expected a number")),
+            ValueParser::BoolParser => {
+                if ["true", "false", "yes", "no", "on", "off", "1", "0"].iter().any(|accepted| accepted.eq_ignore_ascii_case(value)) {
+                    Ok(())
+                } else {
+                    Err(String::from("expected a boolean (true/false, yes/no, on/off, 1/0)"))
+                }
+            },
+            ValueParser::PathParser { must_exist } => {
+                if value.is_empty() {
+                    Err(String::from("expected a path"))
+                } else if *must_exist && value.ne("-") && !Path::new(value).exists() {
+                    Err(String::from("expected a path that exists"))
+                } else {
+                    Ok(())
+                }
+            },
+            ValueParser::UrlParser => {
+                //DATA
+                let (scheme, rest) = value.split_once("://").unwrap_or(("", ""));
+                let host: &str = rest.split(['/', '?', '#']).next().unwrap_or("");
+
+                let valid_scheme: bool = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                    && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c));
+                if valid_scheme && !host.is_empty() {
+                    Ok(())
+                } else {
+                    Err(String::from("expected a url, ei. https://example.com"))
+                }
+            },
+            ValueParser::DurationParser => parse_duration(value).map(|_| ()),
+        }
+    }
+}
+
+/// returns the duration in `value`, a number followed by a unit: `ms` (milliseconds), `s` (seconds), `m` (minutes), or `h` (hours), ei. `30s` or `1.5h`
+///
+/// # Notes:
+/// - a number without a unit is in seconds, ei. `45` is `45s`
+/// - the number can have a fraction, but can't be negative
+///
+/// # Errors
+/// - `value` doesn't start with a number, or the number is negative or too big
+/// - the unit isn't one of the above, ei. `30x`
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use clia::value_parser;
+/// //...
+///     assert_eq!(value_parser::parse_duration("30s"), Ok(Duration::from_secs(30)));
+///     assert_eq!(value_parser::parse_duration("500ms"), Ok(Duration::from_millis(500)));
+///     assert_eq!(value_parser::parse_duration("2m"), Ok(Duration::from_secs(120)));
+///     assert_eq!(value_parser::parse_duration("1.5h"), Ok(Duration::from_secs(5400)));
+///     assert_eq!(value_parser::parse_duration("45"), Ok(Duration::from_secs(45))); //seconds
+///
+///     assert_eq!(value_parser::parse_duration("30x").unwrap_err(), "unknown unit 'x' in '30x', expected one of: ms, s, m, h");
+///     assert_eq!(value_parser::parse_duration("s").unwrap_err(), "expected a duration, ei. 30s, 500ms, 2m, or 1h");
+///     assert!(value_parser::parse_duration("-5s").is_err());
+///     assert!(value_parser::parse_duration("").is_err());
+/// ```
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    //DATA
+    let unit_start: usize = value.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(value.len());
+    let (number, unit) = value.split_at(unit_start);
+    let number: f64 = number.parse::<f64>().map_err(|_| String::from("expected a duration, ei. 30s, 500ms, 2m, or 1h"))?;
+
+    let seconds: f64 = match unit {
+        "ms" => number / 1000.0,
+        "s" | "" => number,
+        "m" => number * 60.0,
+        "h" => number * 60.0 * 60.0,
+        _ => return Err(format!("unknown unit '{}' in '{}', expected one of: ms, s, m, h", unit, value)),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("duration '{}' is too long", value))
+}
+
+/// the encodings the data of an option can be decoded from, see `ClOption::encoding()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// pairs of hex digits, in either case, optionally prefixed with `0x`, ei. `0xDEADbeef`
+    Hex,
+    /// standard base64, with or without padding, ei. `aGVsbG8=`
+    Base64,
+}
+impl Encoding {
+    /// returns the bytes encoded in `value`, or a description of what went wrong if it isn't encoded properly
+    ///
+    /// # Errors
+    /// - `value` isn't encoded with this encoding, the error describes what was expected, ei. `expected hex, ei. 0a1b`
+    ///
+    /// # Examples
+    /// ```
+    /// use clia::value_parser::Encoding;
+    /// //...
+    ///     assert_eq!(Encoding::Hex.decode("0a1B"), Ok(vec![0x0a, 0x1b]));
+    ///     assert_eq!(Encoding::Hex.decode("0xff00"), Ok(vec![0xff, 0x00]));
+    ///     assert!(Encoding::Hex.decode("abc").is_err()); //an odd number of digits
+    ///     assert_eq!(Encoding::Hex.decode("0g").unwrap_err(), "expected hex, ei. 0a1b");
+    ///
+    ///     assert_eq!(Encoding::Base64.decode("aGVsbG8="), Ok(b"hello".to_vec()));
+    ///     assert_eq!(Encoding::Base64.decode("aGVsbG8"), Ok(b"hello".to_vec())); //padding is optional
+    ///     assert_eq!(Encoding::Base64.decode("+/+/"), Ok(vec![0xfb, 0xff, 0xbf]));
+    ///     assert!(Encoding::Base64.decode("aGVsbG8==").is_err()); //too much padding
+    ///     assert_eq!(Encoding::Base64.decode("aGV*bG8=").unwrap_err(), "expected base64, ei. aGVsbG8=");
+    /// ```
+    pub fn decode(&self, value: &str) -> Result<Vec<u8>, String> {
+        match self {
+            Encoding::Hex => {
+                let digits: &str = value.strip_prefix("0x").unwrap_or(value);
+                if !digits.len().is_multiple_of(2) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(String::from("expected hex, ei. 0a1b"));
+                }
+                Ok((0..digits.len()).step_by(2).map(|index| u8::from_str_radix(&digits[index..index + 2], 16).unwrap_or_default()).collect())
+            },
+            Encoding::Base64 => {
+                //DATA
+                let error = || String::from("expected base64, ei. aGVsbG8=");
+                let unpadded: &str = value.trim_end_matches('=');
+                let padding: usize = value.len() - unpadded.len();
+                let mut bytes: Vec<u8> = Vec::with_capacity(unpadded.len() * 3 / 4);
+                let (mut bits, mut bit_count): (u32, u32) = (0, 0);
+
+                //padding only fills out the last group of 4
+                if unpadded.len() % 4 == 1 || padding > 2 || (padding > 0 && !value.len().is_multiple_of(4)) {
+                    return Err(error());
+                }
+
+                for c in unpadded.bytes() {
+                    let sextet: u8 = match c {
+                        b'A'..=b'Z' => c - b'A',
+                        b'a'..=b'z' => c - b'a' + 26,
+                        b'0'..=b'9' => c - b'0' + 52,
+                        b'+' => 62,
+                        b'/' => 63,
+                        _ => return Err(error()),
+                    };
+                    bits = (bits << 6) | u32::from(sextet);
+                    bit_count += 6;
+                    if bit_count >= 8 {
+                        bit_count -= 8;
+                        bytes.push((bits >> bit_count) as u8);
+                        bits &= (1 << bit_count) - 1;
+                    }
+                }
+
+                Ok(bytes)
+            },
+        }
+    }
+}
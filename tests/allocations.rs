@@ -0,0 +1,112 @@
+//! # Allocations
+//! counts the allocations of parsing representative command lines, with a counting global allocator
+//!
+//! the counts are compared between command lines that only differ in the length of a list, so the checks don't depend on the
+//! allocations of the standard library, only on how many each list item costs
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use clia::{
+    option_args::{ClOption, ClOptionInfo},
+    option_parser,
+};
+
+/// the system allocator, counting the allocations made on each thread
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// returns how many allocations `f` made on this thread
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before: usize = ALLOCATIONS.with(|allocations| allocations.get());
+    f();
+    ALLOCATIONS.with(|allocations| allocations.get()) - before
+}
+
+/// the options of a typical search tool
+fn valid_options() -> Vec<ClOption> {
+    vec![
+        ClOption::new_flag(&ClOptionInfo::new("-r", "--recursive", "Search through subdirectories").unwrap()),
+        ClOption::new_flag_list(&ClOptionInfo::new("-f", "--filter", "Extensions to search").unwrap(), "EXTENSIONS"),
+        ClOption::new_flag_list(&ClOptionInfo::new("-i", "--ignore", "Directories to skip").unwrap(), "DIRECTORIES").bracketed_lists(true),
+        ClOption::new_flag_data(&ClOptionInfo::new("-F", "--format", "Format the output").unwrap(), "FORMAT").with_allowed_values(&["DEFAULT", "BULLET"]),
+        ClOption::new_flag_data(&ClOptionInfo::new("-o", "--output", "File to write to").unwrap(), "FILE"),
+    ]
+}
+
+/// the args of a search with `items` extensions and `items` ignored directories
+fn args_with(items: usize) -> Vec<String> {
+    let extensions: Vec<String> = (0..items).map(|item| format!("ext{}", item)).collect();
+    let directories: Vec<String> = (0..items).map(|item| format!("dir{}", item)).collect();
+    ["foo.exe", "-r", "-f", &extensions.join(","), "-i", &format!("[{}]", directories.join(" ")), "-F", "BULLET", "-o", "out.txt", "src"]
+    .iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn list_items_cost_one_allocation_each() {
+    let valid_options: Vec<ClOption> = valid_options();
+    let (few, many): (Vec<String>, Vec<String>) = (args_with(2), args_with(34));
+    let mut found: Vec<ClOption> = Vec::new();
+
+    //warm up anything allocated once per thread
+    option_parser::parse_for_options(&few, &valid_options).unwrap();
+
+    let few_allocations: usize = count_allocations(|| found = option_parser::parse_for_options(&few, &valid_options).unwrap());
+    assert_eq!(found[1].get_list().unwrap().len(), 2);
+    let many_allocations: usize = count_allocations(|| found = option_parser::parse_for_options(&many, &valid_options).unwrap());
+    assert_eq!(found[1].get_list().unwrap().len(), 34);
+    assert_eq!(found[2].get_list().unwrap().len(), 34);
+
+    //the 64 extra items are each stored as a String, the splitting itself only grows a reused buffer
+    let extra: usize = many_allocations - few_allocations;
+    assert!(extra <= 64 + 8, "{} extra allocations for 64 extra list items", extra);
+}
+
+/// splits the lists of `args` with the public helpers, which split each list into a buffer of its own
+fn split_with_helpers(args: &[String]) -> (Vec<String>, Vec<String>) {
+    (option_parser::get_list_after_flag(args, "-f").unwrap(), option_parser::get_bracketed_list_after_flag(args, "-i").unwrap())
+}
+
+#[test]
+fn reused_buffer_allocates_less_than_fresh_ones() {
+    let valid_options: Vec<ClOption> = valid_options();
+    let (few, many): (Vec<String>, Vec<String>) = (args_with(2), args_with(34));
+    let mut found: Vec<ClOption> = Vec::new();
+    let mut lists: (Vec<String>, Vec<String>) = (Vec::new(), Vec::new());
+
+    //warm up anything allocated once per thread
+    option_parser::parse_for_options(&few, &valid_options).unwrap();
+    split_with_helpers(&few);
+
+    //both store every item as a String, so the difference is only in the buffers the lists are split into
+    let parser_extra: usize = count_allocations(|| found = option_parser::parse_for_options(&many, &valid_options).unwrap())
+        - count_allocations(|| found = option_parser::parse_for_options(&few, &valid_options).unwrap());
+    let helpers_extra: usize = count_allocations(|| lists = split_with_helpers(&many)) - count_allocations(|| lists = split_with_helpers(&few));
+    assert_eq!((lists.0.len(), lists.1.len()), (2, 2));
+    assert_eq!(found[1].get_list(), Some(&lists.0));
+    assert_eq!(found[2].get_list(), Some(&lists.1));
+
+    assert!(parser_extra < helpers_extra, "{} extra allocations with a reused buffer, {} with a buffer per list", parser_extra, helpers_extra);
+}
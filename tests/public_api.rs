@@ -0,0 +1,162 @@
+//! # Public API
+//! a snapshot of the public api of clia, checked at compile time
+//!
+//! every assertion here names something users depend on, with its signature, so a change that breaks them fails to compile here first,
+//! if a change here is deliberate, update the assertion (and the version) along with it
+
+//the signatures are spelled out on purpose
+#![allow(clippy::type_complexity)]
+
+use std::error::Error;
+use std::fmt::{Debug, Display};
+
+use clia::{
+    help::{self, HelpTemplate},
+    option_args::{ClOption, ClOptionInfo, ClOptionInfoBuilder, LazyDefault},
+    option_parser,
+    parameter_args::ClParameter,
+    parameter_parser,
+    parse_error::{ParseError, ParseErrorKind, ParseErrors, ValidationPhase},
+    parser_settings::ParserSettings,
+    spec::CliSpec,
+    synopsis::Synopsis,
+    Parser,
+};
+
+/// asserts that each path is a function with the given signature, ei. `api! { ClOption::new_flag: fn(&ClOptionInfo) -> ClOption; }`
+macro_rules! api {
+    ($($path:path : $signature:ty;)*) => {
+        $(const _: $signature = $path;)*
+    };
+}
+
+/// asserts that each type implements the given traits, ei. `bounds! { ParseError: Error | Send | Sync; }`
+macro_rules! bounds {
+    ($($type:ty : $($bound:path)|+;)*) => {
+        $(const _: fn() = || {
+            fn check<T: ?Sized $(+ $bound)+>() {}
+            check::<$type>();
+        };)*
+    };
+}
+
+type ParseResult<T> = Result<T, Box<dyn Error>>;
+
+//Parser
+api! {
+    Parser::new: fn(&[String], &[ClOption], &[ClParameter]) -> ParseResult<Parser>;
+    Parser::new_with_settings: fn(&[String], &[ClOption], &[ClParameter], &ParserSettings) -> ParseResult<Parser>;
+    Parser::help: fn(&str, &str, &str, &[ClOption], &[ClParameter]) -> String;
+    Parser::get_valid_options: fn(&Parser) -> &Vec<ClOption>;
+    Parser::get_expected_parameters: fn(&Parser) -> &Vec<ClParameter>;
+    Parser::get_option_arguments_found: fn(&Parser) -> &Vec<ClOption>;
+    Parser::get_parameter_arguments_found: fn(&Parser) -> &Vec<ClParameter>;
+    Parser::get_unmatched_arguments: fn(&Parser) -> &Vec<String>;
+    Parser::get_warnings: fn(&Parser) -> &Vec<String>;
+    Parser::get_flag: fn(&Parser, &str) -> bool;
+    Parser::get_one: for<'a> fn(&'a Parser, &str) -> Option<&'a str>;
+    Parser::get_many: for<'a> fn(&'a Parser, &str) -> Option<&'a [String]>;
+}
+
+//options
+api! {
+    ClOptionInfo::new: fn(&str, &str, &str) -> ParseResult<ClOptionInfo>;
+    ClOptionInfo::builder: fn() -> ClOptionInfoBuilder;
+    ClOptionInfo::with_long_description: fn(ClOptionInfo, &str) -> ClOptionInfo;
+    ClOptionInfo::get_short_flag: fn(&ClOptionInfo) -> &str;
+    ClOptionInfo::get_long_flag: fn(&ClOptionInfo) -> &str;
+    ClOptionInfo::get_description: fn(&ClOptionInfo) -> &str;
+    ClOptionInfo::has_flag: fn(&ClOptionInfo, &str) -> bool;
+    ClOptionInfoBuilder::build: fn(ClOptionInfoBuilder) -> ParseResult<ClOptionInfo>;
+    ClOption::new_flag: fn(&ClOptionInfo) -> ClOption;
+    ClOption::new_flag_toggle: fn(&ClOptionInfo) -> ClOption;
+    ClOption::new_flag_count: fn(&ClOptionInfo) -> ClOption;
+    ClOption::new_flag_preset: fn(&ClOptionInfo, &[&str]) -> ClOption;
+    ClOption::new_flag_list: fn(&ClOptionInfo, &str) -> ClOption;
+    ClOption::new_flag_data: fn(&ClOptionInfo, &str) -> ClOption;
+    ClOption::new_choice: fn(&ClOptionInfo, &str, &[&str], usize) -> ClOption;
+    ClOption::gen_help_line: fn(&ClOption) -> String;
+    ClOption::gen_help_line_wrapped: fn(&ClOption, usize) -> String;
+    ClOption::get_info: fn(&ClOption) -> &ClOptionInfo;
+    ClOption::get_short_flag: fn(&ClOption) -> &str;
+    ClOption::get_long_flag: fn(&ClOption) -> &str;
+    ClOption::get_present: fn(&ClOption) -> bool;
+    ClOption::get_list: fn(&ClOption) -> Option<&Vec<String>>;
+    ClOption::get_data: fn(&ClOption) -> Option<&str>;
+    ClOption::get_toggle: fn(&ClOption) -> Option<bool>;
+    ClOption::get_count: fn(&ClOption) -> Option<u32>;
+    ClOption::get_default_with: fn(&ClOption) -> Option<&LazyDefault>;
+}
+
+//parameters
+api! {
+    ClParameter::new: fn(&str, &str) -> ClParameter;
+    ClParameter::new_optional: fn(&str, &str) -> ClParameter;
+    ClParameter::optional: fn(ClParameter, bool) -> ClParameter;
+    ClParameter::variadic: fn(ClParameter, bool) -> ClParameter;
+    ClParameter::gen_help_line: fn(&ClParameter) -> String;
+    ClParameter::get_name: fn(&ClParameter) -> &str;
+    ClParameter::get_description: fn(&ClParameter) -> &str;
+    ClParameter::get_data: fn(&ClParameter) -> &str;
+    ClParameter::get_optional: fn(&ClParameter) -> bool;
+    ClParameter::is_optional: fn(&ClParameter) -> bool;
+    ClParameter::get_variadic: fn(&ClParameter) -> bool;
+    ClParameter::get_values: fn(&ClParameter) -> &Vec<String>;
+}
+
+//parsing functions
+api! {
+    option_parser::parse_for_options: fn(&[String], &[ClOption]) -> ParseResult<Vec<ClOption>>;
+    option_parser::parse_for_options_with_settings: fn(&[String], &[ClOption], &ParserSettings) -> ParseResult<Vec<ClOption>>;
+    option_parser::is_flag: fn(&str) -> bool;
+    option_parser::find_terminator: fn(&[String]) -> Option<usize>;
+    option_parser::find_duplicated_flags: fn(&[String], &[ClOption]) -> Vec<String>;
+    option_parser::find_unknown_flags: fn(&[String], &[ClOption]) -> Vec<String>;
+    option_parser::get_list_after_flag: fn(&[String], &str) -> ParseResult<Vec<String>>;
+    option_parser::get_data_after_flag: fn(&[String], &str) -> ParseResult<String>;
+    parameter_parser::parse_for_parameters: fn(&[String], &[ClParameter]) -> ParseResult<Vec<ClParameter>>;
+    parameter_parser::parse_for_parameters_strict: fn(&[String], &[ClParameter], &[ClOption]) -> ParseResult<Vec<ClParameter>>;
+}
+
+//errors
+api! {
+    ParseError::new: fn(ParseErrorKind, Option<usize>, &str) -> ParseError;
+    ParseErrors::new: fn(Vec<ParseError>) -> ParseErrors;
+}
+
+//settings, help, and specs
+api! {
+    ParserSettings::new: fn() -> ParserSettings;
+    HelpTemplate::new: fn() -> HelpTemplate;
+    help::wrap: fn(&str, usize) -> Vec<String>;
+    CliSpec::new: fn(&[ClOption], &[ClParameter]) -> CliSpec;
+    CliSpec::with_about: fn(CliSpec, &str, &str, &str) -> CliSpec;
+    CliSpec::help: fn(&CliSpec) -> String;
+    CliSpec::usage: fn(&CliSpec) -> String;
+    CliSpec::synopsis: fn(&CliSpec) -> Synopsis;
+}
+#[cfg(feature = "build-spec")]
+api! {
+    clia::spec::load_toml: fn(&str) -> Result<CliSpec, clia::spec::SpecError>;
+    clia::spec::load_lines: fn(&str) -> Result<CliSpec, clia::spec::SpecError>;
+}
+
+//trait bounds, so errors can cross threads and be boxed, and options can be shared
+bounds! {
+    ParseError: Error | Display | Debug | Clone | PartialEq | Send | Sync;
+    ParseErrors: Debug | Clone | PartialEq | Send | Sync;
+    ParseErrorKind: Copy | Debug | PartialEq | Eq | std::hash::Hash | Send | Sync;
+    ValidationPhase: Copy | Ord | std::hash::Hash;
+    ClOption: Clone | Debug | PartialEq | Send | Sync;
+    ClOptionInfo: Clone | Debug | PartialEq | Send | Sync;
+    ClParameter: Clone | Debug | PartialEq | Send | Sync;
+    ParserSettings: Clone | Send | Sync;
+    CliSpec: Clone | Debug | Send | Sync;
+    Parser: Send | Sync;
+}
+
+#[test]
+fn parse_error_boxes_into_send_sync_error() {
+    let error: Box<dyn Error + Send + Sync> = Box::new(ParseError::new(ParseErrorKind::Other, None, "User Error: example"));
+    assert_eq!(error.to_string(), "User Error: example");
+}